@@ -1,27 +1,195 @@
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{quote, quote_spanned};
+use std::collections::HashMap;
 use std::str::FromStr;
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
 use syn::{
-    parse, Expr, Field, Fields, GenericArgument, Ident, ItemStruct, Lit, PathArguments, Type,
+    parse_macro_input, Attribute, Error, Expr, Field, Fields, GenericArgument, Ident, ItemEnum,
+    ItemStruct, Lit, LitInt, LitStr, PathArguments, Result, Token, Type,
 };
 
 #[proc_macro_derive(SMBIOS, attributes(smbios))]
 pub fn smbios_derive(input: TokenStream) -> TokenStream {
-    let ast: ItemStruct = parse(input).unwrap();
+    let ast = parse_macro_input!(input as ItemStruct);
 
+    expand(ast).unwrap_or_else(|e| e.to_compile_error()).into()
+}
+
+#[proc_macro_derive(SmbiosEnum, attributes(smbios))]
+pub fn smbios_enum_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as ItemEnum);
+
+    expand_enum(ast)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand_enum(ast: ItemEnum) -> Result<proc_macro2::TokenStream> {
+    let enum_name = ast.ident;
+
+    let mut variant_idents = vec![];
+    let mut variant_values = vec![];
+    let mut variant_names = vec![];
+    let mut unknown = None;
+    for variant in &ast.variants {
+        let ident = &variant.ident;
+        match &variant.fields {
+            Fields::Unit => {
+                let value = get_attr_int::<u64>(&variant.attrs, "value")?.ok_or_else(|| {
+                    Error::new(
+                        variant.span(),
+                        format!("variant `{}` needs `#[smbios(value = ...)]`", ident),
+                    )
+                })?;
+                let name = get_attr_str(&variant.attrs, "name")?
+                    .map(|n| n.value())
+                    .unwrap_or_else(|| ident.to_string());
+                variant_idents.push(ident.clone());
+                variant_values.push(value);
+                variant_names.push(name);
+            }
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 && unknown.is_none() => {
+                unknown = Some(ident.clone());
+            }
+            _ => {
+                return Err(Error::new(
+                    variant.span(),
+                    "SmbiosEnum expects unit variants and a single `Unknown(u64)` variant",
+                ))
+            }
+        }
+    }
+
+    let unknown = unknown.ok_or_else(|| {
+        Error::new(
+            enum_name.span(),
+            "SmbiosEnum needs a variant holding unknown values, like `Unknown(u64)`",
+        )
+    })?;
+
+    let enum_impl = quote! {
+        impl SmbiosEnum for #enum_name {
+            fn from_raw(value: u64) -> Self {
+                match value {
+                    #(#variant_values => #enum_name::#variant_idents,)*
+                    v => #enum_name::#unknown(v),
+                }
+            }
+
+            fn to_raw(&self) -> u64 {
+                match self {
+                    #(#enum_name::#variant_idents => #variant_values,)*
+                    #enum_name::#unknown(v) => *v,
+                }
+            }
+        }
+
+        impl std::fmt::Display for #enum_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    #(#enum_name::#variant_idents => f.write_str(#variant_names),)*
+                    #enum_name::#unknown(v) => write!(f, "Unknown (0x{:02X})", v),
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for #enum_name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use serde::ser::SerializeStruct;
+                let mut state = serializer.serialize_struct(stringify!(#enum_name), 2)?;
+                state.serialize_field("name", &self.to_string())?;
+                state.serialize_field("value", &self.to_raw())?;
+                state.end()
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for #enum_name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                #[derive(serde::Deserialize)]
+                struct Raw {
+                    value: u64,
+                }
+
+                let raw = Raw::deserialize(deserializer)?;
+                Ok(Self::from_raw(raw.value))
+            }
+        }
+    };
+
+    Ok(enum_impl)
+}
+
+fn expand(ast: ItemStruct) -> Result<proc_macro2::TokenStream> {
     let struct_name = ast.ident;
+    let min_lengths = get_min_lengths(&ast.attrs)?;
 
     let mut field_mandatories = vec![];
     let mut field_getters = vec![];
     let mut field_names = vec![];
     let mut field_ctors = vec![];
-    if let Fields::Named(fields) = ast.fields {
-        for field in &fields.named {
-            let func_name = field.ident.as_ref().unwrap();
-            field_names.push(func_name.clone());
+    let mut field_checked_ctors = vec![];
+    let mut field_heaps = vec![];
+    let mut field_labels = vec![];
+    let mut field_encoders = vec![];
+    let mut field_setters = vec![];
+    let mut packed_fields = vec![];
+    let mut packed_companions = vec![];
+    let mut serde_fields = vec![];
+    let mut serde_names = vec![];
+    let mut field_tys = vec![];
+    let mut last_byte_field = None;
+    let fields = match ast.fields {
+        Fields::Named(fields) => fields,
+        fields => {
+            return Err(Error::new(
+                fields.span(),
+                "SMBIOS can only be derived for structs with named fields",
+            ))
+        }
+    };
+
+    let mut field_sizes = vec![];
+    let mut length_sources = HashMap::new();
+    for field in &fields.named {
+        let tydef = get_type_def(&field.ty)?;
+        field_sizes.push(field_byte_size(field, &tydef)?);
+        if let Some((source, multiplier)) = get_length_source(field, &tydef)? {
+            length_sources.insert(source, (field, multiplier, tydef.optional));
+        }
+    }
+
+    for (index, field) in fields.named.iter().enumerate() {
+        let func_name = field.ident.as_ref().unwrap();
+        field_names.push(func_name.clone());
+
+        let ty = &field.ty;
+        let mut tydef = get_type_def(ty)?;
+        if has_flag(&field.attrs, "enum") {
+            tydef.copy_trait = true;
+        }
+        if is_smbios_string(&tydef.ident) {
+            if !tydef.optional || tydef.enumerable() {
+                return Err(Error::new(
+                    field.ty.span(),
+                    "SmbiosString is only supported as Option<SmbiosString>",
+                ));
+            }
 
-            let ty = &field.ty;
-            let tydef = get_type_def(ty);
+            let index_name = Ident::new(&format!("{}_index", func_name), func_name.span());
+            field_getters.push(quote! {
+                pub fn #func_name(&self) -> Option<&str> {
+                    self.#func_name.as_ref().and_then(|s| s.value())
+                }
+
+                pub fn #index_name(&self) -> Option<u8> {
+                    self.#func_name.as_ref().map(|s| s.index())
+                }
+            });
+        } else {
             let ret_ty = ty_ref(&tydef);
             let method = method_ref(func_name, &tydef);
             field_getters.push(quote! {
@@ -29,27 +197,311 @@ pub fn smbios_derive(input: TokenStream) -> TokenStream {
                     #method
                 }
             });
+        }
+
+        let bit = get_attr_int::<usize>(&field.attrs, "bit")?;
+        field_setters.push(field_setter(field, &tydef, last_byte_field.zip(bit)));
+
+        if let Some(flags) = get_attr_list(&field.attrs, "bitflags")? {
+            field_getters.push(field_bitflags(field, &tydef, &flags)?);
+        }
+
+        if let Some(target) = get_attr_str(&field.attrs, "handle_to")? {
+            field_getters.push(field_handle_resolver(field, &tydef, &target)?);
+        }
+
+        field_tys.push(ty.clone());
+        if has_flag(&field.attrs, "packed") {
+            let companions = get_attr_list(&field.attrs, "packed")?.unwrap_or_default();
+            if companions.is_empty() {
+                return Err(Error::new(
+                    func_name.span(),
+                    "`packed` expects the getters decoding the field, like `packed(\"ty_str\")`",
+                ));
+            }
+            packed_fields.push(func_name.to_string());
+            packed_companions.push(quote! {
+                (stringify!(#func_name), &[#(#companions),*])
+            });
 
-            if !tydef.optional {
-                field_mandatories.push(quote! {
-                    let #func_name = raw.#func_name;
+            // The raw value is kept under `<field>_raw`, next to its decoded parts.
+            let raw_name = format!("{}_raw", func_name);
+            serde_fields.push(quote! {
+                state.serialize_field(#raw_name, &self.#func_name)?;
+            });
+            serde_names.push(raw_name);
+            for companion in &companions {
+                if fields
+                    .named
+                    .iter()
+                    .any(|f| *f.ident.as_ref().unwrap() == companion.value())
+                {
+                    return Err(Error::new(
+                        companion.span(),
+                        format!("`{}` is already the name of a field", companion.value()),
+                    ));
+                }
+                let getter = Ident::new(&companion.value(), companion.span());
+                serde_fields.push(quote! {
+                    state.serialize_field(#companion, &self.#getter())?;
                 });
-                continue;
             }
+        } else {
+            let name = func_name.to_string();
+            serde_fields.push(quote! {
+                state.serialize_field(#name, &self.#func_name)?;
+            });
+            serde_names.push(name);
+        }
+
+        field_labels.append(&mut field_label_entries(field, &tydef)?);
+
+        if let Some(heap) = field_heap_bytes(func_name, &tydef) {
+            field_heaps.push(heap);
+        }
+
+        if !tydef.optional || get_attr_int::<usize>(&field.attrs, "bit")?.is_some() {
+            // Header fields and bits of a preceding byte have nothing to encode.
+        } else if has_flag(&field.attrs, "strings") {
+            field_encoders.push(quote! {
+                for value in &self.#func_name {
+                    if strings.push(value) == 0 {
+                        error = Some(EncodeError::TooManyStrings {
+                            structure: stringify!(#struct_name),
+                        });
+                        return None;
+                    }
+                }
+            });
+        } else {
+            let source = length_sources.get(&func_name.to_string()).copied();
+            field_encoders.push(field_encoder(field, &tydef, &struct_name, source)?);
+        }
+
+        if let Some(bit) = get_attr_int(&field.attrs, "bit")? {
+            let ctor = field_ctor_bit(field, &tydef, last_byte_field, bit)?;
+            field_checked_ctors.push(ctor.clone());
+            field_ctors.push(ctor);
+            continue;
+        }
 
-            let ctor = field_ctor(field, &tydef);
+        if is_u8(&tydef.ident) && tydef.optional && !tydef.enumerable() {
+            last_byte_field = Some(func_name);
+        }
+
+        if has_flag(&field.attrs, "strings") {
+            let ctor = field_ctor_string_set(field, &struct_name)?;
+            field_checked_ctors.push(ctor.clone());
             field_ctors.push(ctor);
+            continue;
+        }
+
+        if !tydef.optional {
+            field_mandatories.push(quote! {
+                let #func_name = raw.#func_name;
+            });
+            continue;
         }
+
+        let version_check = if let Some(version) = get_attr_str(&field.attrs, "min_version")? {
+            let (major, minor) = parse_version(&version)?;
+            quote! {
+                if let Some(smbios) = smbios {
+                    if !smbios.is_later(#major, #minor) {
+                        body.clear();
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        let skip = if let Some(skip) = get_attr_int::<usize>(&field.attrs, "skip")? {
+            quote! {
+                body.advance(body.remaining().min(#skip));
+            }
+        } else {
+            quote! {}
+        };
+
+        // Fields after a vector are only found by size when all of them are fixed.
+        let tail = field_sizes[index + 1..]
+            .iter()
+            .copied()
+            .sum::<Option<usize>>()
+            .unwrap_or(0);
+        let ctor = field_ctor(field, &tydef, &struct_name, tail)?;
+        let realign = field_realign(field, &tydef, tail);
+        field_checked_ctors.push(quote! {
+            #version_check
+            #skip
+            let start = body.remaining();
+            #ctor
+            if #func_name.is_none() && error.is_none() {
+                let offset = 4 + raw.body.len() - start;
+                if (start > 0 && body.remaining() == start)
+                    || (start == 0 && offset < raw.length as usize)
+                {
+                    *error = Some(ParseError::FieldTruncated {
+                        structure: stringify!(#struct_name),
+                        field: stringify!(#func_name),
+                        offset,
+                        length: raw.length,
+                    });
+                }
+            }
+            #realign
+        });
+        field_ctors.push(quote! {
+            #version_check
+            #skip
+            #ctor
+            #realign
+        });
+    }
+
+    let encode_body_func = quote! {
+        #[cfg(feature = "encode")]
+        pub fn encode_body(
+            &self,
+            body: &mut BytesMut,
+            strings: &mut StringPool,
+        ) -> Result<(), EncodeError> {
+            // Encoding stops at the first absent field, like a shorter structure.
+            let mut error = None;
+            let mut encode = || -> Option<()> {
+                #(#field_encoders)*
+                Some(())
+            };
+            encode();
+            error.map_or(Ok(()), Err)
+        }
+    };
+
+    // Fixed sizes are known up front, vectors are measured on the decoded value
+    // so the fields after them keep their offsets.
+    let mut sized_names = vec![];
+    let mut sized_idents = vec![];
+    let mut sized_exprs = vec![];
+    for (field, size) in fields.named.iter().zip(&field_sizes) {
+        if *size == Some(0) {
+            continue;
+        }
+
+        let func_name = field.ident.as_ref().unwrap();
+        sized_names.push(func_name.to_string());
+        sized_idents.push(func_name);
+        sized_exprs.push(match size {
+            Some(size) => quote! { #size },
+            None => vec_raw_size(field, &get_type_def(&field.ty)?)?,
+        });
     }
 
     let from_table_func = if !field_mandatories.is_empty() {
+        // Newest version first, so the first match is the one in effect.
+        let majors = min_lengths.iter().rev().map(|((major, _), _)| major);
+        let minors = min_lengths.iter().rev().map(|((_, minor), _)| minor);
+        let lengths = min_lengths.iter().rev().map(|(_, length)| length);
+
         quote! {
+            pub fn spec_min_length(major: u8, minor: u8) -> Option<u8> {
+                #(
+                    if (major, minor) >= (#majors, #minors) {
+                        return Some(#lengths);
+                    }
+                )*
+                None
+            }
+
+            // The name, end offset and presence of every field stored in the body.
+            fn field_ends(&self) -> Vec<(&'static str, usize, bool)> {
+                let mut end = 4;
+                let mut ends = vec![];
+                #(
+                    end += #sized_exprs;
+                    ends.push((#sized_names, end, self.#sized_idents.is_some()));
+                )*
+                ends
+            }
+
+            pub fn has_field(&self, name: &str) -> bool {
+                self.field_ends()
+                    .iter()
+                    .any(|(field, end, _)| *field == name && *end <= self.length as usize)
+            }
+
+            pub fn missing_fields(&self) -> Vec<&'static str> {
+                self.field_ends()
+                    .into_iter()
+                    .filter(|(_, end, present)| *end <= self.length as usize && !present)
+                    .map(|(field, _, _)| field)
+                    .collect()
+            }
+
+            pub fn length_is_valid(&self, smbios: &RawSmbiosData) -> bool {
+                Self::spec_min_length(smbios.smbios_major_version, smbios.smbios_minior_version)
+                    .map_or(true, |min| self.length >= min)
+            }
+
+            #encode_body_func
+
+            #[cfg(feature = "encode")]
+            pub fn encode(&self, strings: &mut StringPool) -> Result<BytesMut, EncodeError> {
+                let mut body = BytesMut::new();
+                self.encode_body(&mut body, strings)?;
+
+                // The formatted area length is a single byte, header included.
+                let length = 4 + body.len();
+                if length > u8::MAX as usize {
+                    return Err(EncodeError::TooLong {
+                        structure: stringify!(#struct_name),
+                        length,
+                    });
+                }
+
+                let mut buf = BytesMut::with_capacity(length);
+                buf.put_u8(self.table_ty);
+                buf.put_u8(length as u8);
+                buf.put_u16_le(self.handle);
+                buf.extend_from_slice(&body);
+                Ok(buf)
+            }
+
             pub fn from_raw_table(raw: &RawSmbiosTable) -> Self {
+                Self::from_raw_table_with_warnings(raw).0
+            }
+
+            pub fn try_from_raw_table(raw: &RawSmbiosTable) -> Result<Self, ParseError> {
+                let mut error = None;
+                let table = Self::decode_raw_table(raw, None, &mut vec![], &mut error);
+                match error {
+                    Some(error) => Err(error),
+                    None => Ok(table),
+                }
+            }
+
+            pub fn from_raw_table_with_warnings(raw: &RawSmbiosTable) -> (Self, Vec<String>) {
+                let mut warnings = vec![];
+                let table = Self::decode_raw_table(raw, None, &mut warnings, &mut None);
+                (table, warnings)
+            }
+
+            pub fn from_raw_table_versioned(raw: &RawSmbiosTable, smbios: &RawSmbiosData) -> Self {
+                Self::decode_raw_table(raw, Some(smbios), &mut vec![], &mut None)
+            }
+
+            #[allow(unused_variables, clippy::ptr_arg)]
+            fn decode_raw_table(
+                raw: &RawSmbiosTable,
+                smbios: Option<&RawSmbiosData>,
+                warnings: &mut Vec<String>,
+                error: &mut Option<ParseError>,
+            ) -> Self {
                 #(#field_mandatories)*
 
                 let mut body = raw.body.clone();
 
-                #(#field_ctors)*
+                #(#field_checked_ctors)*
 
                 #struct_name {
                     #(#field_names),*
@@ -57,8 +509,31 @@ pub fn smbios_derive(input: TokenStream) -> TokenStream {
             }
         }
     } else {
+        let raw_size = match field_sizes.iter().copied().sum::<Option<usize>>() {
+            Some(size) => quote! { Some(#size) },
+            None => quote! { None },
+        };
         quote! {
+            pub const RAW_SIZE: Option<usize> = #raw_size;
+
+            // The bytes this record was read from; a record cut short by the
+            // end of the body only counts its present fields.
+            pub fn raw_len(&self) -> usize {
+                let mut len = 0;
+                #(
+                    if self.#sized_idents.is_some() {
+                        len += #sized_exprs;
+                    }
+                )*
+                len
+            }
+
+            #encode_body_func
+
+            #[allow(unused_variables)]
             pub fn from_raw(body: &mut Bytes, raw: &RawSmbiosTable) -> Self {
+                // Nested structures have nowhere to report clamped vectors.
+                let warnings: &mut Vec<String> = &mut vec![];
                 #(#field_ctors)*
 
                 #struct_name {
@@ -68,15 +543,77 @@ pub fn smbios_derive(input: TokenStream) -> TokenStream {
         }
     };
 
+    let serde_len = serde_fields.len();
+    let serde_impl = quote! {
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for #struct_name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use serde::ser::SerializeStruct;
+                let mut state = serializer.serialize_struct(stringify!(#struct_name), #serde_len)?;
+                #(#serde_fields)*
+                state.end()
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for #struct_name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                // Decoded companions of packed fields are ignored, their raw value is enough.
+                #[derive(serde::Deserialize)]
+                struct Raw {
+                    #(
+                        #[serde(rename = #serde_names)]
+                        #field_names: #field_tys,
+                    )*
+                }
+
+                let raw = Raw::deserialize(deserializer)?;
+                Ok(#struct_name {
+                    #(#field_names: raw.#field_names),*
+                })
+            }
+        }
+    };
+
     let struct_impl = quote! {
+        #serde_impl
+
+        impl Default for #struct_name {
+            fn default() -> Self {
+                #struct_name {
+                    #(#field_names: Default::default()),*
+                }
+            }
+        }
+
         impl #struct_name {
+            pub const FIELD_NAMES: &'static [&'static str] = &[#(stringify!(#field_names)),*];
+
+            pub const PACKED_FIELDS: &'static [&'static str] = &[#(#packed_fields),*];
+
+            pub const PACKED_COMPANIONS: &'static [(&'static str, &'static [&'static str])] =
+                &[#(#packed_companions),*];
+
             #(#field_getters)*
 
+            #(#field_setters)*
+
+            pub fn approx_heap_bytes(&self) -> usize {
+                0 #(+ #field_heaps)*
+            }
+
+            #[allow(unused_mut)]
+            pub fn fields(&self) -> Vec<(&'static str, FieldValue)> {
+                let mut fields = vec![];
+                #(#field_labels)*
+                fields
+            }
+
             #from_table_func
         }
     };
 
-    struct_impl.into()
+    Ok(struct_impl)
 }
 
 #[derive(Debug)]
@@ -140,161 +677,787 @@ fn method_ref(func_name: &Ident, tydef: &TypeDef) -> proc_macro2::TokenStream {
     }
 }
 
-fn field_ctor(field: &Field, tydef: &TypeDef) -> proc_macro2::TokenStream {
-    if is_u8(&tydef.ident) {
-        let method = Ident::new("get_u8", proc_macro2::Span::call_site());
-        field_ctor_number(field, tydef, &method, 1)
+fn field_heap_bytes(func_name: &Ident, tydef: &TypeDef) -> Option<proc_macro2::TokenStream> {
+    let elem_ty = &tydef.ident;
+    let elem_heap = if is_string(elem_ty) {
+        quote! { e.capacity() }
+    } else if tydef.copy_trait {
+        quote! { 0 }
+    } else {
+        quote! { e.approx_heap_bytes() }
+    };
+
+    let heap = if tydef.vector {
+        quote! {
+            v.capacity() * std::mem::size_of::<#elem_ty>()
+                + v.iter().map(|e| #elem_heap).sum::<usize>()
+        }
+    } else if tydef.array() {
+        if tydef.copy_trait {
+            return None;
+        }
+        quote! { v.iter().map(|e| #elem_heap).sum::<usize>() }
+    } else if tydef.copy_trait {
+        return None;
+    } else {
+        quote! { { let e = v; #elem_heap } }
+    };
+
+    if tydef.optional {
+        Some(quote! { self.#func_name.as_ref().map_or(0, |v| #heap) })
+    } else {
+        Some(quote! { { let v = &self.#func_name; #heap } })
+    }
+}
+
+fn field_bitflags(
+    field: &Field,
+    tydef: &TypeDef,
+    flags: &[LitStr],
+) -> Result<proc_macro2::TokenStream> {
+    let func_name = field.ident.as_ref().unwrap();
+
+    let bits = match int_byte_size(&tydef.ident) {
+        Some(size) if tydef.optional && !tydef.enumerable() => size * 8,
+        _ => {
+            return Err(Error::new(
+                field.ty.span(),
+                "`bitflags` is only supported on optional integer fields",
+            ))
+        }
+    };
+
+    if flags.len() > bits {
+        return Err(Error::new(
+            flags[bits].span(),
+            format!(
+                "`bitflags` lists {} flags for a {}-bit field",
+                flags.len(),
+                bits
+            ),
+        ));
+    }
+
+    let str_name = Ident::new(&format!("{}_str", func_name), func_name.span());
+    let flags_name = Ident::new(&format!("{}_flags", func_name), func_name.span());
+    Ok(quote! {
+        pub fn #flags_name(&self) -> impl Iterator<Item = &'static str> {
+            const FLAGS: &[&str] = &[#(#flags),*];
+            let value = self.#func_name.map_or(0, |v| v as u64);
+            FLAGS
+                .iter()
+                .enumerate()
+                .filter(move |(i, _)| (value & (1 << i)) != 0)
+                .map(|(_, name)| *name)
+        }
+
+        pub fn #str_name(&self) -> Option<Vec<String>> {
+            self.#func_name
+                .map(|_| self.#flags_name().map(|name| name.to_string()).collect())
+        }
+    })
+}
+
+fn field_setter(
+    field: &Field,
+    tydef: &TypeDef,
+    bit_source: Option<(&Ident, usize)>,
+) -> proc_macro2::TokenStream {
+    let func_name = field.ident.as_ref().unwrap();
+    let setter_name = Ident::new(&format!("with_{}", func_name), func_name.span());
+
+    // A bit lives in the byte it was read from, which is what gets encoded.
+    if let Some((byte_field, bit)) = bit_source {
+        return quote! {
+            pub fn #setter_name(mut self, value: bool) -> Self {
+                let byte = self.#byte_field.unwrap_or_default() & !(1 << #bit);
+                self.#byte_field = Some(byte | ((value as u8) << #bit));
+                self.#func_name = Some(value);
+                self
+            }
+        };
+    }
+
+    let elem_ty = &tydef.ident;
+    let (value_ty, value) = if tydef.vector {
+        (quote! { Vec<#elem_ty> }, quote! { value })
+    } else if tydef.array() {
+        let len = tydef.array_length as usize;
+        (quote! { [#elem_ty; #len] }, quote! { value })
+    } else if is_string(elem_ty) || is_smbios_string(elem_ty) {
+        (quote! { impl Into<#elem_ty> }, quote! { value.into() })
+    } else {
+        (quote! { #elem_ty }, quote! { value })
+    };
+
+    let value = if tydef.optional {
+        quote! { Some(#value) }
+    } else {
+        value
+    };
+
+    quote! {
+        pub fn #setter_name(mut self, value: #value_ty) -> Self {
+            self.#func_name = #value;
+            self
+        }
+    }
+}
+
+fn field_handle_resolver(
+    field: &Field,
+    tydef: &TypeDef,
+    target: &LitStr,
+) -> Result<proc_macro2::TokenStream> {
+    let func_name = field.ident.as_ref().unwrap();
+
+    if !is_u16(&tydef.ident) || !tydef.optional || tydef.enumerable() {
+        return Err(Error::new(
+            field.ty.span(),
+            "`handle_to` is only supported on Option<u16> fields",
+        ));
+    }
+
+    let target: Ident = target.parse()?;
+    let resolved_name = Ident::new(&format!("{}_resolved", func_name), func_name.span());
+    Ok(quote! {
+        pub fn #resolved_name<'a>(&self, tables: &'a SmbiosTables) -> Option<&'a #target> {
+            match self.#func_name? {
+                0xFFFE | 0xFFFF => None,
+                handle => match tables.get_by_handle(handle) {
+                    Some(SmbiosTable::#target(table)) => Some(table),
+                    _ => None,
+                },
+            }
+        }
+    })
+}
+
+fn field_label_entries(field: &Field, tydef: &TypeDef) -> Result<Vec<proc_macro2::TokenStream>> {
+    let func_name = field.ident.as_ref().unwrap();
+
+    let mut entries = vec![];
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("smbios")) {
+        let attrs = std::slice::from_ref(attr);
+        let label = match get_attr_str(attrs, "label")? {
+            Some(label) => label,
+            None => continue,
+        };
+
+        let getter = if tydef.optional {
+            quote! { self.#func_name() }
+        } else {
+            quote! { Some(self.#func_name()) }
+        };
+
+        let fmt = get_attr_str(attrs, "fmt")?;
+        let mut value = if let Some(with) = get_attr_str(attrs, "with")? {
+            if let Some(fmt) = fmt {
+                return Err(Error::new(
+                    fmt.span(),
+                    "`fmt` cannot be combined with `with`",
+                ));
+            }
+
+            let with: Ident = with.parse()?;
+            quote! { self.#with().map(FieldValue::from) }
+        } else if let Some(fmt) = fmt {
+            if fmt.value() != "hex" {
+                return Err(Error::new(fmt.span(), "`fmt` only supports \"hex\""));
+            }
+
+            let width = match get_attr_int::<usize>(&field.attrs, "width")? {
+                Some(width) => width * 2,
+                None => {
+                    int_byte_size(&tydef.ident).ok_or_else(|| {
+                        Error::new(
+                            fmt.span(),
+                            "`fmt = \"hex\"` is only supported on integer fields",
+                        )
+                    })? * 2
+                }
+            };
+
+            if tydef.enumerable() {
+                quote! {
+                    #getter.map(|v| {
+                        FieldValue::List(v.iter().map(|e| format!("0x{:0w$X}", e, w = #width)).collect())
+                    })
+                }
+            } else {
+                quote! {
+                    #getter.map(|v| FieldValue::Text(format!("0x{:0w$X}", v, w = #width)))
+                }
+            }
+        } else if has_flag(&field.attrs, "enum") {
+            quote! { #getter.map(|v| FieldValue::Text(v.to_string())) }
+        } else if tydef.copy_trait || is_string(&tydef.ident) || is_smbios_string(&tydef.ident) {
+            quote! { #getter.map(FieldValue::from) }
+        } else {
+            return Err(Error::new(
+                label.span(),
+                format!(
+                    "`label` on a `{}` field needs `with = \"...\"`",
+                    tydef.ident
+                ),
+            ));
+        };
+
+        if let Some(unit) = get_attr_str(attrs, "unit")? {
+            value = quote! { #value.map(|v| v.with_unit(#unit)) };
+        }
+
+        entries.push(quote! {
+            if let Some(value) = #value {
+                fields.push((#label, value));
+            }
+        });
+    }
+
+    Ok(entries)
+}
+
+fn field_byte_size(field: &Field, tydef: &TypeDef) -> Result<Option<usize>> {
+    if !tydef.optional || has_flag(&field.attrs, "strings") {
+        return Ok(Some(0));
+    }
+
+    if get_attr_int::<usize>(&field.attrs, "bit")?.is_some() {
+        return Ok(Some(0));
+    }
+
+    if tydef.vector {
+        return Ok(None);
+    }
+
+    let size = if has_flag(&field.attrs, "enum") {
+        1
+    } else if let Some(width) = get_attr_int::<usize>(&field.attrs, "width")? {
+        width
+    } else if is_bool(&tydef.ident) || is_string(&tydef.ident) || is_smbios_string(&tydef.ident) {
+        1
+    } else if let Some(size) = int_byte_size(&tydef.ident) {
+        size
+    } else {
+        return Ok(None);
+    };
+
+    let count = if tydef.array() {
+        tydef.array_length as usize
+    } else {
+        1
+    };
+    let skip = get_attr_int::<usize>(&field.attrs, "skip")?.unwrap_or(0);
+    Ok(Some(skip + size * count))
+}
+
+fn field_realign(field: &Field, tydef: &TypeDef, tail: usize) -> proc_macro2::TokenStream {
+    let func_name = field.ident.as_ref().unwrap();
+
+    if !tydef.vector || has_flag(&field.attrs, "remaining") || tail == 0 {
+        return quote! {};
+    }
+
+    quote! {
+        if #func_name.is_none() || clamped {
+            body.advance(body.remaining().saturating_sub(#tail));
+        }
+    }
+}
+
+// Clamps `length` units to what the body can hold, then runs `read` with
+// `fit` units. When the count does not fit, the fixed fields after the vector
+// are kept out of it. `read` may shadow `fit` when it stops early.
+fn vec_ctor(
+    field: &Field,
+    struct_name: &Ident,
+    length: &proc_macro2::TokenStream,
+    unit_size: Option<proc_macro2::TokenStream>,
+    read: &proc_macro2::TokenStream,
+    tail: usize,
+    unit: &str,
+) -> proc_macro2::TokenStream {
+    let func_name = field.ident.as_ref().unwrap();
+
+    let available = if tail > 0 {
+        quote! { body.remaining().saturating_sub(#tail) }
+    } else {
+        quote! { body.remaining() }
+    };
+    let (needed, available) = match unit_size {
+        Some(size) => (quote! { len * #size }, quote! { #available / #size }),
+        None => (quote! { len }, available),
+    };
+
+    quote! {
+        let (#func_name, clamped) = if let Some(len) = #length {
+            let fit = if #needed <= body.remaining() {
+                len
+            } else {
+                #available
+            };
+
+            let mut v = vec![];
+            #read
+
+            if fit < len {
+                warnings.push(format!(
+                    "{}: {} declares {} {} but only {} fit",
+                    stringify!(#struct_name),
+                    stringify!(#func_name),
+                    len,
+                    #unit,
+                    fit
+                ));
+            }
+            (Some(v), fit < len)
+        } else {
+            (None, false)
+        };
+    }
+}
+
+// The bytes a decoded vector occupies in the body.
+fn vec_raw_size(field: &Field, tydef: &TypeDef) -> Result<proc_macro2::TokenStream> {
+    let func_name = field.ident.as_ref().unwrap();
+    let skip = get_attr_int::<usize>(&field.attrs, "skip")?.unwrap_or(0);
+
+    let elem = if let Some(size) = int_byte_size(&tydef.ident) {
+        quote! { v.len() * #size }
+    } else if is_bool(&tydef.ident) || is_string(&tydef.ident) {
+        quote! { v.len() }
+    } else {
+        quote! { v.iter().map(|e| e.raw_len()).sum::<usize>() }
+    };
+
+    Ok(quote! { #skip + self.#func_name.as_ref().map_or(0, |v| #elem) })
+}
+
+fn get_length_source(field: &Field, tydef: &TypeDef) -> Result<Option<(String, usize)>> {
+    if !tydef.vector {
+        return Ok(None);
+    }
+
+    let (lit, multiplier) = if let Some(lit) = get_attr_str(&field.attrs, "length")? {
+        (lit, 1)
+    } else if let Some(lit) = get_attr_str(&field.attrs, "length_bytes")? {
+        (lit, int_byte_size(&tydef.ident).unwrap_or(1))
+    } else {
+        return Ok(None);
+    };
+
+    // Only a plain field name can be rewritten to match the vector.
+    match lit.parse::<Expr>() {
+        Ok(Expr::Path(path)) => Ok(path
+            .path
+            .get_ident()
+            .map(|source| (source.to_string(), multiplier))),
+        _ => Ok(None),
+    }
+}
+
+fn field_encoder(
+    field: &Field,
+    tydef: &TypeDef,
+    struct_name: &Ident,
+    length_of: Option<(&Field, usize, bool)>,
+) -> Result<proc_macro2::TokenStream> {
+    let func_name = field.ident.as_ref().unwrap();
+
+    let value = if let Some((vector, multiplier, optional)) = length_of {
+        let vector = vector.ident.as_ref().unwrap();
+        let ty = &tydef.ident;
+        let count = quote! {
+            |count: usize| match #ty::try_from(count) {
+                Ok(count) => Some(count),
+                Err(_) => {
+                    error = Some(EncodeError::CountOverflow {
+                        structure: stringify!(#struct_name),
+                        field: stringify!(#func_name),
+                        count,
+                    });
+                    None
+                }
+            }
+        };
+        if optional {
+            quote! {
+                match self.#vector.as_ref() {
+                    Some(v) => (#count)(v.len() * #multiplier)?,
+                    None => self.#func_name?,
+                }
+            }
+        } else {
+            quote! { (#count)(self.#vector.len() * #multiplier)? }
+        }
+    } else if tydef.copy_trait && !tydef.enumerable() {
+        quote! { self.#func_name? }
+    } else {
+        quote! { self.#func_name.as_ref()? }
+    };
+
+    let skip = match get_attr_int::<usize>(&field.attrs, "skip")? {
+        Some(skip) => quote! { body.put_bytes(0, #skip); },
+        None => quote! {},
+    };
+
+    let put = if tydef.enumerable() {
+        let elem = if tydef.copy_trait {
+            quote! { *e }
+        } else {
+            quote! { e }
+        };
+        let put = encode_value(field, tydef, struct_name, &elem);
+        quote! {
+            for e in value.iter() {
+                #put
+            }
+        }
+    } else {
+        encode_value(field, tydef, struct_name, &quote! { value })
+    };
+
+    Ok(quote! {
+        let value = #value;
+        #skip
+        #put
+    })
+}
+
+fn encode_value(
+    field: &Field,
+    tydef: &TypeDef,
+    struct_name: &Ident,
+    value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let ident = &tydef.ident;
+    let intern = |value: proc_macro2::TokenStream| {
+        quote! {
+            let value: &str = #value;
+            let index = strings.intern(value);
+            if index == 0 && !value.is_empty() {
+                error = Some(EncodeError::TooManyStrings {
+                    structure: stringify!(#struct_name),
+                });
+                return None;
+            }
+            body.put_u8(index);
+        }
+    };
+
+    if has_flag(&field.attrs, "enum") {
+        quote! { body.put_u8(#value.to_raw() as u8); }
+    } else if has_flag(&field.attrs, "width") {
+        quote! { body.put_uint_le(#value as u64, 3); }
+    } else if is_bool(ident) {
+        quote! { body.put_u8(#value as u8); }
+    } else if is_u8(ident) {
+        quote! { body.put_u8(#value); }
+    } else if is_u16(ident) {
+        quote! { body.put_u16_le(#value); }
+    } else if is_u32(ident) {
+        quote! { body.put_u32_le(#value); }
+    } else if is_u64(ident) {
+        quote! { body.put_u64_le(#value); }
+    } else if is_i8(ident) {
+        quote! { body.put_i8(#value); }
+    } else if is_i16(ident) {
+        quote! { body.put_i16_le(#value); }
+    } else if is_i32(ident) {
+        quote! { body.put_i32_le(#value); }
+    } else if is_i64(ident) {
+        quote! { body.put_i64_le(#value); }
+    } else if is_string(ident) {
+        intern(quote! { #value })
+    } else if is_smbios_string(ident) {
+        intern(quote! { #value.value().unwrap_or_default() })
+    } else {
+        quote! {
+            if let Err(e) = #value.encode_body(body, strings) {
+                error = Some(e);
+                return None;
+            }
+        }
+    }
+}
+
+fn field_ctor(
+    field: &Field,
+    tydef: &TypeDef,
+    struct_name: &Ident,
+    tail: usize,
+) -> Result<proc_macro2::TokenStream> {
+    if has_flag(&field.attrs, "enum") {
+        return field_ctor_enum(field, tydef);
+    }
+
+    if has_flag(&field.attrs, "remaining") && !(tydef.vector && tydef.copy_trait) {
+        return Err(Error::new(
+            field.ty.span(),
+            "`remaining` is only supported on a Vec of integers",
+        ));
+    }
+
+    if let Some(width) = get_attr_int::<usize>(&field.attrs, "width")? {
+        if !is_u32(&tydef.ident) || width != 3 {
+            return Err(Error::new(
+                field.span(),
+                "`width` is only supported as `width = 3` on u32 fields",
+            ));
+        }
+
+        let method = quote! { get_uint_le(3) as u32 };
+        field_ctor_number(field, tydef, &method, 3, struct_name, tail)
+    } else if is_bool(&tydef.ident) {
+        if tydef.enumerable() {
+            return Err(Error::new(
+                field.ty.span(),
+                "bool is only supported as a single field",
+            ));
+        }
+
+        let method = quote! { get_u8() != 0 };
+        field_ctor_number(field, tydef, &method, 1, struct_name, tail)
+    } else if is_u8(&tydef.ident) {
+        let method = quote! { get_u8() };
+        field_ctor_number(field, tydef, &method, 1, struct_name, tail)
     } else if is_u16(&tydef.ident) {
-        let method = Ident::new("get_u16_le", proc_macro2::Span::call_site());
-        field_ctor_number(field, tydef, &method, 2)
+        let method = quote! { get_u16_le() };
+        field_ctor_number(field, tydef, &method, 2, struct_name, tail)
     } else if is_u32(&tydef.ident) {
-        let method = Ident::new("get_u32_le", proc_macro2::Span::call_site());
-        field_ctor_number(field, tydef, &method, 4)
+        let method = quote! { get_u32_le() };
+        field_ctor_number(field, tydef, &method, 4, struct_name, tail)
     } else if is_u64(&tydef.ident) {
-        let method = Ident::new("get_u64_le", proc_macro2::Span::call_site());
-        field_ctor_number(field, tydef, &method, 8)
+        let method = quote! { get_u64_le() };
+        field_ctor_number(field, tydef, &method, 8, struct_name, tail)
     } else if is_i8(&tydef.ident) {
-        let method = Ident::new("get_i8", proc_macro2::Span::call_site());
-        field_ctor_number(field, tydef, &method, 1)
+        let method = quote! { get_i8() };
+        field_ctor_number(field, tydef, &method, 1, struct_name, tail)
     } else if is_i16(&tydef.ident) {
-        let method = Ident::new("get_i16_le", proc_macro2::Span::call_site());
-        field_ctor_number(field, tydef, &method, 2)
+        let method = quote! { get_i16_le() };
+        field_ctor_number(field, tydef, &method, 2, struct_name, tail)
     } else if is_i32(&tydef.ident) {
-        let method = Ident::new("get_i32_le", proc_macro2::Span::call_site());
-        field_ctor_number(field, tydef, &method, 4)
+        let method = quote! { get_i32_le() };
+        field_ctor_number(field, tydef, &method, 4, struct_name, tail)
     } else if is_i64(&tydef.ident) {
-        let method = Ident::new("get_i64_le", proc_macro2::Span::call_site());
-        field_ctor_number(field, tydef, &method, 8)
+        let method = quote! { get_i64_le() };
+        field_ctor_number(field, tydef, &method, 8, struct_name, tail)
     } else if is_string(&tydef.ident) {
-        field_ctor_string(field, tydef)
+        field_ctor_string(field, tydef, struct_name, tail)
+    } else if is_smbios_string(&tydef.ident) {
+        let func_name = field.ident.as_ref().unwrap();
+        let warn = dangling_string_warning(field, struct_name);
+        Ok(quote! {
+            let #func_name = if body.remaining() >= 1 {
+                let idx = body.get_u8();
+                let value = SmbiosString::from_index(raw, idx);
+                if value.is_dangling() {
+                    #warn
+                }
+                Some(value)
+            } else {
+                None
+            };
+        })
+    } else if is_primitive(&tydef.ident) {
+        Err(Error::new(
+            field.ty.span(),
+            format!("unsupported field type `{}`", tydef.ident),
+        ))
     } else {
-        field_ctor_struct(field, tydef)
+        field_ctor_struct(field, tydef, struct_name, tail)
     }
 }
 
 fn field_ctor_number(
     field: &Field,
     tydef: &TypeDef,
-    method: &Ident,
+    method: &proc_macro2::TokenStream,
     byte_size: usize,
-) -> proc_macro2::TokenStream {
+    struct_name: &Ident,
+    tail: usize,
+) -> Result<proc_macro2::TokenStream> {
     let func_name = &field.ident.as_ref().unwrap();
 
-    if tydef.array() {
+    let ctor = if tydef.array() {
         let length = tydef.array_length as usize;
         quote! {
             let #func_name = if body.remaining() >= (#length * #byte_size) {
                 let mut arr = [0; #length];
                 for idx in 0..#length {
-                    arr[idx] = body.#method();
+                    arr[idx] = body.#method;
                 }
                 Some(arr)
             } else {
                 None
             };
         }
-    } else if tydef.vector {
-        let length = get_vec_length(field);
+    } else if let (true, Some(length)) = (tydef.vector, get_vec_length_bytes(field)?) {
+        // A trailing partial element is skipped so later fields stay aligned.
+        let read = if byte_size > 1 {
+            quote! {
+                for _ in 0..fit / #byte_size {
+                    v.push(body.#method);
+                }
+                body.advance(fit % #byte_size);
+            }
+        } else {
+            quote! {
+                for _ in 0..fit {
+                    v.push(body.#method);
+                }
+            }
+        };
+        vec_ctor(field, struct_name, &length, None, &read, tail, "bytes")
+    } else if has_flag(&field.attrs, "remaining") {
         quote! {
-            let #func_name = if let Some(len) = #length {
-                let len = len as usize;
-                if body.remaining() >= (len * #byte_size) {
-                    let mut v = vec![];
-                    for _ in 0..len {
-                        v.push(body.#method());
-                    }
-                    Some(v)
-                } else {
-                    None
+            let #func_name = if let Some(len) = Some(body.remaining() / #byte_size).filter(|&n| n > 0) {
+                let mut v = vec![];
+                for _ in 0..len {
+                    v.push(body.#method);
                 }
+                Some(v)
             } else {
                 None
             };
         }
+    } else if tydef.vector {
+        let length = get_vec_length(field)?;
+        let unit_size = (byte_size > 1).then(|| quote! { #byte_size });
+        let read = quote! {
+            for _ in 0..fit {
+                v.push(body.#method);
+            }
+        };
+        vec_ctor(
+            field,
+            struct_name,
+            &length,
+            unit_size,
+            &read,
+            tail,
+            "entries",
+        )
     } else {
         quote! {
             let #func_name = if body.remaining() >= #byte_size {
-                Some(body.#method())
+                Some(body.#method)
             } else {
                 None
             };
         }
-    }
+    };
+
+    Ok(ctor)
 }
 
-fn field_ctor_string(field: &Field, tydef: &TypeDef) -> proc_macro2::TokenStream {
+fn field_ctor_string(
+    field: &Field,
+    tydef: &TypeDef,
+    struct_name: &Ident,
+    tail: usize,
+) -> Result<proc_macro2::TokenStream> {
     let func_name = &field.ident.as_ref().unwrap();
+    let warn = dangling_string_warning(field, struct_name);
+    let lookup = quote! {
+        {
+            let value = raw.get_string_by_index(idx);
+            if idx != 0 && value.is_none() {
+                #warn
+            }
+            value
+        }
+    };
 
-    if tydef.array() {
+    let ctor = if tydef.array() {
         let length = tydef.array_length as usize;
         quote! {
             let #func_name = if body.remaining() >= #length {
-                let mut arr = [0; #length];
-                for idx in 0..#length {
+                let arr: [String; #length] = std::array::from_fn(|_| {
                     let idx = body.get_u8();
-                    arr[idx] = raw.get_string_by_index(idx)
-                }
+                    #lookup.unwrap_or_default()
+                });
                 Some(arr)
             } else {
                 None
             };
         }
     } else if tydef.vector {
-        let length = get_vec_length(field);
-        quote! {
-            let #func_name = if let Some(len) = #length {
-                let len = len as usize;
-                if body.remaining() >= len  {
-                    let mut v = vec![];
-                    for _ in 0..len {
-                        let idx = body.get_u8();
-                        if let Some(value) = raw.get_string_by_index(idx) {
-                            v.push(value);
-                        }
-                    }
-                    Some(v)
-                } else {
-                    None
+        let length = get_vec_length(field)?;
+        let read = quote! {
+            for _ in 0..fit {
+                let idx = body.get_u8();
+                if let Some(value) = #lookup {
+                    v.push(value);
                 }
-            } else {
-                None
-            };
-        }
+            }
+        };
+        vec_ctor(field, struct_name, &length, None, &read, tail, "entries")
     } else {
         quote! {
             let #func_name = if body.remaining() >= 1 {
                 let idx = body.get_u8();
-                raw.get_string_by_index(idx)
+                #lookup
             } else {
                 None
             };
         }
+    };
+
+    Ok(ctor)
+}
+
+// A non-zero index past the string set is a firmware bug, not an absent field.
+fn dangling_string_warning(field: &Field, struct_name: &Ident) -> proc_macro2::TokenStream {
+    let func_name = field.ident.as_ref().unwrap();
+    quote! {
+        warnings.push(format!(
+            "{}: {} references string {} but only {} present",
+            stringify!(#struct_name),
+            stringify!(#func_name),
+            idx,
+            raw.tailer.len()
+        ));
     }
 }
 
-fn field_ctor_struct(field: &Field, tydef: &TypeDef) -> proc_macro2::TokenStream {
+fn field_ctor_struct(
+    field: &Field,
+    tydef: &TypeDef,
+    parent_name: &Ident,
+    tail: usize,
+) -> Result<proc_macro2::TokenStream> {
     let func_name = &field.ident.as_ref().unwrap();
     let struct_name = &tydef.ident;
 
-    if tydef.vector {
-        let length = get_vec_length(field);
-        quote! {
-            let #func_name = if let Some(len) = #length {
-                let len = len as usize;
-                if body.remaining() >= len  {
-                    let mut v = vec![];
-                    for _ in 0..len {
-                        let value = #struct_name::from_raw(&mut body, raw);
-                        v.push(value);
-                    }
-                    Some(v)
-                } else {
-                    None
+    let ctor = if tydef.vector {
+        let length = get_vec_length(field)?;
+        let unit_size = quote! { #struct_name::RAW_SIZE.unwrap_or(1) };
+        // Variable-size records are only counted as one byte each, so stop
+        // once the body is exhausted or a record reads nothing.
+        let read = quote! {
+            for _ in 0..fit {
+                let before = body.remaining();
+                if before == 0 {
+                    break;
                 }
-            } else {
-                None
-            };
-        }
+                let value = #struct_name::from_raw(&mut body, raw);
+                if body.remaining() == before {
+                    break;
+                }
+                v.push(value);
+            }
+            let fit = v.len();
+        };
+        vec_ctor(
+            field,
+            parent_name,
+            &length,
+            Some(unit_size),
+            &read,
+            tail,
+            "entries",
+        )
     } else {
         quote! {
             let #func_name = if body.remaining() >= 1 {
@@ -303,22 +1466,160 @@ fn field_ctor_struct(field: &Field, tydef: &TypeDef) -> proc_macro2::TokenStream
                 None
             };
         }
+    };
+
+    Ok(ctor)
+}
+
+fn field_ctor_enum(field: &Field, tydef: &TypeDef) -> Result<proc_macro2::TokenStream> {
+    let func_name = &field.ident.as_ref().unwrap();
+    let enum_name = &tydef.ident;
+
+    if !tydef.optional || tydef.enumerable() || is_copy_trait(enum_name) {
+        return Err(Error::new(
+            field.ty.span(),
+            "`enum` is only supported on Option<T> fields where T derives SmbiosEnum",
+        ));
     }
+
+    Ok(quote! {
+        let #func_name = if body.remaining() >= 1 {
+            Some(<#enum_name as SmbiosEnum>::from_raw(body.get_u8() as u64))
+        } else {
+            None
+        };
+    })
 }
 
-fn get_vec_length(field: &Field) -> proc_macro2::TokenStream {
-    for attr in field.attrs.iter().filter(|a| a.path().is_ident("smbios")) {
+fn field_ctor_bit(
+    field: &Field,
+    tydef: &TypeDef,
+    byte_field: Option<&Ident>,
+    bit: usize,
+) -> Result<proc_macro2::TokenStream> {
+    let func_name = &field.ident.as_ref().unwrap();
+
+    if !is_bool(&tydef.ident) || !tydef.optional || tydef.enumerable() {
+        return Err(Error::new(
+            field.ty.span(),
+            "`bit` is only supported on Option<bool> fields",
+        ));
+    }
+
+    if bit > 7 {
+        return Err(Error::new(field.span(), "`bit` must be in 0..=7"));
+    }
+
+    let byte_field = byte_field.ok_or_else(|| {
+        Error::new(
+            field.span(),
+            "`bit` needs a preceding Option<u8> field to read from",
+        )
+    })?;
+
+    Ok(quote! {
+        let #func_name = #byte_field.map(|b| b & (1 << #bit) != 0);
+    })
+}
+
+fn field_ctor_string_set(field: &Field, struct_name: &Ident) -> Result<proc_macro2::TokenStream> {
+    let func_name = &field.ident.as_ref().unwrap();
+    let length = get_vec_length(field)?;
+    Ok(quote! {
+        let #func_name = if let Some(len) = #length {
+            let mut v = vec![];
+            for idx in 1..=len.min(u8::MAX as usize) {
+                match raw.get_string_by_index(idx as u8) {
+                    Some(value) => v.push(value),
+                    None => warnings.push(format!(
+                        "{}: string {} of {} is missing",
+                        stringify!(#struct_name),
+                        idx,
+                        len
+                    )),
+                }
+            }
+            v
+        } else {
+            vec![]
+        };
+    })
+}
+
+fn has_flag(attrs: &[Attribute], name: &str) -> bool {
+    attrs
+        .iter()
+        .filter(|a| a.path().is_ident("smbios"))
+        .any(|attr| match &attr.meta {
+            syn::Meta::List(list) => list.tokens.clone().into_iter().any(|arg| match arg {
+                proc_macro2::TokenTree::Ident(i) => i == name,
+                _ => false,
+            }),
+            _ => false,
+        })
+}
+
+fn get_vec_length(field: &Field) -> Result<proc_macro2::TokenStream> {
+    if let Some(lit) = get_attr_str(&field.attrs, "length")? {
+        return length_expr(&lit);
+    }
+
+    if let Some(lit) = get_attr_str(&field.attrs, "length_bytes")? {
+        return Err(Error::new(
+            lit.span(),
+            "`length_bytes` is only supported on a Vec of integers",
+        ));
+    }
+
+    Err(Error::new(
+        field.span(),
+        format!(
+            "field `{}` needs `#[smbios(length = \"...\")]`",
+            field.ident.as_ref().unwrap()
+        ),
+    ))
+}
+
+fn get_vec_length_bytes(field: &Field) -> Result<Option<proc_macro2::TokenStream>> {
+    let lit = match get_attr_str(&field.attrs, "length_bytes")? {
+        Some(lit) => lit,
+        None => return Ok(None),
+    };
+
+    if get_attr_str(&field.attrs, "length")?.is_some() {
+        return Err(Error::new(
+            lit.span(),
+            "`length` and `length_bytes` cannot be combined",
+        ));
+    }
+
+    length_expr(&lit).map(Some)
+}
+
+fn length_expr(lit: &LitStr) -> Result<proc_macro2::TokenStream> {
+    let expr: Expr = lit.parse()?;
+    // Type errors in the expression are reported at the attribute.
+    Ok(quote_spanned! {lit.span()=>
+        {
+            fn vec_length<T: Into<usize>>(len: Option<T>) -> Option<usize> {
+                len.map(Into::into)
+            }
+            vec_length(#expr)
+        }
+    })
+}
+
+fn get_attr_value(attrs: &[Attribute], name: &str) -> Option<proc_macro2::Literal> {
+    for attr in attrs.iter().filter(|a| a.path().is_ident("smbios")) {
         if let syn::Meta::List(list) = &attr.meta {
             let mut args = list.tokens.clone().into_iter();
             while let Some(arg) = args.next() {
                 if let proc_macro2::TokenTree::Ident(i) = arg {
-                    if i == "length" {
+                    if i == name {
                         if let Some(proc_macro2::TokenTree::Punct(op)) = args.next() {
                             if op.as_char() == '=' {
                                 if let Some(proc_macro2::TokenTree::Literal(value)) = args.next() {
-                                    let expr = &value.to_string().replace('"', "");
-                                    let stream = proc_macro2::TokenStream::from_str(expr).unwrap();
-                                    return quote! { #stream };
+                                    return Some(value);
                                 }
                             }
                         }
@@ -328,10 +1629,107 @@ fn get_vec_length(field: &Field) -> proc_macro2::TokenStream {
         }
     }
 
-    unimplemented!(
-        "Need Attribute `length` to Field `{}`",
-        field.ident.as_ref().unwrap().to_string()
-    );
+    None
+}
+
+fn get_attr_list(attrs: &[Attribute], name: &str) -> Result<Option<Vec<LitStr>>> {
+    for attr in attrs.iter().filter(|a| a.path().is_ident("smbios")) {
+        if let syn::Meta::List(list) = &attr.meta {
+            let mut args = list.tokens.clone().into_iter();
+            while let Some(arg) = args.next() {
+                if let proc_macro2::TokenTree::Ident(i) = arg {
+                    if i == name {
+                        let invalid = || {
+                            Error::new(
+                                i.span(),
+                                format!("`{}` expects a list of string literals", name),
+                            )
+                        };
+                        return match args.next() {
+                            Some(proc_macro2::TokenTree::Group(group))
+                                if group.delimiter() == proc_macro2::Delimiter::Parenthesis =>
+                            {
+                                let parser = Punctuated::<LitStr, Token![,]>::parse_terminated;
+                                parser
+                                    .parse2(group.stream())
+                                    .map(|names| Some(names.into_iter().collect()))
+                            }
+                            _ => Err(invalid()),
+                        };
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+fn get_attr_str(attrs: &[Attribute], name: &str) -> Result<Option<LitStr>> {
+    match get_attr_value(attrs, name) {
+        Some(value) => {
+            let span = value.span();
+            syn::parse2(proc_macro2::TokenTree::Literal(value).into())
+                .map(Some)
+                .map_err(|_| Error::new(span, format!("`{}` expects a string literal", name)))
+        }
+        None => Ok(None),
+    }
+}
+
+fn get_attr_int<N>(attrs: &[Attribute], name: &str) -> Result<Option<N>>
+where
+    N: FromStr,
+    N::Err: std::fmt::Display,
+{
+    match get_attr_value(attrs, name) {
+        Some(value) => {
+            let span = value.span();
+            let lit: LitInt = syn::parse2(proc_macro2::TokenTree::Literal(value).into())
+                .map_err(|_| Error::new(span, format!("`{}` expects an integer literal", name)))?;
+            lit.base10_parse().map(Some)
+        }
+        None => Ok(None),
+    }
+}
+
+fn get_min_lengths(attrs: &[Attribute]) -> Result<Vec<((u8, u8), u8)>> {
+    let mut lengths = vec![];
+    for attr in attrs.iter().filter(|a| a.path().is_ident("smbios")) {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("min_length") {
+                return Err(meta.error("unsupported struct attribute"));
+            }
+
+            meta.parse_nested_meta(|entry| {
+                let key = entry.path.require_ident()?;
+                let version = key
+                    .to_string()
+                    .strip_prefix('v')
+                    .and_then(|v| v.split_once('_'))
+                    .and_then(|(major, minor)| {
+                        Some((u8::from_str(major).ok()?, u8::from_str(minor).ok()?))
+                    })
+                    .ok_or_else(|| Error::new(key.span(), "expected a version key like `v2_3`"))?;
+                let length: LitInt = entry.value()?.parse()?;
+                lengths.push((version, length.base10_parse::<u8>()?));
+                Ok(())
+            })
+        })?;
+    }
+
+    lengths.sort();
+    Ok(lengths)
+}
+
+fn parse_version(version: &LitStr) -> Result<(u8, u8)> {
+    let value = version.value();
+    let invalid = || Error::new(version.span(), format!("invalid version `{}`", value));
+    let (major, minor) = value.split_once('.').ok_or_else(invalid)?;
+    Ok((
+        u8::from_str(major).map_err(|_| invalid())?,
+        u8::from_str(minor).map_err(|_| invalid())?,
+    ))
 }
 
 fn get_array_len(len: &Expr) -> Option<i32> {
@@ -344,29 +1742,31 @@ fn get_array_len(len: &Expr) -> Option<i32> {
     None
 }
 
-fn get_type_def(ty: &Type) -> TypeDef {
+fn get_type_def(ty: &Type) -> Result<TypeDef> {
     match ty {
         Type::Array(a) => {
-            let mut def = get_type_def(&a.elem);
-            def.array_length = get_array_len(&a.len).unwrap();
-            def
+            let mut def = get_type_def(&a.elem)?;
+            def.array_length = get_array_len(&a.len).ok_or_else(|| {
+                Error::new(a.len.span(), "array length must be an integer literal")
+            })?;
+            Ok(def)
         }
         Type::Path(p) => match p.path.get_ident() {
             Some(i) => {
                 let copy_trait = is_copy_trait(i);
-                TypeDef {
+                Ok(TypeDef {
                     ident: i.clone(),
                     array_length: -1,
                     vector: false,
                     optional: false,
                     copy_trait,
-                }
+                })
             }
             _ => {
                 let q_ty = &p.path.segments[0].ident;
                 if let PathArguments::AngleBracketed(arg) = &p.path.segments[0].arguments {
-                    if let GenericArgument::Type(arg_ty) = &arg.args[0] {
-                        let mut def = get_type_def(arg_ty);
+                    if let Some(GenericArgument::Type(arg_ty)) = arg.args.first() {
+                        let mut def = get_type_def(arg_ty)?;
 
                         if is_vector(q_ty) {
                             def.vector = true;
@@ -376,26 +1776,52 @@ fn get_type_def(ty: &Type) -> TypeDef {
                             def.optional = true;
                         }
 
-                        return def;
+                        return Ok(def);
                     }
                 }
 
-                unimplemented!("Not supported yet. Type `{}`", q_ty.to_string());
+                Err(Error::new(
+                    ty.span(),
+                    format!("unsupported field type `{}`", q_ty),
+                ))
             }
         },
-        _ => {
-            unimplemented!("Not supported yet.");
-        }
+        _ => Err(Error::new(ty.span(), "unsupported field type")),
+    }
+}
+
+fn int_byte_size(ident: &Ident) -> Option<usize> {
+    match ident.to_string().as_str() {
+        "u8" | "i8" => Some(1),
+        "u16" | "i16" => Some(2),
+        "u32" | "i32" => Some(4),
+        "u64" | "i64" => Some(8),
+        _ => None,
     }
 }
 
 fn is_copy_trait(ident: &Ident) -> bool {
     matches!(
         ident.to_string().as_str(),
-        "u8" | "u16" | "u32" | "u64" | "i8" | "i16" | "i32" | "i64"
+        "u8" | "u16" | "u32" | "u64" | "i8" | "i16" | "i32" | "i64" | "bool"
+    )
+}
+
+fn is_primitive(ident: &Ident) -> bool {
+    matches!(
+        ident.to_string().as_str(),
+        "char" | "f32" | "f64" | "u128" | "i128" | "usize" | "isize" | "str"
     )
 }
 
+fn is_smbios_string(ident: &Ident) -> bool {
+    is_type(ident, "SmbiosString")
+}
+
+fn is_bool(ident: &Ident) -> bool {
+    is_type(ident, "bool")
+}
+
 fn is_optional(ident: &Ident) -> bool {
     is_type(ident, "Option")
 }
@@ -0,0 +1,206 @@
+use bytes::{Buf, Bytes};
+use smbios::error::ParseError;
+use smbios::*;
+use smbios_derive::{SmbiosEnum, SMBIOS};
+
+#[path = "../../smbios/tests/common/mod.rs"]
+mod common;
+
+use common::raw_table;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, SmbiosEnum)]
+pub enum Kind {
+    #[smbios(value = 0x01)]
+    Other,
+    #[smbios(value = 0x02, name = "Unknown")]
+    UnknownKind,
+    #[smbios(value = 0x03, name = "Rack Mount")]
+    RackMount,
+    Unknown(u64),
+}
+
+#[derive(SMBIOS)]
+pub struct Entry {
+    kind: Option<u8>,
+    handle: Option<u16>,
+}
+
+#[derive(SMBIOS)]
+pub struct Sample {
+    table_ty: u8,
+    length: u8,
+    handle: u16,
+    #[smbios(label = "Name")]
+    name: Option<SmbiosString>,
+    #[smbios(enum, label = "Kind")]
+    kind: Option<Kind>,
+    #[smbios(label = "Address", fmt = "hex")]
+    address: Option<u16>,
+    #[smbios(width = 3)]
+    size: Option<u32>,
+    #[smbios(bitflags("Reserved", "Enabled", "Locked"))]
+    #[smbios(label = "Flags", with = "flags_str")]
+    flags: Option<u8>,
+    #[smbios(bit = 1)]
+    enabled: Option<bool>,
+    names: Option<[String; 2]>,
+    count: Option<u8>,
+    #[smbios(length = "count")]
+    entries: Option<Vec<Entry>>,
+    #[smbios(skip = 1, remaining)]
+    trailer: Option<Vec<u16>>,
+}
+
+fn sample_body() -> Vec<u8> {
+    vec![
+        0x01, // name
+        0x03, // kind
+        0x00, 0xE0, // address
+        0xEF, 0xCD, 0xAB, // size
+        0x06, // flags
+        0x02, 0x01, // names
+        0x02, // count
+        0x05, 0x01, 0x00, // entries[0]
+        0x06, 0x02, 0x00, // entries[1]
+        0xFF, // reserved
+        0x11, 0x00, 0x22, 0x00, // trailer
+    ]
+}
+
+#[test]
+fn decode_sample() {
+    let raw = raw_table(0xC0, &sample_body(), &["first", "second"]);
+    let table = Sample::try_from_raw_table(&raw).unwrap();
+
+    assert_eq!(table.table_ty(), 0xC0);
+    assert_eq!(table.handle(), 0x0100);
+    assert_eq!(table.name(), Some("first"));
+    assert_eq!(table.name_index(), Some(1));
+    assert_eq!(table.kind(), Some(Kind::RackMount));
+    assert_eq!(table.address(), Some(0xE000));
+    assert_eq!(table.size(), Some(0xABCDEF));
+    assert_eq!(table.enabled(), Some(true));
+    assert_eq!(
+        table.names(),
+        Some(&["second".to_string(), "first".to_string()][..])
+    );
+
+    let entries = table.entries().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].kind(), Some(5));
+    assert_eq!(entries[0].handle(), Some(1));
+    assert_eq!(entries[1].kind(), Some(6));
+    assert_eq!(entries[1].handle(), Some(2));
+
+    assert_eq!(table.trailer(), Some(&[0x11, 0x22][..]));
+}
+
+#[test]
+fn decode_generated_helpers() {
+    let raw = raw_table(0xC0, &sample_body(), &["first", "second"]);
+    let table = Sample::from_raw_table(&raw);
+
+    assert_eq!(
+        table.flags_flags().collect::<Vec<_>>(),
+        vec!["Enabled", "Locked"]
+    );
+    assert_eq!(
+        table.fields(),
+        vec![
+            ("Name", FieldValue::Text("first".to_string())),
+            ("Kind", FieldValue::Text("Rack Mount".to_string())),
+            ("Address", FieldValue::Text("0xE000".to_string())),
+            (
+                "Flags",
+                FieldValue::List(vec!["Enabled".to_string(), "Locked".to_string()])
+            ),
+        ]
+    );
+}
+
+#[test]
+fn decode_enum() {
+    assert_eq!(Kind::from_raw(0x01), Kind::Other);
+    assert_eq!(Kind::from_raw(0x7F), Kind::Unknown(0x7F));
+    assert_eq!(Kind::UnknownKind.to_raw(), 0x02);
+    assert_eq!(Kind::UnknownKind.to_string(), "Unknown");
+    assert_eq!(Kind::Unknown(0x7F).to_string(), "Unknown (0x7F)");
+}
+
+#[test]
+fn decode_dangling_string() {
+    let mut body = sample_body();
+    body[0] = 0x05;
+    let raw = raw_table(0xC0, &body, &["first", "second"]);
+    let table = Sample::from_raw_table(&raw);
+
+    assert_eq!(table.name(), None);
+    assert_eq!(table.name_index(), Some(5));
+}
+
+#[test]
+fn decode_truncated() {
+    let body = sample_body();
+    let raw = raw_table(0xC0, &body[..5], &[]);
+
+    let table = Sample::from_raw_table(&raw);
+    assert_eq!(table.address(), Some(0xE000));
+    assert_eq!(table.size(), None);
+    assert!(table.entries().is_none());
+
+    assert_eq!(
+        Sample::try_from_raw_table(&raw).err(),
+        Some(ParseError::FieldTruncated {
+            structure: "Sample",
+            field: "size",
+            offset: 8,
+            length: 9,
+        })
+    );
+}
+
+#[test]
+fn build_sample() {
+    let table = Sample::default()
+        .with_flags(0x04)
+        .with_enabled(true)
+        .with_kind(Kind::Other)
+        .with_entries(vec![Entry::default().with_kind(7)]);
+
+    assert_eq!(table.flags(), Some(0x06));
+    assert_eq!(table.enabled(), Some(true));
+    assert_eq!(table.kind(), Some(Kind::Other));
+    assert_eq!(table.entries().map(|e| e[0].kind()), Some(Some(7)));
+    assert_eq!(table.entries().map(|e| e[0].handle()), Some(None));
+
+    let table = table.with_enabled(false);
+    assert_eq!(table.flags(), Some(0x04));
+    assert_eq!(table.enabled(), Some(false));
+}
+
+#[derive(SMBIOS)]
+pub struct Versioned {
+    table_ty: u8,
+    length: u8,
+    handle: u16,
+    base: Option<u8>,
+    #[smbios(min_version = "3.0")]
+    extra: Option<u8>,
+}
+
+#[test]
+fn decode_versioned() {
+    let raw = raw_table(0xC1, &[0x01, 0x02], &[]);
+    let data =
+        |major, minor| RawSmbiosData::from(&mut Bytes::from(vec![0, major, minor, 0, 0, 0, 0, 0]));
+
+    let table = Versioned::from_raw_table_versioned(&raw, &data(2, 8));
+    assert_eq!(table.base(), Some(0x01));
+    assert_eq!(table.extra(), None);
+
+    let table = Versioned::from_raw_table_versioned(&raw, &data(3, 0));
+    assert_eq!(table.extra(), Some(0x02));
+
+    let table = Versioned::from_raw_table(&raw);
+    assert_eq!(table.extra(), Some(0x02));
+}
@@ -0,0 +1,17 @@
+#![allow(unused_imports)]
+
+use bytes::{Buf, Bytes};
+use smbios::error::ParseError;
+use smbios::*;
+use smbios_derive::SMBIOS;
+
+#[derive(SMBIOS)]
+pub struct BitWithoutByte {
+    table_ty: u8,
+    length: u8,
+    handle: u16,
+    #[smbios(bit = 0)]
+    flag: Option<bool>,
+}
+
+fn main() {}
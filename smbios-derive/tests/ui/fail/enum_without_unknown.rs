@@ -0,0 +1,9 @@
+use smbios_derive::SmbiosEnum;
+
+#[derive(SmbiosEnum)]
+pub enum Kind {
+    #[smbios(value = 0x01)]
+    Other,
+}
+
+fn main() {}
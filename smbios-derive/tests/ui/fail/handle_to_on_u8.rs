@@ -0,0 +1,17 @@
+#![allow(unused_imports)]
+
+use bytes::{Buf, Bytes};
+use smbios::error::ParseError;
+use smbios::*;
+use smbios_derive::SMBIOS;
+
+#[derive(SMBIOS)]
+pub struct HandleToOnU8 {
+    table_ty: u8,
+    length: u8,
+    handle: u16,
+    #[smbios(handle_to = "Cache")]
+    cache_handle: Option<u8>,
+}
+
+fn main() {}
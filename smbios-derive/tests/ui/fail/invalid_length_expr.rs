@@ -0,0 +1,18 @@
+#![allow(unused_imports)]
+
+use bytes::{Buf, Bytes};
+use smbios::error::ParseError;
+use smbios::*;
+use smbios_derive::SMBIOS;
+
+#[derive(SMBIOS)]
+pub struct InvalidLengthExpr {
+    table_ty: u8,
+    length: u8,
+    handle: u16,
+    count: Option<u8>,
+    #[smbios(length = "count +")]
+    handles: Option<Vec<u16>>,
+}
+
+fn main() {}
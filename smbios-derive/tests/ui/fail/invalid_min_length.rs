@@ -0,0 +1,17 @@
+#![allow(unused_imports)]
+
+use bytes::{Buf, Bytes};
+use smbios::error::ParseError;
+use smbios::*;
+use smbios_derive::SMBIOS;
+
+#[derive(SMBIOS)]
+#[smbios(min_length(v2 = 0x08))]
+pub struct InvalidMinLength {
+    table_ty: u8,
+    length: u8,
+    handle: u16,
+    value: Option<u16>,
+}
+
+fn main() {}
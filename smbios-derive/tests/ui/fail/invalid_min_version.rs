@@ -0,0 +1,17 @@
+#![allow(unused_imports)]
+
+use bytes::{Buf, Bytes};
+use smbios::error::ParseError;
+use smbios::*;
+use smbios_derive::SMBIOS;
+
+#[derive(SMBIOS)]
+pub struct InvalidMinVersion {
+    table_ty: u8,
+    length: u8,
+    handle: u16,
+    #[smbios(min_version = "2")]
+    value: Option<u16>,
+}
+
+fn main() {}
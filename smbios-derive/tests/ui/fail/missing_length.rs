@@ -0,0 +1,16 @@
+#![allow(unused_imports)]
+
+use bytes::{Buf, Bytes};
+use smbios::error::ParseError;
+use smbios::*;
+use smbios_derive::SMBIOS;
+
+#[derive(SMBIOS)]
+pub struct MissingLength {
+    table_ty: u8,
+    length: u8,
+    handle: u16,
+    handles: Option<Vec<u16>>,
+}
+
+fn main() {}
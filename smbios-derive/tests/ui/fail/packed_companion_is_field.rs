@@ -0,0 +1,18 @@
+#![allow(unused_imports)]
+
+use bytes::{Buf, Bytes};
+use smbios::error::ParseError;
+use smbios::*;
+use smbios_derive::SMBIOS;
+
+#[derive(SMBIOS)]
+pub struct PackedCompanionIsField {
+    table_ty: u8,
+    length: u8,
+    handle: u16,
+    #[smbios(packed("status"))]
+    value: Option<u8>,
+    status: Option<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#![allow(unused_imports)]
+
+use bytes::{Buf, Bytes};
+use smbios::error::ParseError;
+use smbios::*;
+use smbios_derive::SMBIOS;
+
+#[derive(SMBIOS)]
+pub struct PackedWithoutCompanions {
+    table_ty: u8,
+    length: u8,
+    handle: u16,
+    #[smbios(packed())]
+    value: Option<u8>,
+}
+
+fn main() {}
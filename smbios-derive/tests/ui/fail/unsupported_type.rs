@@ -0,0 +1,16 @@
+#![allow(unused_imports)]
+
+use bytes::{Buf, Bytes};
+use smbios::error::ParseError;
+use smbios::*;
+use smbios_derive::SMBIOS;
+
+#[derive(SMBIOS)]
+pub struct UnsupportedType {
+    table_ty: u8,
+    length: u8,
+    handle: u16,
+    ratio: Option<f32>,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#![allow(unused_imports)]
+
+use bytes::{Buf, Bytes};
+use smbios::error::ParseError;
+use smbios::*;
+use smbios_derive::SMBIOS;
+
+#[derive(SMBIOS)]
+pub struct WidthOnU16 {
+    table_ty: u8,
+    length: u8,
+    handle: u16,
+    #[smbios(width = 3)]
+    value: Option<u16>,
+}
+
+fn main() {}
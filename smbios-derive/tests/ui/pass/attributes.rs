@@ -0,0 +1,51 @@
+#![allow(unused_imports)]
+
+use bytes::{Buf, Bytes};
+use smbios::error::ParseError;
+use smbios::*;
+use smbios_derive::{SmbiosEnum, SMBIOS};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, SmbiosEnum)]
+pub enum Kind {
+    #[smbios(value = 0x01)]
+    Other,
+    #[smbios(value = 0x02, name = "Unknown")]
+    UnknownKind,
+    Unknown(u64),
+}
+
+#[derive(SMBIOS)]
+pub struct Attributes {
+    table_ty: u8,
+    length: u8,
+    handle: u16,
+    #[smbios(label = "Name")]
+    name: Option<SmbiosString>,
+    #[smbios(enum, label = "Kind")]
+    kind: Option<Kind>,
+    #[smbios(packed("size_kb"), label = "Address", fmt = "hex")]
+    #[smbios(label = "Size", with = "size_kb", unit = " kB")]
+    address: Option<u16>,
+    #[smbios(bitflags("Reserved", "Enabled", "Locked"))]
+    #[smbios(label = "Flags", with = "flags_str")]
+    flags: Option<u8>,
+    #[smbios(min_version = "2.7")]
+    extended: Option<u32>,
+}
+
+impl Attributes {
+    fn size_kb(&self) -> Option<u32> {
+        self.address().map(|a| a as u32 * 16 / 1024)
+    }
+}
+
+fn main() {
+    let _: &[&str] = Attributes::FIELD_NAMES;
+    let _: &[&str] = Attributes::PACKED_FIELDS;
+    let _: &[(&str, &[&str])] = Attributes::PACKED_COMPANIONS;
+    let _: fn(&Attributes) -> Option<Kind> = Attributes::kind;
+    let _: fn(&Attributes) -> Option<Vec<String>> = Attributes::flags_str;
+    let _: fn(&Attributes) -> Vec<(&'static str, FieldValue)> = Attributes::fields;
+    let _: fn(&RawSmbiosTable, &RawSmbiosData) -> Attributes = Attributes::from_raw_table_versioned;
+    let _ = Kind::from_raw(1).to_raw();
+}
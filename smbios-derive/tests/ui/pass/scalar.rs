@@ -0,0 +1,37 @@
+#![allow(unused_imports)]
+
+use bytes::{Buf, Bytes};
+use smbios::error::ParseError;
+use smbios::*;
+use smbios_derive::SMBIOS;
+
+#[derive(SMBIOS)]
+pub struct Scalars {
+    table_ty: u8,
+    length: u8,
+    handle: u16,
+    byte: Option<u8>,
+    word: Option<u16>,
+    dword: Option<u32>,
+    qword: Option<u64>,
+    signed_byte: Option<i8>,
+    signed_word: Option<i16>,
+    signed_dword: Option<i32>,
+    signed_qword: Option<i64>,
+    present: Option<bool>,
+    #[smbios(width = 3)]
+    packed: Option<u32>,
+    flags: Option<u8>,
+    #[smbios(bit = 0)]
+    flag0: Option<bool>,
+    #[smbios(bit = 7)]
+    flag7: Option<bool>,
+}
+
+fn main() {
+    let _: fn(&RawSmbiosTable) -> Scalars = Scalars::from_raw_table;
+    let _: fn(&RawSmbiosTable) -> Result<Scalars, ParseError> = Scalars::try_from_raw_table;
+    let _: fn(&Scalars) -> u16 = Scalars::handle;
+    let _: fn(&Scalars) -> Option<u32> = Scalars::packed;
+    let _: fn(&Scalars) -> Option<bool> = Scalars::flag7;
+}
@@ -0,0 +1,39 @@
+#![allow(unused_imports)]
+
+use bytes::{Buf, Bytes};
+use smbios::error::ParseError;
+use smbios::*;
+use smbios_derive::SMBIOS;
+
+#[derive(SMBIOS)]
+pub struct Strings {
+    table_ty: u8,
+    length: u8,
+    handle: u16,
+    indexed: Option<SmbiosString>,
+    plain: Option<String>,
+    pair: Option<[String; 2]>,
+    count: Option<u8>,
+    #[smbios(length = "count")]
+    listed: Option<Vec<String>>,
+}
+
+#[derive(SMBIOS)]
+pub struct StringSet {
+    table_ty: u8,
+    length: u8,
+    handle: u16,
+    count: Option<u8>,
+    #[smbios(strings, length = "count")]
+    strings: Vec<String>,
+}
+
+fn main() {
+    let _: fn(&Strings) -> Option<&str> = Strings::indexed;
+    let _: fn(&Strings) -> Option<u8> = Strings::indexed_index;
+    let _: fn(&Strings) -> Option<&str> = Strings::plain;
+    let _: fn(&Strings) -> Option<&[String]> = Strings::pair;
+    let _: fn(&Strings) -> Option<&[String]> = Strings::listed;
+    let _: fn(&StringSet) -> &[String] = StringSet::strings;
+    let _: fn(&RawSmbiosTable) -> (StringSet, Vec<String>) = StringSet::from_raw_table_with_warnings;
+}
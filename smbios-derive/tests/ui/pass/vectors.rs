@@ -0,0 +1,42 @@
+#![allow(unused_imports)]
+
+use bytes::{Buf, Bytes};
+use smbios::error::ParseError;
+use smbios::*;
+use smbios_derive::SMBIOS;
+
+#[derive(SMBIOS)]
+pub struct Record {
+    kind: Option<u8>,
+    size: Option<u8>,
+    #[smbios(length_bytes = "size")]
+    data: Option<Vec<u8>>,
+}
+
+#[derive(SMBIOS)]
+pub struct Vectors {
+    table_ty: u8,
+    length: u8,
+    handle: u16,
+    uuid: Option<[u8; 16]>,
+    words: Option<[u16; 2]>,
+    count: Option<u8>,
+    #[smbios(length = "count")]
+    handles: Option<Vec<u16>>,
+    #[smbios(length_bytes = "count.map(|c| c as usize * 2)")]
+    loads: Option<Vec<u16>>,
+    #[smbios(length = "count")]
+    records: Option<Vec<Record>>,
+    #[smbios(skip = 2, remaining)]
+    rest: Option<Vec<u16>>,
+}
+
+fn main() {
+    let _: fn(&Vectors) -> Option<&[u8]> = Vectors::uuid;
+    let _: fn(&Vectors) -> Option<&[u16]> = Vectors::words;
+    let _: fn(&Vectors) -> Option<&[u16]> = Vectors::handles;
+    let _: fn(&Vectors) -> Option<&[Record]> = Vectors::records;
+    let _: fn(&Vectors) -> Option<&[u16]> = Vectors::rest;
+    let _: fn(&Record) -> Option<&[u8]> = Record::data;
+    let _: fn(&mut Bytes, &RawSmbiosTable) -> Record = Record::from_raw;
+}
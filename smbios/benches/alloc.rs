@@ -0,0 +1,120 @@
+use bytes::Bytes;
+use smbios::{RawSmbiosData, SmbiosTables};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn structure(table_ty: u8, body: &[u8], strings: &[&str]) -> Vec<u8> {
+    let mut bytes = vec![table_ty, (body.len() + 4) as u8, 0x00, 0x01];
+    bytes.extend_from_slice(body);
+    for s in strings {
+        bytes.extend_from_slice(s.as_bytes());
+        bytes.push(0);
+    }
+    bytes.push(0);
+    bytes
+}
+
+// A server-sized table: a handful of system structures plus many DIMMs.
+fn table_data() -> Vec<u8> {
+    let mut data = vec![];
+    data.extend(structure(
+        0,
+        &[1, 2, 0, 0, 3, 0],
+        &["Vendor", "1.0.0", "01/01/2024"],
+    ));
+    data.extend(structure(
+        1,
+        &[
+            1, 2, 3, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 6,
+        ],
+        &["Acme", "Server", "1.0", "SN0001", "SKU", "Family"],
+    ));
+    data.extend(structure(
+        2,
+        &[1, 2, 3, 4, 5],
+        &["Acme", "Board", "1.0", "BSN", "Tag"],
+    ));
+    for i in 0..64u8 {
+        let mut body = vec![0; 0x24];
+        body[0x0C] = 1;
+        body[0x0D] = 2;
+        body[0x13] = 3;
+        body[0x14] = 4;
+        body[0x15] = 5;
+        body[0x16] = 6;
+        let locator = format!("DIMM {}", i);
+        let serial = format!("{:08X}", i as u32 * 0x1111);
+        data.extend(structure(
+            17,
+            &body,
+            &[
+                &locator,
+                "BANK 0",
+                "Micron",
+                &serial,
+                "ASSET",
+                "36ASF4G72PZ",
+            ],
+        ));
+    }
+    data.extend(structure(127, &[], &[]));
+    data
+}
+
+fn measure(name: &str, parse: impl Fn(RawSmbiosData) -> SmbiosTables) {
+    let data = table_data();
+    let raw = || {
+        let mut bytes = vec![0x00, 0x03, 0x06, 0x00];
+        bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&data);
+        RawSmbiosData::from(&mut Bytes::from(bytes))
+    };
+
+    let input = raw();
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let tables = parse(input);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    let heap = tables.approx_heap_bytes();
+    drop(tables);
+
+    let iterations = 1000;
+    let start = Instant::now();
+    for _ in 0..iterations {
+        drop(parse(raw()));
+    }
+    let elapsed = start.elapsed() / iterations;
+
+    println!(
+        "{:<8} {:>6} allocations {:>8} heap bytes {:>10.1?} per parse",
+        name, allocations, heap, elapsed
+    );
+}
+
+fn main() {
+    measure("default", SmbiosTables::from);
+    measure("arena", SmbiosTables::from_in_arena);
+}
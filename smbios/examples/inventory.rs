@@ -0,0 +1,96 @@
+use serde::Serialize;
+use smbios::error::Error;
+use smbios::*;
+use std::process::ExitCode;
+
+#[derive(Serialize)]
+struct MemoryDeviceEntry {
+    locator: Option<String>,
+    bank_locator: Option<String>,
+    size_bytes: Option<u64>,
+    memory_type: Option<String>,
+    speed: Option<String>,
+    manufacturer: Option<String>,
+    part_number: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Inventory {
+    meta: SmbiosMeta,
+    bios_vendor: Option<String>,
+    bios_version: Option<String>,
+    system_manufacturer: Option<String>,
+    system_product: Option<String>,
+    system_serial: Option<String>,
+    system_uuid: Option<String>,
+    cpu_sockets: usize,
+    total_enabled_cores: u32,
+    total_memory_bytes: u64,
+    memory_devices: Vec<MemoryDeviceEntry>,
+}
+
+impl Inventory {
+    fn from_tables(tables: &SmbiosTables) -> Self {
+        let mut inventory = Inventory {
+            meta: tables.meta().clone(),
+            bios_vendor: None,
+            bios_version: None,
+            system_manufacturer: None,
+            system_product: None,
+            system_serial: None,
+            system_uuid: None,
+            cpu_sockets: 0,
+            total_enabled_cores: tables.total_enabled_cores(),
+            total_memory_bytes: tables.total_memory_bytes(),
+            memory_devices: vec![],
+        };
+
+        for table in tables.iter() {
+            match table {
+                SmbiosTable::Bios(bios) => {
+                    inventory.bios_vendor = bios.vendor().map(String::from);
+                    inventory.bios_version = bios.bios_version().map(String::from);
+                }
+                SmbiosTable::System(system) => {
+                    inventory.system_manufacturer = system.manufacturer().map(String::from);
+                    inventory.system_product = system.product_name().map(String::from);
+                    inventory.system_serial = system.serial_number().map(String::from);
+                    inventory.system_uuid = system.uuid_string(tables.data());
+                }
+                SmbiosTable::Processor(_) => inventory.cpu_sockets += 1,
+                SmbiosTable::MemoryDevice(device) => {
+                    // Empty slots are part of the inventory too; size_bytes is 0.
+                    inventory.memory_devices.push(MemoryDeviceEntry {
+                        locator: device.device_locator().map(String::from),
+                        bank_locator: device.bank_locator().map(String::from),
+                        size_bytes: device.size_bytes(),
+                        memory_type: device.memory_ty_str(),
+                        speed: device.speed_str(),
+                        manufacturer: device.manufacturer().map(String::from),
+                        part_number: device.part_number().map(String::from),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        inventory
+    }
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let tables = SmbiosTables::from(smbios::get_smbios()?);
+    let inventory = Inventory::from_tables(&tables);
+    println!("{}", serde_json::to_string_pretty(&inventory).unwrap());
+    Ok(())
+}
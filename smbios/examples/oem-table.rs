@@ -0,0 +1,75 @@
+use bytes::{Buf, Bytes};
+use smbios::*;
+
+// A fictional OEM structure (type 0x85):
+//   0x04 BYTE   Asset string
+//   0x05 WORD   Board revision
+//   0x07 BYTE   Flags (bit 0: service mode)
+struct OemAsset {
+    asset: Option<String>,
+    revision: u16,
+    service_mode: bool,
+}
+
+impl OemAsset {
+    const TABLE_TY: u8 = 0x85;
+
+    fn decode(raw: &RawSmbiosTable) -> Option<Self> {
+        if raw.table_ty != Self::TABLE_TY {
+            return None;
+        }
+
+        let mut body = raw.body();
+        if body.remaining() < 4 {
+            return None;
+        }
+
+        let asset = raw.get_string_by_index(body.get_u8());
+        let revision = body.get_u16_le();
+        let service_mode = (body.get_u8() & 0x01) != 0;
+        Some(OemAsset {
+            asset,
+            revision,
+            service_mode,
+        })
+    }
+}
+
+fn main() {
+    // RSMB-style header followed by the structure table.
+    #[rustfmt::skip]
+    let mut data = Bytes::from_static(&[
+        0x00, 0x03, 0x06, 0x00, 0x15, 0x00, 0x00, 0x00,
+        // type 0x85, length 8, handle 0xE000
+        0x85, 0x08, 0x00, 0xE0, 0x01, 0x02, 0x01, 0x01,
+        b'A', b'T', b'-', b'4', b'2', 0x00, 0x00,
+        // End of Table
+        0x7F, 0x04, 0xFF, 0xFF, 0x00, 0x00,
+    ]);
+
+    let tables = SmbiosTables::from(RawSmbiosData::from(&mut data));
+    for table in tables.iter() {
+        if let SmbiosTable::Other(raw) = table {
+            match OemAsset::decode(raw) {
+                Some(oem) => {
+                    println!(
+                        "Handle 0x{:04X}, OEM type 0x{:02X}",
+                        raw.handle, raw.table_ty
+                    );
+                    println!(
+                        "\tAsset: {}",
+                        oem.asset.as_deref().unwrap_or("Not Specified")
+                    );
+                    println!("\tRevision: 0x{:04X}", oem.revision);
+                    println!("\tService Mode: {}", oem.service_mode);
+                }
+                None => println!(
+                    "Handle 0x{:04X}, unhandled type {} ({} bytes)",
+                    raw.handle,
+                    raw.table_ty,
+                    raw.body().len()
+                ),
+            }
+        }
+    }
+}
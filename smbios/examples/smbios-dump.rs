@@ -1,9 +1,11 @@
 use smbios::error::Error;
 use smbios::*;
-use std::io::Write;
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
 
 macro_rules! write_header {
     ($dst: expr, $table: ident) => {
+        $dst.table_ty = $table.table_ty();
         write!(
             $dst,
             "Handle 0x{:04X}, DMI type {}, {} bytes\n",
@@ -16,18 +18,25 @@ macro_rules! write_header {
 
 macro_rules! write_title {
     ($dst: expr, $value: expr) => {
-        write!($dst, "{}\n", $value)?;
+        write!($dst, "{}\n", $dst.paint(TITLE, $value))?;
     };
 }
 
 macro_rules! write_kv {
     ($dst: expr, $key: tt, $value: expr $(, $values: expr)*) => {
         if let Some(v) = $value {
-            write!($dst, "\t{}: {}", $key, v)?;
-            $(
-                write!($dst, "{}", $values)?;
-            )*
-            write!($dst, "\n")?;
+            if $dst.is_redacted(&$key) {
+                write!($dst, "\t{}: {}\n", $dst.paint(KEY, &$key), REDACTED)?;
+            } else {
+                write!($dst, "\t{}: {}", $dst.paint(KEY, &$key), $dst.value(v))?;
+                $(
+                    write!($dst, "{}", $values)?;
+                )*
+                if let Some(note) = $dst.note(&$key) {
+                    write!($dst, " (note: {})", note)?;
+                }
+                write!($dst, "\n")?;
+            }
         }
     };
 }
@@ -35,11 +44,18 @@ macro_rules! write_kv {
 macro_rules! write_format_kv {
     ($dst: expr, $key: tt, $format: literal, $value: expr $(, $values: expr)*) => {
         if let Some(v) = $value {
-            write!($dst, "\t{}: {}", $key, format!($format, v))?;
-            $(
-                write!($dst, "{}", $values)?;
-            )*
-            write!($dst, "\n")?;
+            if $dst.is_redacted(&$key) {
+                write!($dst, "\t{}: {}\n", $dst.paint(KEY, &$key), REDACTED)?;
+            } else {
+                write!($dst, "\t{}: {}", $dst.paint(KEY, &$key), $dst.value(format!($format, v)))?;
+                $(
+                    write!($dst, "{}", $values)?;
+                )*
+                if let Some(note) = $dst.note(&$key) {
+                    write!($dst, " (note: {})", note)?;
+                }
+                write!($dst, "\n")?;
+            }
         }
     };
 }
@@ -48,7 +64,7 @@ macro_rules! write_iter {
     ($dst: expr, $key: tt, $value: expr) => {
         if let Some(iter) = $value {
             if !$key.is_empty() {
-                write!($dst, "\t{}:\n", $key)?;
+                write!($dst, "\t{}:\n", $dst.paint(KEY, &$key))?;
             }
 
             for i in iter {
@@ -62,7 +78,7 @@ macro_rules! write_format_iter {
     ($dst: expr, $key: tt, $format: literal, $value: expr) => {
         if let Some(iter) = $value {
             if !$key.is_empty() {
-                write!($dst, "\t{}:\n", $key)?;
+                write!($dst, "\t{}:\n", $dst.paint(KEY, &$key))?;
             }
 
             for i in iter {
@@ -76,7 +92,7 @@ macro_rules! write_item {
     ($dst: expr, $($value: expr),+) => {
         write!($dst, "\t\t")?;
         $(
-            write!($dst, "{}", $value)?;
+            write!($dst, "{}", $dst.value($value))?;
         )*
         write!($dst, "\n")?;
     };
@@ -92,231 +108,331 @@ macro_rules! write_format_item {
     };
 }
 
-fn main() -> Result<(), Error> {
-    let smbios = smbios::get_smbios()?;
-
-    let mut data = smbios.smbios_table_data.clone();
-    while !data.is_empty() {
-        let table = RawSmbiosTable::from(&mut data);
-        match table.table_ty {
-            0 => dump_type0(&Bios::from_raw_table(&table), &mut std::io::stdout()).unwrap(),
-            1 => dump_type1(
-                &System::from_raw_table(&table),
-                &mut std::io::stdout(),
-                &smbios,
-            )
-            .unwrap(),
-            2 => dump_type2(&BaseBoard::from_raw_table(&table), &mut std::io::stdout()).unwrap(),
-            3 => dump_type3(&Chassis::from_raw_table(&table), &mut std::io::stdout()).unwrap(),
-            4 => dump_type4(
-                &Processor::from_raw_table(&table),
-                &mut std::io::stdout(),
-                &smbios,
-            )
-            .unwrap(),
-            5 => dump_type5(
-                &MemoryController::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            6 => dump_type6(
-                &MemoryModule::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            7 => dump_type7(&Cache::from_raw_table(&table), &mut std::io::stdout()).unwrap(),
-            8 => dump_type8(
-                &PortConnector::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            9 => dump_type9(&SystemSlots::from_raw_table(&table), &mut std::io::stdout()).unwrap(),
-            10 => dump_type10(
-                &OnBoardDevices::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            11 => dump_type11(
-                &OemStrings::from_raw_table(&table),
-                &mut std::io::stdout(),
-                &table,
-            )
-            .unwrap(),
-            12 => dump_type12(
-                &SystemConfigurationOptions::from_raw_table(&table),
-                &mut std::io::stdout(),
-                &table,
-            )
-            .unwrap(),
-            13 => dump_type13(
-                &BiosLanguage::from_raw_table(&table),
-                &mut std::io::stdout(),
-                &table,
-            )
-            .unwrap(),
-            14 => dump_type14(
-                &GroupAssociations::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            15 => dump_type15(
-                &SystemEventLog::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            16 => dump_type16(
-                &PhysicalMemoryArray::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            17 => dump_type17(
-                &MemoryDevice::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            18 => dump_type18(
-                &B32MemoryError::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            19 => dump_type19(
-                &MemoryArrayMappedAddress::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            20 => dump_type20(
-                &MemoryDeviceMappedAddress::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            21 => dump_type21(
-                &BuiltinPointingDevice::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            22 => dump_type22(
-                &PortableBattery::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            23 => {
-                dump_type23(&SystemReset::from_raw_table(&table), &mut std::io::stdout()).unwrap()
+const REDACTED: &str = "[REDACTED]";
+
+// ANSI styles used by `--color`.
+const TITLE: &str = "\x1b[1m";
+const KEY: &str = "\x1b[36m";
+const SENTINEL: &str = "\x1b[33m";
+const RESET: &str = "\x1b[0m";
+
+// Values that mean the firmware did not provide the field.
+fn is_sentinel(value: &str) -> bool {
+    matches!(
+        value,
+        "Unknown" | "Not Present" | "Not Provided" | "Not Specified" | "Unspecified"
+    ) || value.starts_with("Unknown (")
+}
+
+// Field names replaced by `--sanitize`, per table type.
+const REDACTED_FIELDS: &[(u8, &[&str])] = &[
+    (1, &["Serial Number", "UUID"]),
+    (2, &["Serial Number", "Asset Tag"]),
+    (3, &["Serial Number", "Asset Tag"]),
+    (4, &["Serial Number", "Asset Tag"]),
+    (17, &["Serial Number", "Asset Tag"]),
+    (22, &["Serial Number", "SBDS Serial Number"]),
+    (39, &["Serial Number", "Asset Tag"]),
+];
+
+#[derive(Default)]
+enum Color {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl Color {
+    fn from_arg(arg: Option<&str>) -> Option<Self> {
+        match arg? {
+            "auto" => Some(Color::Auto),
+            "always" => Some(Color::Always),
+            "never" => Some(Color::Never),
+            _ => None,
+        }
+    }
+
+    fn enabled(&self) -> bool {
+        match self {
+            Color::Auto => {
+                std::env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal()
             }
-            24 => dump_type24(
-                &HardwareSecurity::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            25 => dump_type25(
-                &SystemPowerControls::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            26 => dump_type26(
-                &VoltageProbe::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            27 => dump_type27(
-                &CoolingDevice::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            28 => dump_type28(
-                &TemperatureProbe::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            29 => dump_type29(
-                &ElectricalCurrentProbe::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            30 => dump_type30(
-                &OutOfBandRemoteAccess::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            32 => dump_type32(&SystemBoot::from_raw_table(&table), &mut std::io::stdout()).unwrap(),
-            33 => dump_type33(
-                &B64MemoryError::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            34 => dump_type34(
-                &ManagementDevice::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            35 => dump_type35(
-                &ManagementDeviceComponent::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            36 => dump_type36(
-                &ManagementDeviceThresholdData::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            37 => dump_type37(
-                &MemoryChannel::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            38 => dump_type38(&IpmiDevice::from_raw_table(&table), &mut std::io::stdout()).unwrap(),
-            39 => dump_type39(
-                &SystemPowerSupply::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            40 => dump_type40(&Additional::from_raw_table(&table), &mut std::io::stdout()).unwrap(),
-            41 => dump_type41(
-                &OnboardDevicesExtended::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            42 => dump_type42(
-                &ManagementControllerHostInterface::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            43 => dump_type43(&TpmDevice::from_raw_table(&table), &mut std::io::stdout()).unwrap(),
-            44 => dump_type44(
-                &ProcessorAdditional::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            45 => dump_type45(
-                &FirmwareInventory::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            46 => dump_type46(
-                &StringProperty::from_raw_table(&table),
-                &mut std::io::stdout(),
-            )
-            .unwrap(),
-            126 => {
-                let mut w = std::io::stdout();
-                let t = Inactive::from_raw_table(&table);
-                write_header!(w, t);
-                write_title!(w, get_table_name_by_id(126).unwrap());
+            Color::Always => true,
+            Color::Never => false,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Options {
+    sanitize: bool,
+    notes: bool,
+    strict: bool,
+    resync: bool,
+    uuid: bool,
+    serial: bool,
+    shell_safe: bool,
+    quote: bool,
+    allow_acpi_fallback: bool,
+    color: Color,
+}
+
+impl Options {
+    fn from_args(mut args: impl Iterator<Item = String>) -> Self {
+        let mut options = Options::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--sanitize" => options.sanitize = true,
+                "--notes" => options.notes = true,
+                "--strict" => options.strict = true,
+                "--resync" => options.resync = true,
+                "--uuid" => options.uuid = true,
+                "--serial" => options.serial = true,
+                "--shell-safe" => options.shell_safe = true,
+                "--quote" => {
+                    options.shell_safe = true;
+                    options.quote = true;
+                }
+                "--allow-acpi-fallback" => options.allow_acpi_fallback = true,
+                "--color" => match Color::from_arg(args.next().as_deref()) {
+                    Some(color) => options.color = color,
+                    None => Self::usage(&arg),
+                },
+                _ => match arg
+                    .strip_prefix("--color=")
+                    .and_then(|c| Color::from_arg(Some(c)))
+                {
+                    Some(color) => options.color = color,
+                    None => Self::usage(&arg),
+                },
             }
-            127 => {
-                let mut w = std::io::stdout();
-                let t = EnfOfTable::from_raw_table(&table);
-                write_header!(w, t);
-                write_title!(w, get_table_name_by_id(127).unwrap());
+        }
+        options
+    }
+
+    fn usage(arg: &str) -> ! {
+        eprintln!("Unknown option: {}", arg);
+        eprintln!(
+            "Usage: smbios-dump [--sanitize] [--notes] [--strict] [--resync] [--uuid] [--serial] [--shell-safe] [--quote] [--allow-acpi-fallback] [--color auto|always|never]"
+        );
+        std::process::exit(2);
+    }
+}
+
+struct Dumper<'a, W: Write> {
+    inner: W,
+    options: &'a Options,
+    table_ty: u8,
+    color: bool,
+}
+
+impl<'a, W: Write> Dumper<'a, W> {
+    fn new(inner: W, options: &'a Options) -> Self {
+        Dumper {
+            inner,
+            options,
+            table_ty: 0,
+            color: options.color.enabled(),
+        }
+    }
+
+    fn paint(&self, style: &str, text: impl std::fmt::Display) -> String {
+        if self.color {
+            format!("{}{}{}", style, text, RESET)
+        } else {
+            text.to_string()
+        }
+    }
+
+    fn value(&self, value: impl std::fmt::Display) -> String {
+        let value = value.to_string();
+        if self.color && is_sentinel(&value) {
+            self.paint(SENTINEL, value)
+        } else {
+            value
+        }
+    }
+
+    fn is_redacted(&self, key: impl AsRef<str>) -> bool {
+        self.options.sanitize
+            && REDACTED_FIELDS
+                .iter()
+                .filter(|(ty, _)| *ty == self.table_ty)
+                .any(|(_, keys)| keys.contains(&key.as_ref()))
+    }
+
+    fn note(&self, key: impl AsRef<str>) -> Option<&'static str> {
+        if self.options.notes {
+            get_field_note(self.table_ty, key.as_ref())
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, W: Write> Write for Dumper<'a, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let options = Options::from_args(std::env::args().skip(1));
+    let mut data = if options.allow_acpi_fallback {
+        smbios::get_smbios_with_acpi_fallback()?
+    } else {
+        smbios::get_smbios()?
+    };
+    data.resync = options.resync;
+    if options.uuid {
+        dump_uuid(&data, &options);
+        return Ok(());
+    }
+    if options.serial {
+        dump_serials(&data, &options);
+        return Ok(());
+    }
+
+    let tables = SmbiosTables::from(data);
+    let smbios = tables.data();
+
+    let mut w = Dumper::new(std::io::stdout(), &options);
+    let mut structures = smbios.structures();
+    for (table, parsed) in structures.by_ref().zip(tables.iter()) {
+        if options.strict && !parsed.length_is_valid(smbios) {
+            eprintln!(
+                "Warning: handle 0x{:04X}, DMI type {}: {} bytes is shorter than SMBIOS {}.{} requires",
+                table.handle,
+                table.table_ty,
+                table.length,
+                smbios.smbios_major_version,
+                smbios.smbios_minior_version
+            );
+        }
+
+        if options.strict {
+            for field in parsed.missing_fields() {
+                eprintln!(
+                    "Warning: handle 0x{:04X}, DMI type {}: `{}` is within the length but failed to decode",
+                    table.handle, table.table_ty, field
+                );
             }
-            _ => dump_raw(&table, &mut std::io::stdout()).unwrap(),
         }
 
+        // Only the string set structures clamp their counts.
+        let warnings = match table.table_ty {
+            11 => OemStrings::from_raw_table_with_warnings(&table).1,
+            12 => SystemConfigurationOptions::from_raw_table_with_warnings(&table).1,
+            13 => BiosLanguage::from_raw_table_with_warnings(&table).1,
+            _ => vec![],
+        };
+        report_warnings(warnings);
+
+        dump_table(parsed, &mut w, &tables).unwrap();
         println!();
     }
 
+    for resync in structures.resyncs() {
+        eprintln!(
+            "Warning: skipped {} bytes at offset 0x{:X} to resync to the next structure",
+            resync.skipped, resync.offset
+        );
+    }
+    if let Some((offset, error)) = structures.error() {
+        eprintln!("Warning: stopped at offset 0x{:X}: {}", offset, error);
+    }
+
     Ok(())
 }
 
+fn report_warnings(warnings: Vec<String>) {
+    for warning in warnings {
+        eprintln!("Warning: {}", warning);
+    }
+}
+
+fn dump_table(
+    table: &SmbiosTable,
+    w: &mut Dumper<'_, impl Write>,
+    tables: &SmbiosTables,
+) -> std::io::Result<()> {
+    let smbios = tables.data();
+    match table {
+        SmbiosTable::Bios(t) => dump_type0(t, w),
+        SmbiosTable::System(t) => dump_type1(t, w, smbios),
+        SmbiosTable::BaseBoard(t) => dump_type2(t, w),
+        SmbiosTable::Chassis(t) => dump_type3(t, w, tables),
+        SmbiosTable::Processor(t) => dump_type4(t, w, smbios),
+        SmbiosTable::MemoryController(t) => dump_type5(t, w),
+        SmbiosTable::MemoryModule(t) => dump_type6(t, w),
+        SmbiosTable::Cache(t) => dump_type7(t, w),
+        SmbiosTable::PortConnector(t) => dump_type8(t, w),
+        SmbiosTable::SystemSlots(t) => dump_type9(t, w),
+        SmbiosTable::OnBoardDevices(t) => dump_type10(t, w),
+        SmbiosTable::OemStrings(t) => dump_type11(t, w),
+        SmbiosTable::SystemConfigurationOptions(t) => dump_type12(t, w),
+        SmbiosTable::BiosLanguage(t) => dump_type13(t, w),
+        SmbiosTable::GroupAssociations(t) => dump_type14(t, w),
+        SmbiosTable::SystemEventLog(t) => dump_type15(t, w),
+        SmbiosTable::PhysicalMemoryArray(t) => dump_type16(t, w),
+        SmbiosTable::MemoryDevice(t) => dump_type17(t, w),
+        SmbiosTable::B32MemoryError(t) => dump_type18(t, w),
+        SmbiosTable::MemoryArrayMappedAddress(t) => dump_type19(t, w),
+        SmbiosTable::MemoryDeviceMappedAddress(t) => dump_type20(t, w),
+        SmbiosTable::BuiltinPointingDevice(t) => dump_type21(t, w),
+        SmbiosTable::PortableBattery(t) => dump_type22(t, w),
+        SmbiosTable::SystemReset(t) => dump_type23(t, w),
+        SmbiosTable::HardwareSecurity(t) => dump_type24(t, w),
+        SmbiosTable::SystemPowerControls(t) => dump_type25(t, w),
+        SmbiosTable::VoltageProbe(t) => dump_type26(t, w),
+        SmbiosTable::CoolingDevice(t) => dump_type27(t, w, tables),
+        SmbiosTable::TemperatureProbe(t) => dump_type28(t, w),
+        SmbiosTable::ElectricalCurrentProbe(t) => dump_type29(t, w),
+        SmbiosTable::OutOfBandRemoteAccess(t) => dump_type30(t, w),
+        SmbiosTable::SystemBoot(t) => dump_type32(t, w),
+        SmbiosTable::B64MemoryError(t) => dump_type33(t, w),
+        SmbiosTable::ManagementDevice(t) => dump_type34(t, w),
+        SmbiosTable::ManagementDeviceComponent(t) => dump_type35(t, w),
+        SmbiosTable::ManagementDeviceThresholdData(t) => dump_type36(t, w),
+        SmbiosTable::MemoryChannel(t) => dump_type37(t, w),
+        SmbiosTable::IpmiDevice(t) => dump_type38(t, w),
+        SmbiosTable::SystemPowerSupply(t) => dump_type39(t, w),
+        SmbiosTable::Additional(t) => dump_type40(t, w),
+        SmbiosTable::OnboardDevicesExtended(t) => dump_type41(t, w),
+        SmbiosTable::ManagementControllerHostInterface(t) => dump_type42(t, w),
+        SmbiosTable::TpmDevice(t) => dump_type43(t, w),
+        SmbiosTable::ProcessorAdditional(t) => dump_type44(t, w),
+        SmbiosTable::FirmwareInventory(t) => dump_type45(t, w),
+        SmbiosTable::StringProperty(t) => dump_type46(t, w),
+        SmbiosTable::Inactive(t) => {
+            write_header!(w, t);
+            write_title!(w, get_table_name_by_id(126).unwrap());
+            Ok(())
+        }
+        SmbiosTable::EnfOfTable(t) => {
+            write_header!(w, t);
+            write_title!(w, get_table_name_by_id(127).unwrap());
+            Ok(())
+        }
+        SmbiosTable::Other(t) => dump_raw(t, w),
+    }
+}
+
 fn dump_raw(table: &RawSmbiosTable, writer: &mut impl Write) -> std::io::Result<()> {
     writeln!(
         writer,
@@ -338,114 +454,141 @@ fn dump_raw(table: &RawSmbiosTable, writer: &mut impl Write) -> std::io::Result<
             write_bytearray(writer, bytes)?;
 
             // String
-            if let Ok(s) = String::from_utf8(bytes.to_vec()) {
-                writeln!(writer, "\t\t{}", s)?;
-            }
+            writeln!(writer, "\t\t{}", table.encoding.decode(bytes))?;
+        }
+    }
+
+    Ok(())
+}
+
+// Stops at the first System structure instead of decoding the whole table.
+// `--shell-safe` output for scripts; exits 5 when nothing printable is left.
+fn shell_value(value: &str, options: &Options) -> String {
+    if !options.shell_safe {
+        return value.to_string();
+    }
+
+    match get_shell_safe_str(value, options.quote) {
+        Some(value) => value,
+        None => {
+            eprintln!("Value is empty after removing control characters");
+            std::process::exit(5);
+        }
+    }
+}
+
+fn dump_uuid(smbios: &RawSmbiosData, options: &Options) {
+    let uuid = smbios
+        .structures()
+        .find(|t| t.table_ty == 1)
+        .and_then(|t| System::from_raw_table(&t).uuid_string(smbios));
+
+    match uuid {
+        Some(uuid) => println!("{}", shell_value(&uuid, options)),
+        None => {
+            eprintln!("No System UUID found");
+            std::process::exit(1);
+        }
+    }
+}
+
+// Stops once the System, Baseboard and Chassis structures have been seen.
+fn dump_serials(smbios: &RawSmbiosData, options: &Options) {
+    let mut serials: [Option<Option<String>>; 3] = [None, None, None];
+    for table in smbios.structures() {
+        let serial = match table.table_ty {
+            1 => System::from_raw_table(&table)
+                .serial_number()
+                .map(String::from),
+            2 => BaseBoard::from_raw_table(&table)
+                .serial_number()
+                .map(String::from),
+            3 => Chassis::from_raw_table(&table)
+                .serial_number()
+                .map(String::from),
+            _ => continue,
+        };
+        serials[table.table_ty as usize - 1].get_or_insert(serial);
+        if serials.iter().all(Option::is_some) {
+            break;
         }
     }
 
+    let serials = serials.map(|s| {
+        s.flatten()
+            .map_or("Not Specified".to_string(), |s| shell_value(&s, options))
+    });
+    for (name, serial) in ["System", "Baseboard", "Chassis"].iter().zip(serials) {
+        println!("{} Serial Number: {}", name, serial);
+    }
+}
+
+fn write_fields(
+    writer: &mut Dumper<'_, impl Write>,
+    fields: Vec<(&'static str, FieldValue)>,
+) -> std::io::Result<()> {
+    for (key, value) in fields {
+        match value {
+            FieldValue::Text(v) => {
+                write_kv!(writer, key, Some(v));
+            }
+            FieldValue::List(v) => {
+                write_iter!(writer, key, Some(v));
+            }
+        }
+    }
     Ok(())
 }
 
-fn dump_type0(table: &Bios, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type0(table: &Bios, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(0).unwrap());
-    write_kv!(writer, "Vendor", table.vendor());
-    write_kv!(writer, "Version", table.bios_version());
-    write_kv!(writer, "Release Date", table.bios_release_date());
-    write_format_kv!(writer, "Address", "0x{:04X}", table.bios_starting_address());
-    write_kv!(writer, "Runtime Size", table.runtime_size_kb(), "kB");
-    write_kv!(writer, "ROM Size", table.bios_rom_size_ex(), "kB");
-    write_iter!(writer, "Charracteristics", table.bios_characteristics_str());
-    write_iter!(writer, "", table.bios_characteristics_ex_str());
-    write_kv!(writer, "BIOS Revisione", table.system_bios_release());
-    write_kv!(
-        writer,
-        "Firmware Revisione",
-        table.embedded_ctrl_firmware_release()
-    );
-    Ok(())
+    write_fields(writer, table.fields())
 }
 
 fn dump_type1(
     table: &System,
-    writer: &mut impl Write,
+    writer: &mut Dumper<'_, impl Write>,
     smbios: &RawSmbiosData,
 ) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(1).unwrap());
-    write_kv!(writer, "Manufacturer", table.manufacturer());
-    write_kv!(writer, "Product Name", table.product_name());
-    write_kv!(writer, "Version", table.version());
-    write_kv!(writer, "Serial Number", table.serial_number());
-    write_kv!(writer, "UUID", table.get_uuid(smbios));
-    write_kv!(writer, "Wake-up Type", table.wakeup_ty_str());
-    write_kv!(writer, "SKU Number", table.sku_number());
-    write_kv!(writer, "Family", table.family());
+    write_fields(writer, table.fields())?;
+    // The UUID byte order depends on the SMBIOS version.
+    write_kv!(writer, "UUID", table.uuid_string(smbios));
     Ok(())
 }
 
-fn dump_type2(table: &BaseBoard, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type2(table: &BaseBoard, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(2).unwrap());
-    write_kv!(writer, "Manufacturer", table.manufacturer());
-    write_kv!(writer, "Product Name", table.product());
-    write_kv!(writer, "Version", table.version());
-    write_kv!(writer, "Serial Number", table.serial_number());
-    write_kv!(writer, "Asset Tag", table.asset_tag());
-    write_iter!(writer, "Features", table.feature_flags_str());
-    write_kv!(writer, "Location In Chassis", table.location());
-    write_kv!(writer, "Chassis Handle", table.chassis_handle());
-    write_kv!(writer, "Type", table.board_ty_str());
-    Ok(())
+    write_fields(writer, table.fields())
 }
 
-fn dump_type3(table: &Chassis, writer: &mut impl Write) -> std::io::Result<()> {
+#[allow(unused_mut, unused_variables)]
+fn dump_type3(
+    table: &Chassis,
+    writer: &mut Dumper<'_, impl Write>,
+    tables: &SmbiosTables,
+) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(3).unwrap());
-    write_kv!(writer, "Manufacturer", table.manufacturer());
-    write_kv!(writer, "Type", table.ty_str());
-    write_kv!(
-        writer,
-        "Lock",
-        table
-            .ty_lock()
-            .map(|l| if l { "Present" } else { "Not Present" })
-    );
-    write_kv!(writer, "Version", table.version());
-    write_kv!(writer, "Serial Number", table.serial_number());
-    write_kv!(writer, "Assert Tag", table.asset_tag_number());
-    write_kv!(writer, "Boot-up State", table.boot_up_state_str());
-    write_kv!(writer, "Power Supply State", table.power_supply_state_str());
-    write_kv!(writer, "Thermal State", table.thermal_state_str());
-    write_kv!(writer, "Security Status", table.security_status_str());
-    write_format_kv!(writer, "OEM Information", "0x{:08X}", table.oem_defined());
-    write_kv!(writer, "Height", table.height(), " U");
-    write_kv!(writer, "Number of Power Cords", table.num_power_cords());
-    if let Some(contained_elements) = table.contained_elements() {
-        let count = table.contained_element_count().unwrap();
-        let len = table.contained_element_record_length().unwrap();
-        write_kv!(writer, "Contained Elements", Some(count));
-        for i in 0..count {
-            let idx = (i * len) as usize;
-            let ty = contained_elements[idx];
-            let ty_str = if (ty & 0x80) > 0 {
-                get_table_name_by_id(ty & 0x7F).unwrap()
-            } else {
-                get_board_ty_str(ty & 0x7F)
-            };
-            let min = contained_elements[idx + 1];
-            let max = contained_elements[idx + 2];
-            write_item!(writer, format!("{} ({}-{})", ty_str, min, max));
+    let mut fields = table.fields();
+    // Registered OEM decoders replace the raw hex for known manufacturers.
+    #[cfg(feature = "oem")]
+    if let Some(oem) = tables.chassis_oem_str(table) {
+        for (key, value) in fields.iter_mut() {
+            if *key == "OEM Information" {
+                *value = FieldValue::Text(oem.clone());
+            }
         }
     }
-    write_kv!(writer, "SKU Number", table.sku_number());
-    Ok(())
+    write_fields(writer, fields)
 }
 
 fn dump_type4(
     table: &Processor,
-    writer: &mut impl Write,
+    writer: &mut Dumper<'_, impl Write>,
     smbios: &RawSmbiosData,
 ) -> std::io::Result<()> {
     write_header!(writer, table);
@@ -454,7 +597,17 @@ fn dump_type4(
     write_kv!(writer, "Type", table.processor_ty_str());
     write_kv!(writer, "Family", table.processor_family_str());
     write_kv!(writer, "Manufacturer", table.processor_manufacturer());
-    // TODO: processor_id
+    write_kv!(
+        writer,
+        "ID",
+        table.processor_id().map(|id| {
+            id.to_le_bytes()
+                .iter()
+                .map(|b| format!("{:02X}", b))
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+    );
     write_kv!(writer, "Version", table.processor_version());
     write_kv!(writer, "Voltage", table.voltage_str());
     write_kv!(writer, "External Clock", table.external_clock(), " MHz");
@@ -489,6 +642,7 @@ fn dump_type4(
     write_kv!(writer, "Core Count", table.core_count_mixed());
     write_kv!(writer, "Core Enabled", table.core_enabled_mixed());
     write_kv!(writer, "Thread Count", table.thread_count_mixed());
+    write_kv!(writer, "Thread Enabled", table.thread_enabled());
     write_iter!(
         writer,
         "Charactaristics",
@@ -497,7 +651,10 @@ fn dump_type4(
     Ok(())
 }
 
-fn dump_type5(table: &MemoryController, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type5(
+    table: &MemoryController,
+    writer: &mut Dumper<'_, impl Write>,
+) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(5).unwrap());
     write_kv!(
@@ -563,32 +720,20 @@ fn dump_type5(table: &MemoryController, writer: &mut impl Write) -> std::io::Res
     Ok(())
 }
 
-fn dump_type6(table: &MemoryModule, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type6(table: &MemoryModule, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(6).unwrap());
     write_kv!(writer, "Socket Designation", table.socket_designation());
-    write_kv!(
-        writer,
-        "Bank Connections",
-        memory_module_connection(table.bank_connections())
-    );
+    write_kv!(writer, "Bank Connections", table.bank_connections_str());
     write_kv!(writer, "Current Speed", table.current_speed(), " ns");
     write_iter!(writer, "Type", table.current_memory_ty_str());
-    write_kv!(
-        writer,
-        "Installed Size",
-        memory_module_size(table.installed_size())
-    );
-    write_kv!(
-        writer,
-        "Enabled Size",
-        memory_module_size(table.enabled_size())
-    );
+    write_kv!(writer, "Installed Size", table.installed_size_str());
+    write_kv!(writer, "Enabled Size", table.enabled_size_str());
     write_kv!(writer, "Error Status", table.error_status());
     Ok(())
 }
 
-fn dump_type7(table: &Cache, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type7(table: &Cache, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(7).unwrap());
     write_kv!(writer, "Socket Designation", table.socket_designation());
@@ -620,7 +765,7 @@ fn dump_type7(table: &Cache, writer: &mut impl Write) -> std::io::Result<()> {
     Ok(())
 }
 
-fn dump_type8(table: &PortConnector, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type8(table: &PortConnector, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(8).unwrap());
     write_kv!(
@@ -647,7 +792,7 @@ fn dump_type8(table: &PortConnector, writer: &mut impl Write) -> std::io::Result
     Ok(())
 }
 
-fn dump_type9(table: &SystemSlots, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type9(table: &SystemSlots, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(9).unwrap());
     write_kv!(writer, "Designation", table.slot_designation());
@@ -687,24 +832,22 @@ fn dump_type9(table: &SystemSlots, writer: &mut impl Write) -> std::io::Result<(
             )?;
         }
     }
-    write_kv!(writer, "PCI Express Generation", table.slot_information());
     write_kv!(
         writer,
-        "Slot Physical Width",
-        table.slot_physical_width_str()
+        "PCI Express Generation",
+        table.slot_information_str()
     );
-    write_format_kv!(
+    write_kv!(
         writer,
-        "Pitch",
-        "{:.2}",
-        table.slot_pitch().map(|p| p / 100),
-        " mm"
+        "Slot Physical Width",
+        table.slot_physical_width_str()
     );
+    write_kv!(writer, "Pitch", table.slot_pitch_str());
     write_kv!(writer, "Height", table.slot_height_str());
     Ok(())
 }
 
-fn dump_type10(table: &OnBoardDevices, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type10(table: &OnBoardDevices, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     //write_title!(writer, get_table_name_by_id(10).unwrap());
     if let Some(devices) = table.get_device() {
@@ -722,43 +865,30 @@ fn dump_type10(table: &OnBoardDevices, writer: &mut impl Write) -> std::io::Resu
     Ok(())
 }
 
-fn dump_type11(
-    table: &OemStrings,
-    writer: &mut impl Write,
-    raw: &RawSmbiosTable,
-) -> std::io::Result<()> {
+fn dump_type11(table: &OemStrings, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(11).unwrap());
-    if let Some(count) = table.count() {
-        for i in 1..=count {
-            let key = format!("String {}", i);
-            write_kv!(writer, key, raw.get_string_by_index(i));
-        }
+    for (i, value) in table.strings().iter().enumerate() {
+        let key = format!("String {}", i + 1);
+        write_kv!(writer, key, Some(value));
     }
     Ok(())
 }
 
 fn dump_type12(
     table: &SystemConfigurationOptions,
-    writer: &mut impl Write,
-    raw: &RawSmbiosTable,
+    writer: &mut Dumper<'_, impl Write>,
 ) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(12).unwrap());
-    if let Some(count) = table.count() {
-        for i in 1..=count {
-            let key = format!("Option {}", i);
-            write_kv!(writer, key, raw.get_string_by_index(i));
-        }
+    for (i, value) in table.strings().iter().enumerate() {
+        let key = format!("Option {}", i + 1);
+        write_kv!(writer, key, Some(value));
     }
     Ok(())
 }
 
-fn dump_type13(
-    table: &BiosLanguage,
-    writer: &mut impl Write,
-    raw: &RawSmbiosTable,
-) -> std::io::Result<()> {
+fn dump_type13(table: &BiosLanguage, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(13).unwrap());
     write_kv!(
@@ -771,49 +901,51 @@ fn dump_type13(
         "Installable Languages",
         table.installable_languages()
     );
-    if let Some(n) = table.installable_languages() {
-        for i in 1..=n {
-            if let Some(lang) = raw.get_string_by_index(i) {
-                write_item!(writer, lang);
-            }
-        }
+    for lang in table.languages() {
+        write_item!(writer, lang);
     }
     write_kv!(
         writer,
         "Currently Installed Language",
-        table
-            .current_language()
-            .and_then(|i| raw.get_string_by_index(i))
+        table.current_language_str()
     );
     Ok(())
 }
 
-fn dump_type14(table: &GroupAssociations, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type14(
+    table: &GroupAssociations,
+    writer: &mut Dumper<'_, impl Write>,
+) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(14).unwrap());
     write_kv!(writer, "Name", table.group_name());
     write_kv!(writer, "Items", table.items().map(|i| i.len()));
     if let Some(items) = table.items() {
         for item in items {
-            let value = format!(
-                "{:04X} ({})",
-                item.item_handle().unwrap(),
-                get_table_name_by_id(item.item_ty().unwrap()).unwrap()
-            );
+            let ty = item.item_ty().unwrap();
+            let name = get_table_name_by_id(ty)
+                .map(|name| name.to_string())
+                .unwrap_or_else(|| format!("Unknown (0x{:02X})", ty));
+            let value = format!("{:04X} ({})", item.item_handle().unwrap(), name);
             write_item!(writer, value);
         }
     }
     Ok(())
 }
 
-fn dump_type15(table: &SystemEventLog, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type15(table: &SystemEventLog, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(15).unwrap());
+    write_kv!(writer, "Access Method", table.access_method_str());
+    write_kv!(writer, "Access Address", table.access_method_address_str());
     // TODO:
     Ok(())
 }
 
-fn dump_type16(table: &PhysicalMemoryArray, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type16(
+    table: &PhysicalMemoryArray,
+    writer: &mut Dumper<'_, impl Write>,
+) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(16).unwrap());
     write_kv!(writer, "Location", table.location_str());
@@ -832,17 +964,16 @@ fn dump_type16(table: &PhysicalMemoryArray, writer: &mut impl Write) -> std::io:
     } else {
         write_kv!(writer, "Maximum Capacity", table.maximum_capacity());
     }
-    write_format_kv!(
+    write_kv!(
         writer,
         "Error Information Handle",
-        "0x{:04X}",
-        table.memory_error_information_handle()
+        table.error_information_handle_str()
     );
     write_kv!(writer, "Number Of Devices", table.num_memory_devices());
     Ok(())
 }
 
-fn dump_type17(table: &MemoryDevice, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type17(table: &MemoryDevice, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(17).unwrap());
     write_format_kv!(
@@ -851,11 +982,10 @@ fn dump_type17(table: &MemoryDevice, writer: &mut impl Write) -> std::io::Result
         "0x{:04X}",
         table.physical_memory_array_handle()
     );
-    write_format_kv!(
+    write_kv!(
         writer,
         "Error Information Handle",
-        "0x{:04X}",
-        table.memory_error_information_handle()
+        table.error_information_handle_str()
     );
     write_kv!(writer, "Total Width", table.total_width(), " bits");
     write_kv!(writer, "Data Width", table.data_width(), " bits");
@@ -870,31 +1000,17 @@ fn dump_type17(table: &MemoryDevice, writer: &mut impl Write) -> std::io::Result
     write_kv!(writer, "Bank Locator", table.bank_locator());
     write_kv!(writer, "Type", table.memory_ty_str());
     write_iter!(writer, "Type Detail", table.ty_detail_str());
-    if table.extended_speed().is_some() {
-        write_kv!(writer, "Speed", table.extended_speed(), " MT/s");
-    } else {
-        write_kv!(writer, "Speed", table.speed(), " MT/s");
-    }
+    write_kv!(writer, "Speed", table.speed_str());
     write_kv!(writer, "Manufacturer", table.manufacturer());
     write_kv!(writer, "Serial Number", table.serial_number());
     write_kv!(writer, "Asset Tag", table.asset_tag());
     write_kv!(writer, "Part Number", table.part_number());
     write_kv!(writer, "Rank", table.attributes().map(|a| a & 0x0F));
-    if table.extended_configured_memory_speed().is_some() {
-        write_kv!(
-            writer,
-            "Configured Memory Speed",
-            table.extended_configured_memory_speed(),
-            " MT/s"
-        );
-    } else {
-        write_kv!(
-            writer,
-            "Configured Memory Speed",
-            table.configured_memory_speed(),
-            " MT/s"
-        );
-    }
+    write_kv!(
+        writer,
+        "Configured Memory Speed",
+        table.configured_memory_speed_str()
+    );
     write_kv!(writer, "Minimum Voltage", table.minimum_voltage(), " V");
     write_kv!(writer, "Maximum Voltage", table.maximum_voltage(), " V");
     write_kv!(
@@ -910,28 +1026,38 @@ fn dump_type17(table: &MemoryDevice, writer: &mut impl Write) -> std::io::Result
         table.memory_operating_mode_capability_str()
     );
     write_kv!(writer, "Firmware Version", table.firmware_version());
-    // TODO:
+    write_format_kv!(
+        writer,
+        "Module Manufacturer ID",
+        "0x{:04X}",
+        table.module_manufacturer_id()
+    );
     write_format_kv!(
         writer,
         "Module Product ID",
         "0x{:04X}",
         table.module_product_id()
     );
-    // TODO:
     write_format_kv!(
         writer,
-        "Module Subsustem Controller Product ID",
+        "Memory Subsystem Controller Manufacturer ID",
+        "0x{:04X}",
+        table.memory_subsystem_ctrl_manufacturer_id()
+    );
+    write_format_kv!(
+        writer,
+        "Memory Subsystem Controller Product ID",
         "0x{:04X}",
         table.memory_subsystem_ctrl_product_id()
     );
-    write_kv!(writer, "Non-Volatile Size", table.volatile_size());
+    write_kv!(writer, "Non-Volatile Size", table.non_volatile_size());
     write_kv!(writer, "Volatile Size", table.volatile_size());
     write_kv!(writer, "Cache Size", table.cache_size());
     write_kv!(writer, "Logical Size", table.logical_size());
     Ok(())
 }
 
-fn dump_type18(table: &B32MemoryError, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type18(table: &B32MemoryError, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(18).unwrap());
     write_kv!(writer, "Type", table.error_ty_str());
@@ -959,7 +1085,10 @@ fn dump_type18(table: &B32MemoryError, writer: &mut impl Write) -> std::io::Resu
     Ok(())
 }
 
-fn dump_type19(table: &MemoryArrayMappedAddress, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type19(
+    table: &MemoryArrayMappedAddress,
+    writer: &mut Dumper<'_, impl Write>,
+) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(19).unwrap());
     if table.ex_starting_address().is_some()
@@ -981,7 +1110,18 @@ fn dump_type19(table: &MemoryArrayMappedAddress, writer: &mut impl Write) -> std
             table.ex_ending_address()
         );
     } else {
-        // TODO:
+        write_format_kv!(
+            writer,
+            "Starting Address",
+            "0x{:016X}",
+            table.starting_address().map(|a| (a as u64) << 10)
+        );
+        write_format_kv!(
+            writer,
+            "Ending Address",
+            "0x{:016X}",
+            table.ending_address().map(|a| ((a as u64 + 1) << 10) - 1)
+        );
     }
     write_format_kv!(
         writer,
@@ -993,7 +1133,10 @@ fn dump_type19(table: &MemoryArrayMappedAddress, writer: &mut impl Write) -> std
     Ok(())
 }
 
-fn dump_type20(table: &MemoryDeviceMappedAddress, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type20(
+    table: &MemoryDeviceMappedAddress,
+    writer: &mut Dumper<'_, impl Write>,
+) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(20).unwrap());
     if table.ex_starting_address().is_some()
@@ -1015,7 +1158,18 @@ fn dump_type20(table: &MemoryDeviceMappedAddress, writer: &mut impl Write) -> st
             table.ex_ending_address()
         );
     } else {
-        // TODO:
+        write_format_kv!(
+            writer,
+            "Starting Address",
+            "0x{:016X}",
+            table.starting_address().map(|a| (a as u64) << 10)
+        );
+        write_format_kv!(
+            writer,
+            "Ending Address",
+            "0x{:016X}",
+            table.ending_address().map(|a| ((a as u64 + 1) << 10) - 1)
+        );
     }
     write_format_kv!(
         writer,
@@ -1043,21 +1197,66 @@ fn dump_type20(table: &MemoryDeviceMappedAddress, writer: &mut impl Write) -> st
     Ok(())
 }
 
-fn dump_type21(table: &BuiltinPointingDevice, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type21(
+    table: &BuiltinPointingDevice,
+    writer: &mut Dumper<'_, impl Write>,
+) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(21).unwrap());
     // TODO:
     Ok(())
 }
 
-fn dump_type22(table: &PortableBattery, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type22(
+    table: &PortableBattery,
+    writer: &mut Dumper<'_, impl Write>,
+) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(22).unwrap());
-    // TODO:
+    write_kv!(writer, "Location", table.location());
+    write_kv!(writer, "Manufacturer", table.manufacturer());
+    write_kv!(writer, "Manufacture Date", table.manufacturer_date());
+    write_kv!(writer, "Serial Number", table.serial_number());
+    write_kv!(writer, "Name", table.device_name());
+    write_kv!(writer, "Chemistry", table.device_chemistry_str());
+    write_kv!(
+        writer,
+        "Design Capacity",
+        table.design_capacity_mwh(),
+        " mWh"
+    );
+    write_kv!(writer, "Design Voltage", table.design_voltage(), " mV");
+    write_kv!(writer, "SBDS Version", table.sbds_version_number());
+    write_kv!(
+        writer,
+        "Maximum Error",
+        table.maximum_error_in_battery_data(),
+        "%"
+    );
+    write_format_kv!(
+        writer,
+        "SBDS Serial Number",
+        "{:04X}",
+        table.sbds_serial_number()
+    );
+    write_kv!(
+        writer,
+        "SBDS Manufacture Date",
+        table
+            .sbds_manufacturer_date_parsed()
+            .map(|(y, m, d)| format!("{:04}-{:02}-{:02}", y, m, d))
+    );
+    write_kv!(writer, "SBDS Chemistry", table.sbds_device_chemistry());
+    write_format_kv!(
+        writer,
+        "OEM-specific Information",
+        "0x{:08X}",
+        table.oem_specific()
+    );
     Ok(())
 }
 
-fn dump_type23(table: &SystemReset, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type23(table: &SystemReset, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(23).unwrap());
     write_kv!(
@@ -1085,72 +1284,52 @@ fn dump_type23(table: &SystemReset, writer: &mut impl Write) -> std::io::Result<
     Ok(())
 }
 
-fn dump_type24(table: &HardwareSecurity, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type24(
+    table: &HardwareSecurity,
+    writer: &mut Dumper<'_, impl Write>,
+) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(24).unwrap());
     // TODO:
     Ok(())
 }
 
-fn dump_type25(table: &SystemPowerControls, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type25(
+    table: &SystemPowerControls,
+    writer: &mut Dumper<'_, impl Write>,
+) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(25).unwrap());
     // TODO:
     Ok(())
 }
 
-fn dump_type26(table: &VoltageProbe, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type26(table: &VoltageProbe, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(26).unwrap());
     write_kv!(writer, "Description", table.description());
     write_kv!(writer, "Location", table.location_str());
     write_kv!(writer, "Status", table.status_str());
-    write_format_kv!(
-        writer,
-        "Maximum Value",
-        "{:.3} V",
-        table.maximum_value().map(|v| v as f32 / 1000f32)
-    );
-    write_format_kv!(
-        writer,
-        "Minimum Value",
-        "{:.3} V",
-        table.minimum_value().map(|v| v as f32 / 1000f32)
-    );
-    write_format_kv!(
-        writer,
-        "Resolution",
-        "{:.1} mV",
-        table.resolution().map(|v| v as f32 / 10f32)
-    );
-    write_format_kv!(
-        writer,
-        "Torelance",
-        "{:.3} V",
-        table.tolerance().map(|v| v as f32 / 1000f32)
-    );
-    write_format_kv!(
-        writer,
-        "Accuracy",
-        "{:.2}%",
-        table.accuracy().map(|v| v as f32 / 100f32)
-    );
+    write_kv!(writer, "Maximum Value", table.maximum_value_str());
+    write_kv!(writer, "Minimum Value", table.minimum_value_str());
+    write_kv!(writer, "Resolution", table.resolution_str());
+    write_kv!(writer, "Tolerance", table.tolerance_str());
+    write_kv!(writer, "Accuracy", table.accuracy_str());
     write_format_kv!(
         writer,
         "OEM-specific Information",
         "{:08X}",
         table.oem_defined()
     );
-    write_format_kv!(
-        writer,
-        "Nominal Value",
-        "{:.3} V",
-        table.nominal_value().map(|v| v as f32 / 1000f32)
-    );
+    write_kv!(writer, "Nominal Value", table.nominal_value_str());
     Ok(())
 }
 
-fn dump_type27(table: &CoolingDevice, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type27(
+    table: &CoolingDevice,
+    writer: &mut Dumper<'_, impl Write>,
+    tables: &SmbiosTables,
+) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(27).unwrap());
     write_format_kv!(
@@ -1159,6 +1338,13 @@ fn dump_type27(table: &CoolingDevice, writer: &mut impl Write) -> std::io::Resul
         "{:04X}",
         table.temperature_probe_handle()
     );
+    write_kv!(
+        writer,
+        "Temperature Probe",
+        table
+            .temperature_probe_handle_resolved(tables)
+            .and_then(|p| p.description())
+    );
     write_kv!(writer, "Type", table.device_ty_str());
     write_kv!(writer, "Status", table.status_str());
     write_kv!(writer, "Cooling Unit Group", table.cooling_unit_group());
@@ -1168,135 +1354,87 @@ fn dump_type27(table: &CoolingDevice, writer: &mut impl Write) -> std::io::Resul
         "{:08X}",
         table.oem_defined()
     );
-    write_kv!(writer, "Nominal Speed", table.nominal_speed(), " rpm");
+    write_kv!(writer, "Nominal Speed", table.nominal_speed_str());
     write_kv!(writer, "Description", table.description());
     Ok(())
 }
 
-fn dump_type28(table: &TemperatureProbe, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type28(
+    table: &TemperatureProbe,
+    writer: &mut Dumper<'_, impl Write>,
+) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(28).unwrap());
     write_kv!(writer, "Description", table.description());
     write_kv!(writer, "Location", table.location_str());
     write_kv!(writer, "Status", table.status_str());
-    write_format_kv!(
-        writer,
-        "Maximum Value",
-        "{:.1} deg C",
-        table.maximum_value().map(|v| v as f32 / 10f32)
-    );
-    write_format_kv!(
-        writer,
-        "Minimum Value",
-        "{:.1} deg C",
-        table.minimum_value().map(|v| v as f32 / 10f32)
-    );
-    write_format_kv!(
-        writer,
-        "Resolution",
-        "{:.3} deg C",
-        table.resolution().map(|v| v as f32 / 1000f32)
-    );
-    write_format_kv!(
-        writer,
-        "Tolerance",
-        "{:.1} deg C",
-        table.tolerance().map(|v| v as f32 / 10f32)
-    );
-    write_format_kv!(
-        writer,
-        "Accuracy",
-        "{:.2}%",
-        table.accuracy().map(|v| v as f32 / 100f32)
-    );
+    write_kv!(writer, "Maximum Value", table.maximum_value_str());
+    write_kv!(writer, "Minimum Value", table.minimum_value_str());
+    write_kv!(writer, "Resolution", table.resolution_str());
+    write_kv!(writer, "Tolerance", table.tolerance_str());
+    write_kv!(writer, "Accuracy", table.accuracy_str());
     write_format_kv!(
         writer,
         "OEM-specific Information",
         "{:08X}",
         table.oem_defined()
     );
-    write_format_kv!(
-        writer,
-        "Nominal Value",
-        "{:.1} deg C",
-        table.nominal_value().map(|v| v as f32 / 10f32)
-    );
+    write_kv!(writer, "Nominal Value", table.nominal_value_str());
     Ok(())
 }
 
-fn dump_type29(table: &ElectricalCurrentProbe, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type29(
+    table: &ElectricalCurrentProbe,
+    writer: &mut Dumper<'_, impl Write>,
+) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(29).unwrap());
     write_kv!(writer, "Description", table.description());
     write_kv!(writer, "Location", table.location_str());
     write_kv!(writer, "Status", table.status_str());
-    write_format_kv!(
-        writer,
-        "Maximum Value",
-        "{:.3} A",
-        table.maximum_value().map(|v| v as f32 / 1000f32)
-    );
-    write_format_kv!(
-        writer,
-        "Minimum Value",
-        "{:.3} A",
-        table.minimum_value().map(|v| v as f32 / 1000f32)
-    );
-    write_format_kv!(
-        writer,
-        "Resolution",
-        "{:.1} mA",
-        table.resolution().map(|v| v as f32 / 10f32)
-    );
-    write_format_kv!(
-        writer,
-        "Tolerance",
-        "{:.3} A",
-        table.tolerance().map(|v| v as f32 / 1000f32)
-    );
-    write_format_kv!(
-        writer,
-        "Accuracy",
-        "{:.2}%",
-        table.accuracy().map(|v| v as f32 / 100f32)
-    );
+    write_kv!(writer, "Maximum Value", table.maximum_value_str());
+    write_kv!(writer, "Minimum Value", table.minimum_value_str());
+    write_kv!(writer, "Resolution", table.resolution_str());
+    write_kv!(writer, "Tolerance", table.tolerance_str());
+    write_kv!(writer, "Accuracy", table.accuracy_str());
     write_format_kv!(
         writer,
         "OEM-specific Information",
         "{:08X}",
         table.oem_defined()
     );
-    write_format_kv!(
-        writer,
-        "Nominal Value",
-        "{:.3} A",
-        table.nominal_value().map(|v| v as f32 / 1000f32)
-    );
+    write_kv!(writer, "Nominal Value", table.nominal_value_str());
     Ok(())
 }
 
-fn dump_type30(table: &OutOfBandRemoteAccess, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type30(
+    table: &OutOfBandRemoteAccess,
+    writer: &mut Dumper<'_, impl Write>,
+) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(30).unwrap());
     // TODO:
     Ok(())
 }
 
-fn dump_type32(table: &SystemBoot, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type32(table: &SystemBoot, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(32).unwrap());
     write_kv!(writer, "Status", table.boot_status_str());
     Ok(())
 }
 
-fn dump_type33(table: &B64MemoryError, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type33(table: &B64MemoryError, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(33).unwrap());
     // TODO:
     Ok(())
 }
 
-fn dump_type34(table: &ManagementDevice, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type34(
+    table: &ManagementDevice,
+    writer: &mut Dumper<'_, impl Write>,
+) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(34).unwrap());
     write_kv!(writer, "Description", table.description());
@@ -1306,7 +1444,10 @@ fn dump_type34(table: &ManagementDevice, writer: &mut impl Write) -> std::io::Re
     Ok(())
 }
 
-fn dump_type35(table: &ManagementDeviceComponent, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type35(
+    table: &ManagementDeviceComponent,
+    writer: &mut Dumper<'_, impl Write>,
+) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(35).unwrap());
     write_kv!(writer, "Description", table.description());
@@ -1333,7 +1474,7 @@ fn dump_type35(table: &ManagementDeviceComponent, writer: &mut impl Write) -> st
 
 fn dump_type36(
     table: &ManagementDeviceThresholdData,
-    writer: &mut impl Write,
+    writer: &mut Dumper<'_, impl Write>,
 ) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(36).unwrap());
@@ -1370,21 +1511,31 @@ fn dump_type36(
     Ok(())
 }
 
-fn dump_type37(table: &MemoryChannel, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type37(table: &MemoryChannel, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(37).unwrap());
     // TODO:
     Ok(())
 }
 
-fn dump_type38(table: &IpmiDevice, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type38(table: &IpmiDevice, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(38).unwrap());
+    write_kv!(writer, "Register Spacing", table.register_spacing_str());
+    write_kv!(writer, "Interrupt Polarity", table.interrupt_polarity_str());
+    write_kv!(
+        writer,
+        "Interrupt Trigger Mode",
+        table.interrupt_trigger_str()
+    );
     // TODO:
     Ok(())
 }
 
-fn dump_type39(table: &SystemPowerSupply, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type39(
+    table: &SystemPowerSupply,
+    writer: &mut Dumper<'_, impl Write>,
+) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(39).unwrap());
     write_kv!(writer, "Power Unit Group", table.power_unit_group());
@@ -1395,12 +1546,7 @@ fn dump_type39(table: &SystemPowerSupply, writer: &mut impl Write) -> std::io::R
     write_kv!(writer, "Asset Tag", table.asset_tag_number());
     write_kv!(writer, "Model Part Number", table.model_part_number());
     write_kv!(writer, "Revision", table.revision_level());
-    write_format_kv!(
-        writer,
-        "Max Power Capacity",
-        "{} W",
-        table.max_power_capacity()
-    );
+    write_kv!(writer, "Max Power Capacity", table.max_power_capacity_str());
     write_kv!(writer, "Status", table.status_str());
     write_kv!(writer, "Type", table.ty_str());
     write_kv!(
@@ -1441,14 +1587,38 @@ fn dump_type39(table: &SystemPowerSupply, writer: &mut impl Write) -> std::io::R
     Ok(())
 }
 
-fn dump_type40(table: &Additional, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type40(table: &Additional, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(40).unwrap());
-    // TODO:
+    for (i, entry) in table
+        .additional_information_entities()
+        .unwrap_or_default()
+        .iter()
+        .enumerate()
+    {
+        writeln!(writer, "\tAdditional Information {}", i + 1)?;
+        write_format_kv!(
+            writer,
+            "Referenced Handle",
+            "0x{:04X}",
+            entry.referenced_handle()
+        );
+        write_format_kv!(
+            writer,
+            "Referenced Offset",
+            "0x{:02X}",
+            entry.referenced_offset()
+        );
+        write_kv!(writer, "String", entry.string());
+        write_format_kv!(writer, "Value", "0x{:X}", entry.value_as_u64());
+    }
     Ok(())
 }
 
-fn dump_type41(table: &OnboardDevicesExtended, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type41(
+    table: &OnboardDevicesExtended,
+    writer: &mut Dumper<'_, impl Write>,
+) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(41).unwrap());
     write_kv!(
@@ -1477,15 +1647,54 @@ fn dump_type41(table: &OnboardDevicesExtended, writer: &mut impl Write) -> std::
 
 fn dump_type42(
     table: &ManagementControllerHostInterface,
-    writer: &mut impl Write,
+    writer: &mut Dumper<'_, impl Write>,
 ) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(42).unwrap());
-    // TODO:
+    write_kv!(writer, "Host Interface Type", table.interface_ty_str());
+    for record in table.protocol_records().unwrap_or_default() {
+        write_kv!(writer, "Protocol ID", record.protocol_ty_str());
+        if let Some(redfish) = record.redfish_over_ip() {
+            write_kv!(writer, "Service UUID", Some(redfish.service_uuid_string()));
+            write_kv!(
+                writer,
+                "Host IP Assignment Type",
+                Some(redfish.host_ip_assignment_ty_str())
+            );
+            write_kv!(writer, "Host IP Address", redfish.host_ip_address);
+            write_kv!(writer, "Host IP Mask", redfish.host_ip_mask);
+            write_kv!(
+                writer,
+                "Redfish Service IP Discovery Type",
+                Some(redfish.service_ip_discovery_ty_str())
+            );
+            write_kv!(
+                writer,
+                "Redfish Service IP Address",
+                redfish.service_ip_address
+            );
+            write_kv!(writer, "Redfish Service IP Mask", redfish.service_ip_mask);
+            write_kv!(
+                writer,
+                "Redfish Service Port",
+                Some(redfish.service_ip_port)
+            );
+            write_kv!(
+                writer,
+                "Redfish Service Vlan",
+                Some(redfish.service_vlan_id)
+            );
+            write_kv!(
+                writer,
+                "Redfish Service Hostname",
+                redfish.service_hostname.as_deref()
+            );
+        }
+    }
     Ok(())
 }
 
-fn dump_type43(table: &TpmDevice, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type43(table: &TpmDevice, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(43).unwrap());
     write_kv!(writer, "Vendor ID", table.vendor_id_str());
@@ -1502,60 +1711,35 @@ fn dump_type43(table: &TpmDevice, writer: &mut impl Write) -> std::io::Result<()
     Ok(())
 }
 
-fn dump_type44(table: &ProcessorAdditional, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type44(
+    table: &ProcessorAdditional,
+    writer: &mut Dumper<'_, impl Write>,
+) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(44).unwrap());
     // TODO:
     Ok(())
 }
 
-fn dump_type45(table: &FirmwareInventory, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type45(
+    table: &FirmwareInventory,
+    writer: &mut Dumper<'_, impl Write>,
+) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(45).unwrap());
     // TODO:
     Ok(())
 }
 
-fn dump_type46(table: &StringProperty, writer: &mut impl Write) -> std::io::Result<()> {
+fn dump_type46(table: &StringProperty, writer: &mut Dumper<'_, impl Write>) -> std::io::Result<()> {
     write_header!(writer, table);
     write_title!(writer, get_table_name_by_id(46).unwrap());
     // TODO:
     Ok(())
 }
 
-fn memory_module_connection(value: Option<u8>) -> Option<String> {
-    value.map(|v| {
-        if v == 0xFF {
-            "None".to_string()
-        } else if (v & 0xF0) == 0xF0 {
-            format!("{}", v & 0xF0)
-        } else if (v & 0x0F) == 0x0F {
-            format!("{}", v >> 4)
-        } else {
-            format!("{} {}", v >> 4, v & 0x0F)
-        }
-    })
-}
-
-fn memory_module_size(value: Option<u8>) -> Option<String> {
-    value.map(|v| {
-        let conn = if (v & 0x80) != 0 {
-            "(Double-bank Connection)"
-        } else {
-            "(Single-bank Connection)"
-        };
-
-        match v & 0x7F {
-            0x7D => format!("Not Determinable {}", conn),
-            0x7E => format!("Disabled {}", conn),
-            0x7F => "Not Installed".to_string(),
-            v => format!("{} MB {}", v, conn),
-        }
-    })
-}
-
 fn write_bus_address(
-    writer: &mut impl Write,
+    writer: &mut Dumper<'_, impl Write>,
     key: &str,
     seg: Option<u16>,
     bus: Option<u8>,
@@ -1578,7 +1762,7 @@ fn write_bus_address(
 }
 
 fn write_cache(
-    writer: &mut impl Write,
+    writer: &mut Dumper<'_, impl Write>,
     key: &str,
     level: &str,
     value: Option<u16>,
@@ -1614,3 +1798,294 @@ fn write_bytearray(writer: &mut impl Write, bytes: &[u8]) -> std::io::Result<()>
     writeln!(writer)?;
     Ok(())
 }
+
+#[cfg(test)]
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::*;
+    use super::*;
+
+    fn dump(options: &Options, table_ty: u8, body: &[u8]) -> String {
+        let tables = smbios_tables(&raw_bytes(table_ty, body, &[]));
+        let mut w = Dumper::new(vec![], options);
+        dump_table(&tables.tables()[0], &mut w, &tables).unwrap();
+        String::from_utf8(w.inner).unwrap()
+    }
+
+    #[test]
+    fn notes_only_with_flag() {
+        let mut processor = [0; 0x16];
+        processor[0x12..0x14].copy_from_slice(&2400u16.to_le_bytes());
+        let mut memory = [0; 0x17];
+        memory[0x11..0x13].copy_from_slice(&3200u16.to_le_bytes());
+        let bios = [0, 0, 0x00, 0xF0, 0, 0];
+
+        let cases = [
+            (0, &bios[..], "\tAddress: 0xF000"),
+            (4, &processor[..], "\tCurrent Speed: 2400 MHz"),
+            (17, &memory[..], "\tSpeed: 3200 MT/s"),
+        ];
+        for (table_ty, body, line) in cases {
+            let note = FIELD_NOTES
+                .iter()
+                .find(|(ty, _, _)| *ty == table_ty)
+                .map(|(_, _, note)| *note)
+                .unwrap();
+
+            let plain = dump(&Options::default(), table_ty, body);
+            assert!(plain.contains(&format!("{}\n", line)), "{}", plain);
+            assert!(!plain.contains("(note:"), "{}", plain);
+
+            let options = Options {
+                notes: true,
+                ..Options::default()
+            };
+            let noted = dump(&options, table_ty, body);
+            assert!(
+                noted.contains(&format!("{} (note: {})\n", line, note)),
+                "{}",
+                noted
+            );
+        }
+    }
+
+    // Byte patches over a prefix of the distinct body: type, length and
+    // (offset, bytes) pairs.
+    #[cfg(feature = "serde")]
+    type Branch = (u8, usize, &'static [(usize, &'static [u8])]);
+
+    // Structures of every type: one where every byte is distinct and names
+    // an existing string, one full of the 0xFF sentinels that switch to
+    // extended fields, and a few that take the remaining branches.
+    #[cfg(feature = "serde")]
+    fn maximal_fixture() -> SmbiosTables {
+        let strings: Vec<String> = (1..=0xFF).map(|i| format!("String {}", i)).collect();
+        let strings: Vec<&str> = strings.iter().map(|s| s.as_str()).collect();
+        let distinct: Vec<u8> = (1..=0xF0).collect();
+        let sentinels = [0xFF; 0xF0];
+
+        let mut structures = vec![];
+        for table_ty in (0..=46).filter(|&ty| ty != 31) {
+            structures.extend(raw_bytes(table_ty, &distinct, &strings));
+            structures.extend(raw_bytes(table_ty, &sentinels, &strings));
+        }
+
+        let branches: &[Branch] = &[
+            // Processor family 2
+            (4, 0xF0, &[(0x02, &[0xFE])]),
+            // Cache sizes before SMBIOS 3.1
+            (7, 0x0F, &[]),
+            // Extended maximum capacity
+            (16, 0xF0, &[(0x03, &[0x00, 0x00, 0x00, 0x80])]),
+            // Extended size
+            (17, 0xF0, &[(0x08, &[0xFF, 0x7F])]),
+            // TPM 2.0 firmware version
+            (43, 0xF0, &[(0x04, &[0x02])]),
+        ];
+        for (table_ty, length, patches) in branches {
+            let mut body = distinct[..*length].to_vec();
+            for (offset, bytes) in *patches {
+                body[*offset..*offset + bytes.len()].copy_from_slice(bytes);
+            }
+            structures.extend(raw_bytes(*table_ty, &body, &strings));
+        }
+
+        structures.extend(raw_bytes(126, &[], &[]));
+        structures.extend(raw_bytes(127, &[], &[]));
+        smbios_tables(&structures)
+    }
+
+    // Every way of changing one JSON value that a typed structure can be
+    // deserialized back from.
+    #[cfg(feature = "serde")]
+    fn mutations(value: &serde_json::Value) -> Vec<serde_json::Value> {
+        use serde_json::Value;
+
+        match value {
+            Value::Bool(b) => vec![Value::Bool(!b)],
+            Value::Number(n) => match (n.as_u64(), n.as_i64()) {
+                (Some(n), _) => (0..64).map(|bit| Value::from(n ^ (1 << bit))).collect(),
+                (_, Some(n)) => (0..64).map(|bit| Value::from(n ^ (1 << bit))).collect(),
+                _ => vec![],
+            },
+            Value::String(s) => vec![Value::from(format!("{}X", s))],
+            Value::Array(items) => {
+                let mut mutated = vec![];
+                for (i, item) in items.iter().enumerate().take(2) {
+                    for m in mutations(item) {
+                        let mut items = items.clone();
+                        items[i] = m;
+                        mutated.push(Value::Array(items));
+                    }
+                }
+                mutated
+            }
+            Value::Object(fields) => {
+                let mut mutated = vec![];
+                for (key, field) in fields {
+                    for m in mutations(field) {
+                        let mut fields = fields.clone();
+                        fields.insert(key.clone(), m);
+                        mutated.push(Value::Object(fields));
+                    }
+                }
+                mutated
+            }
+            Value::Null => vec![],
+        }
+    }
+
+    // Fields the text dump leaves out on purpose, by structure and field.
+    #[cfg(feature = "serde")]
+    const UNPRINTED_FIELDS: &[(&str, &str)] = &[
+        // Counts and lengths already shown by the list they size.
+        ("Additional", "num_additional_information_entities"),
+        ("BaseBoard", "num_contained_object"),
+        ("Chassis", "contained_element_count"),
+        ("FirmwareInventory", "num_associated_components"),
+        (
+            "ManagementControllerHostInterface",
+            "interface_ty_specific_data_length",
+        ),
+        ("ManagementControllerHostInterface", "num_protocol_records"),
+        ("OemStrings", "count"),
+        ("SystemConfigurationOptions", "count"),
+        // Raw data printed decoded or vendor-defined.
+        ("Additional", "strings"),
+        (
+            "ManagementControllerHostInterface",
+            "interface_ty_specific_data",
+        ),
+        ("TpmDevice", "firmawre_version2"),
+        // Structures whose dump is still a TODO.
+        ("B64MemoryError", "device_error_address"),
+        ("B64MemoryError", "error_granularity"),
+        ("B64MemoryError", "error_operation"),
+        ("B64MemoryError", "error_resolution"),
+        ("B64MemoryError", "error_ty"),
+        ("B64MemoryError", "memory_array_error_address"),
+        ("B64MemoryError", "vendor_syndrome"),
+        ("BuiltinPointingDevice", "interface"),
+        ("BuiltinPointingDevice", "num_buttons"),
+        ("BuiltinPointingDevice", "ty"),
+        ("FirmwareInventory", "associated_component_handles"),
+        ("FirmwareInventory", "characteristics"),
+        ("FirmwareInventory", "firmware_component_name"),
+        ("FirmwareInventory", "firmware_id"),
+        ("FirmwareInventory", "firmware_id_format"),
+        ("FirmwareInventory", "firmware_version"),
+        ("FirmwareInventory", "image_size"),
+        ("FirmwareInventory", "lowerest_supported_firmware_version"),
+        ("FirmwareInventory", "manufacturer"),
+        ("FirmwareInventory", "release_date"),
+        ("FirmwareInventory", "state"),
+        ("FirmwareInventory", "version_format"),
+        ("HardwareSecurity", "hardware_security_settings"),
+        ("IpmiDevice", "base_address"),
+        ("IpmiDevice", "i2c_target_address"),
+        ("IpmiDevice", "interface_ty"),
+        ("IpmiDevice", "interrupt_number"),
+        ("IpmiDevice", "ipmi_specification_revision"),
+        ("IpmiDevice", "nv_storage_device_adderss"),
+        ("MemoryChannel", "channel_ty"),
+        ("MemoryChannel", "maximum_channel_load"),
+        ("MemoryChannel", "memory_device_count"),
+        ("MemoryChannel", "memory_devices"),
+        ("OutOfBandRemoteAccess", "connections"),
+        ("OutOfBandRemoteAccess", "manufacturer_name"),
+        ("ProcessorAdditional", "processor_specific_block"),
+        ("ProcessorAdditional", "referenced_handle"),
+        ("StringProperty", "parent_handle"),
+        ("StringProperty", "string_property_id"),
+        ("StringProperty", "string_property_value"),
+        ("SystemEventLog", "length_each_log_ty_desc"),
+        ("SystemEventLog", "list_supported_event_log_ty_desc"),
+        ("SystemEventLog", "log_area_length"),
+        ("SystemEventLog", "log_change_token"),
+        ("SystemEventLog", "log_data_start_offset"),
+        ("SystemEventLog", "log_header_format"),
+        ("SystemEventLog", "log_header_start_offset"),
+        ("SystemEventLog", "log_status"),
+        ("SystemEventLog", "num_supported_log_ty_desc"),
+        (
+            "SystemPowerControls",
+            "next_scheduled_power_on_day_of_month",
+        ),
+        ("SystemPowerControls", "next_scheduled_power_on_hour"),
+        ("SystemPowerControls", "next_scheduled_power_on_minute"),
+        ("SystemPowerControls", "next_scheduled_power_on_month"),
+        ("SystemPowerControls", "next_scheduled_power_on_second"),
+    ];
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn every_field_is_printed() {
+        use std::collections::BTreeSet;
+
+        let tables = maximal_fixture();
+        let options = Options::default();
+        let render = |table: &SmbiosTable| {
+            let mut w = Dumper::new(vec![], &options);
+            dump_table(table, &mut w, &tables).unwrap();
+            String::from_utf8(w.inner).unwrap()
+        };
+
+        let mut populated = BTreeSet::new();
+        let mut in_text = BTreeSet::new();
+        let mut in_json = BTreeSet::new();
+        let mut all = BTreeSet::new();
+        for table in tables.iter() {
+            let json = serde_json::to_value(table).unwrap();
+            let name = json["type"].as_str().unwrap();
+            let text = render(table);
+
+            for field in table.field_names() {
+                let id = format!("{}.{}", name, field);
+                all.insert(id.clone());
+
+                let key = match json.get(*field) {
+                    Some(_) => field.to_string(),
+                    None => format!("{}_raw", field),
+                };
+                let value = &json[&key];
+                if value.is_null() || value.as_array().is_some_and(|a| a.is_empty()) {
+                    continue;
+                }
+                populated.insert(id.clone());
+
+                for m in mutations(value) {
+                    let mut mutated = json.clone();
+                    mutated[&key] = m;
+                    let Ok(table) = serde_json::from_value::<SmbiosTable>(mutated.clone()) else {
+                        continue;
+                    };
+                    if serde_json::to_value(&table).unwrap()[&key] != json[&key] {
+                        in_json.insert(id.clone());
+                    }
+                    if render(&table) != text {
+                        in_text.insert(id.clone());
+                        break;
+                    }
+                }
+            }
+        }
+
+        let unpopulated: Vec<_> = all.difference(&populated).collect();
+        assert!(unpopulated.is_empty(), "not populated: {:?}", unpopulated);
+        let lost: Vec<_> = all.difference(&in_json).collect();
+        assert!(lost.is_empty(), "not kept by JSON: {:?}", lost);
+
+        let unprinted: BTreeSet<_> = all.difference(&in_text).cloned().collect();
+        let allowed: BTreeSet<_> = UNPRINTED_FIELDS
+            .iter()
+            .map(|(name, field)| format!("{}.{}", name, field))
+            .collect();
+        let new: Vec<_> = unprinted.difference(&allowed).collect();
+        assert!(new.is_empty(), "not printed: {:?}", new);
+        let stale: Vec<_> = allowed.difference(&unprinted).collect();
+        assert!(stale.is_empty(), "printed but allowlisted: {:?}", stale);
+    }
+}
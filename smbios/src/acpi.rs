@@ -0,0 +1,80 @@
+use super::{EntryPoint, EntryPointAnchor, RawSmbiosData, SmbiosSource};
+use crate::error::ParseError;
+use bytes::Bytes;
+
+// Every ACPI table starts with the same header: the signature, the length of
+// the whole table, then revision, checksum and OEM identification.
+pub const ACPI_HEADER_SIZE: usize = 0x24;
+
+// Where a vendor ACPI table keeps its copy of the SMBIOS entry point and
+// structure table. These layouts are vendor-defined, so reading them is a
+// heuristic and the entry point's table address is ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AcpiCarrier {
+    pub signature: [u8; 4],
+    pub entry_point_offset: usize,
+    pub table_offset: usize,
+}
+
+pub const ACPI_CARRIERS: &[AcpiCarrier] = &[
+    // A 3.x entry point right after the header, then the structure table.
+    AcpiCarrier {
+        signature: *b"SMB3",
+        entry_point_offset: ACPI_HEADER_SIZE,
+        table_offset: ACPI_HEADER_SIZE + 0x18,
+    },
+    // A 2.x entry point padded to 0x20 bytes, then the structure table.
+    AcpiCarrier {
+        signature: *b"DMIV",
+        entry_point_offset: ACPI_HEADER_SIZE,
+        table_offset: ACPI_HEADER_SIZE + 0x20,
+    },
+];
+
+pub fn get_acpi_carrier(table: &[u8]) -> Option<&'static AcpiCarrier> {
+    ACPI_CARRIERS
+        .iter()
+        .find(|carrier| table.starts_with(&carrier.signature))
+}
+
+impl AcpiCarrier {
+    pub fn extract(&self, table: &[u8]) -> Result<(EntryPoint, RawSmbiosData), ParseError> {
+        let truncated = |field, offset| ParseError::Truncated {
+            structure: "AcpiCarrier",
+            field,
+            offset,
+        };
+
+        if !table.starts_with(&self.signature) {
+            return Err(ParseError::InvalidAnchor);
+        }
+        if table.len() < ACPI_HEADER_SIZE {
+            return Err(truncated("header", table.len()));
+        }
+
+        // The buffer may be padded past the length the table declares.
+        let length = u32::from_le_bytes(table[4..8].try_into().unwrap()) as usize;
+        if length > table.len() {
+            return Err(truncated("length", table.len()));
+        }
+        let table = &table[..length];
+
+        let entry = table.get(self.entry_point_offset..).unwrap_or_default();
+        let entry = EntryPoint::try_from_bytes(&mut Bytes::copy_from_slice(entry))?;
+
+        // A 2.x table must be exactly the entry point's table length; a 3.x
+        // table length is only a maximum.
+        let data = table.get(self.table_offset..).unwrap_or_default();
+        let data = &data[..data.len().min(entry.table_length as usize)];
+        if entry.anchor == EntryPointAnchor::Smbios2 && data.len() < entry.table_length as usize {
+            return Err(truncated(
+                "smbios_table_data",
+                self.table_offset.min(length) + data.len(),
+            ));
+        }
+
+        let source = SmbiosSource::Acpi(String::from_utf8_lossy(&self.signature).to_string());
+        let smbios = RawSmbiosData::from_entry_point(&entry, Bytes::copy_from_slice(data), source);
+        Ok((entry, smbios))
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{RawSmbiosData, SmbiosMeta, SmbiosTable, SmbiosTables, StringEncoding};
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+const CHUNK_SIZE: usize = 4096;
+
+#[derive(Debug, Clone, Copy)]
+struct Span {
+    chunk: u32,
+    start: u32,
+    end: u32,
+}
+
+// A bump allocator for the strings of one snapshot. Strings are appended to
+// pre-reserved chunks and the whole arena is freed once the last table that
+// references it is dropped.
+#[derive(Debug, Default)]
+pub struct Arena {
+    chunks: Vec<String>,
+    spans: Vec<Span>,
+}
+
+impl Arena {
+    pub fn new() -> Self {
+        Arena::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.spans
+            .get(index)
+            .map(|span| &self.chunks[span.chunk as usize][span.start as usize..span.end as usize])
+    }
+
+    pub fn approx_heap_bytes(&self) -> usize {
+        self.chunks.capacity() * std::mem::size_of::<String>()
+            + self.chunks.iter().map(|c| c.capacity()).sum::<usize>()
+            + self.spans.capacity() * std::mem::size_of::<Span>()
+    }
+
+    fn push(&mut self, encoding: StringEncoding, bytes: &[u8]) -> usize {
+        let fits = self
+            .chunks
+            .last()
+            .is_some_and(|c| c.capacity() - c.len() >= bytes.len());
+        if !fits {
+            self.chunks
+                .push(String::with_capacity(CHUNK_SIZE.max(bytes.len())));
+        }
+
+        let chunk = self.chunks.len() - 1;
+        let buf = &mut self.chunks[chunk];
+        let start = buf.len();
+        encoding.decode_into(bytes, buf);
+        self.spans.push(Span {
+            chunk: chunk as u32,
+            start: start as u32,
+            end: buf.len() as u32,
+        });
+        self.spans.len() - 1
+    }
+}
+
+// The strings of one structure: `count` consecutive arena entries.
+#[derive(Debug, Clone)]
+pub(crate) struct ArenaStrings {
+    arena: Arc<Arena>,
+    first: usize,
+    count: usize,
+}
+
+impl ArenaStrings {
+    pub(crate) fn get(&self, index: u8) -> Option<ArenaStr> {
+        let i = (index as usize).checked_sub(1)?;
+        (i < self.count).then(|| ArenaStr {
+            arena: self.arena.clone(),
+            index: self.first + i,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct ArenaStr {
+    arena: Arc<Arena>,
+    index: usize,
+}
+
+impl Deref for ArenaStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.arena.get(self.index).unwrap_or_default()
+    }
+}
+
+// The value behind an `SmbiosString` when the `arena` feature is enabled.
+#[derive(Clone)]
+pub enum StringValue {
+    Owned(String),
+    Arena(ArenaStr),
+}
+
+impl StringValue {
+    // Arena strings are counted once by the arena itself.
+    pub fn capacity(&self) -> usize {
+        match self {
+            StringValue::Owned(s) => s.capacity(),
+            StringValue::Arena(_) => 0,
+        }
+    }
+}
+
+impl Deref for StringValue {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match self {
+            StringValue::Owned(s) => s,
+            StringValue::Arena(s) => s,
+        }
+    }
+}
+
+impl From<String> for StringValue {
+    fn from(value: String) -> Self {
+        StringValue::Owned(value)
+    }
+}
+
+impl PartialEq for StringValue {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for StringValue {}
+
+impl fmt::Debug for StringValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for StringValue {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for StringValue {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(StringValue::Owned)
+    }
+}
+
+impl SmbiosTables {
+    // Same as `SmbiosTables::from`, but every `SmbiosString` is allocated from
+    // a single arena shared by the whole snapshot.
+    pub fn from_in_arena(data: RawSmbiosData) -> Self {
+        let meta = SmbiosMeta::new(&data, SystemTime::now());
+        let mut raws: Vec<_> = data.structures().collect();
+
+        let mut arena = Arena::new();
+        let mut firsts = Vec::with_capacity(raws.len());
+        for raw in &raws {
+            firsts.push(arena.len());
+            for s in &raw.tailer {
+                arena.push(raw.encoding, s);
+            }
+        }
+
+        let arena = Arc::new(arena);
+        for (raw, first) in raws.iter_mut().zip(firsts) {
+            raw.arena = Some(ArenaStrings {
+                arena: arena.clone(),
+                first,
+                count: raw.tailer.len(),
+            });
+        }
+
+        let tables = raws.into_iter().map(SmbiosTable::from).collect();
+        SmbiosTables {
+            data,
+            tables,
+            meta,
+            arena: Some(arena),
+        }
+    }
+
+    pub fn arena(&self) -> Option<&Arena> {
+        self.arena.as_deref()
+    }
+}
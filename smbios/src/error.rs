@@ -1,8 +1,37 @@
+use std::fmt;
+
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
     Io(std::io::Error),
-    #[cfg(target_family = "windows")]
+    #[cfg(all(feature = "acquire", target_family = "windows"))]
     Win32(windows::core::Error),
+    Parse(ParseError),
+    Timeout,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "I/O error: {}", error),
+            #[cfg(all(feature = "acquire", target_family = "windows"))]
+            Error::Win32(error) => write!(f, "Win32 error: {}", error),
+            Error::Parse(error) => write!(f, "parse error: {}", error),
+            Error::Timeout => write!(f, "timed out reading SMBIOS data"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(error) => Some(error),
+            #[cfg(all(feature = "acquire", target_family = "windows"))]
+            Error::Win32(error) => Some(error),
+            Error::Parse(error) => Some(error),
+            Error::Timeout => None,
+        }
+    }
 }
 
 impl From<std::io::Error> for Error {
@@ -11,9 +40,118 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[cfg(target_family = "windows")]
+#[cfg(all(feature = "acquire", target_family = "windows"))]
 impl From<windows::core::Error> for Error {
     fn from(error: windows::core::Error) -> Self {
         Error::Win32(error)
     }
 }
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    Truncated {
+        structure: &'static str,
+        field: &'static str,
+        offset: usize,
+    },
+    FieldTruncated {
+        structure: &'static str,
+        field: &'static str,
+        offset: usize,
+        length: u8,
+    },
+    InvalidAnchor,
+    InvalidLength {
+        handle: u16,
+        length: u8,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Truncated {
+                structure,
+                field,
+                offset,
+            } => write!(
+                f,
+                "truncated at offset {} reading {}.{}",
+                offset, structure, field
+            ),
+            ParseError::FieldTruncated {
+                structure,
+                field,
+                offset,
+                length,
+            } => write!(
+                f,
+                "truncated at offset {} reading {}.{} (declared length {})",
+                offset, structure, field, length
+            ),
+            ParseError::InvalidAnchor => write!(f, "unknown entry point anchor"),
+            ParseError::InvalidLength { handle, length } => write!(
+                f,
+                "structure 0x{:04X} declares length {}, shorter than its header",
+                handle, length
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(feature = "encode")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EncodeError {
+    TooLong {
+        structure: &'static str,
+        length: usize,
+    },
+    CountOverflow {
+        structure: &'static str,
+        field: &'static str,
+        count: usize,
+    },
+    TooManyStrings {
+        structure: &'static str,
+    },
+}
+
+#[cfg(feature = "encode")]
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::TooLong { structure, length } => write!(
+                f,
+                "{} is {} bytes long, more than a structure can declare",
+                structure, length
+            ),
+            EncodeError::CountOverflow {
+                structure,
+                field,
+                count,
+            } => write!(
+                f,
+                "{}.{} cannot hold a count of {}",
+                structure, field, count
+            ),
+            EncodeError::TooManyStrings { structure } => write!(
+                f,
+                "{} needs more strings than a structure can reference",
+                structure
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "encode")]
+impl std::error::Error for EncodeError {}
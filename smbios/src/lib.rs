@@ -1,22 +1,217 @@
+pub mod acpi;
+#[cfg(feature = "arena")]
+pub mod arena;
 pub mod error;
 
-#[cfg(target_family = "unix")]
+#[cfg(feature = "oem")]
+pub mod oem;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(all(feature = "acquire", target_family = "unix"))]
 mod unix;
-#[cfg(target_family = "windows")]
+#[cfg(all(feature = "acquire", target_family = "windows"))]
 mod windows;
 
-#[cfg(target_family = "unix")]
-pub use self::unix::get_smbios;
-#[cfg(target_family = "windows")]
-pub use self::windows::get_smbios;
+#[cfg(all(feature = "acquire", target_family = "unix"))]
+pub use self::unix::{get_smbios, get_smbios_with_acpi_fallback, get_smbios_with_entry};
+#[cfg(all(feature = "acquire", target_family = "windows"))]
+pub use self::windows::{get_smbios, get_smbios_timeout, get_smbios_with_acpi_fallback};
 use bytes::{Buf, Bytes};
-use smbios_derive::SMBIOS;
+#[cfg(feature = "encode")]
+use bytes::{BufMut, BytesMut};
+#[cfg(feature = "encode")]
+use error::EncodeError;
+use error::ParseError;
+use smbios_derive::{SmbiosEnum, SMBIOS};
 use std::collections::HashMap;
+use std::io::Read;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+#[cfg(feature = "uuid")]
 use uuid::Uuid;
 
+#[cfg(feature = "arena")]
+use arena::StringValue;
+#[cfg(not(feature = "arena"))]
+type StringValue = String;
+
 static TABLE_NAMES: OnceLock<HashMap<u8, &'static str>> = OnceLock::new();
 
+// The highest structure type the spec defines. Types after it up to 125 are
+// reserved, 126 and 127 are Inactive and End of Table.
+pub const MAX_SPEC_TABLE_TY: u8 = 46;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmbiosString {
+    index: u8,
+    value: Option<StringValue>,
+}
+
+impl SmbiosString {
+    #[cfg_attr(not(feature = "arena"), allow(clippy::useless_conversion))]
+    pub fn from_index(raw: &RawSmbiosTable, index: u8) -> Self {
+        #[cfg(feature = "arena")]
+        if let Some(strings) = &raw.arena {
+            return SmbiosString {
+                index,
+                value: strings.get(index).map(StringValue::Arena),
+            };
+        }
+
+        SmbiosString {
+            index,
+            value: raw.get_string_by_index(index).map(StringValue::from),
+        }
+    }
+
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    pub fn is_dangling(&self) -> bool {
+        self.index != 0 && self.value.is_none()
+    }
+
+    pub fn approx_heap_bytes(&self) -> usize {
+        self.value.as_ref().map_or(0, |v| v.capacity())
+    }
+}
+
+impl From<&str> for SmbiosString {
+    fn from(value: &str) -> Self {
+        SmbiosString::from(value.to_string())
+    }
+}
+
+impl From<String> for SmbiosString {
+    fn from(value: String) -> Self {
+        // The index is assigned when the structure is encoded.
+        SmbiosString {
+            index: 0,
+            value: Some(StringValue::from(value)),
+        }
+    }
+}
+
+#[cfg(feature = "encode")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StringPool {
+    strings: Vec<String>,
+}
+
+#[cfg(feature = "encode")]
+impl StringPool {
+    pub fn new() -> Self {
+        StringPool::default()
+    }
+
+    pub fn push(&mut self, value: &str) -> u8 {
+        if self.strings.len() >= u8::MAX as usize {
+            return 0;
+        }
+
+        self.strings.push(value.to_string());
+        self.strings.len() as u8
+    }
+
+    pub fn intern(&mut self, value: &str) -> u8 {
+        if value.is_empty() {
+            return 0;
+        }
+
+        match self.strings.iter().position(|s| s == value) {
+            Some(i) => (i + 1) as u8,
+            None => self.push(value),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        for s in &self.strings {
+            bytes.extend_from_slice(s.as_bytes());
+            bytes.push(0);
+        }
+        if self.strings.is_empty() {
+            bytes.push(0);
+        }
+        bytes.push(0);
+        bytes
+    }
+}
+
+pub trait SmbiosEnum {
+    fn from_raw(value: u64) -> Self;
+    fn to_raw(&self) -> u64;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum FieldValue {
+    Text(String),
+    List(Vec<String>),
+}
+
+impl FieldValue {
+    pub fn with_unit(self, unit: &str) -> Self {
+        match self {
+            FieldValue::Text(v) => FieldValue::Text(format!("{}{}", v, unit)),
+            FieldValue::List(v) => {
+                FieldValue::List(v.into_iter().map(|e| format!("{}{}", e, unit)).collect())
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldValue::Text(v) => f.write_str(v),
+            FieldValue::List(v) => f.write_str(&v.join(", ")),
+        }
+    }
+}
+
+macro_rules! field_value_from {
+    ($($ty: ty),*) => {
+        $(
+            impl From<$ty> for FieldValue {
+                fn from(value: $ty) -> Self {
+                    FieldValue::Text(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+field_value_from!(&str, String, u8, u16, u32, u64, i8, i16, i32, i64, bool);
+
+impl<T: std::fmt::Display> From<&[T]> for FieldValue {
+    fn from(value: &[T]) -> Self {
+        FieldValue::List(value.iter().map(|e| e.to_string()).collect())
+    }
+}
+
+impl<T: std::fmt::Display> From<Vec<T>> for FieldValue {
+    fn from(value: Vec<T>) -> Self {
+        FieldValue::List(value.iter().map(|e| e.to_string()).collect())
+    }
+}
+
 fn init_table() -> HashMap<u8, &'static str> {
     let mut names = HashMap::new();
     names.insert(0, "BIOS Information");
@@ -79,13 +274,198 @@ pub fn get_table_name_by_id(id: u8) -> Option<&'static str> {
     }
 }
 
+// Clarifications for commonly misread fields, by table type and dump label.
+pub const FIELD_NOTES: &[(u8, &str, &str)] = &[
+    (0, "Address", Bios::bios_starting_address_note()),
+    (4, "Current Speed", Processor::current_speed_note()),
+    (17, "Speed", MemoryDevice::speed_note()),
+];
+
+pub fn get_field_note(table_ty: u8, label: &str) -> Option<&'static str> {
+    FIELD_NOTES
+        .iter()
+        .find(|(ty, key, _)| *ty == table_ty && *key == label)
+        .map(|(_, _, note)| *note)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SmbiosSource {
+    LinuxSysfs,
+    LinuxDevMem,
+    WindowsRsmb,
+    Buffer,
+    File(PathBuf),
+    Stdin,
+    Acpi(String),
+}
+
+impl std::fmt::Display for SmbiosSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let source = match self {
+            SmbiosSource::LinuxSysfs => "linux-sysfs",
+            SmbiosSource::LinuxDevMem => "linux-devmem",
+            SmbiosSource::WindowsRsmb => "windows-rsmb",
+            SmbiosSource::Buffer => "buffer",
+            SmbiosSource::File(path) => return write!(f, "file:{}", path.display()),
+            SmbiosSource::Stdin => "stdin",
+            SmbiosSource::Acpi(signature) => return write!(f, "acpi:{}", signature),
+        };
+        write!(f, "{}", source)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryPointAnchor {
+    Smbios2,
+    Smbios3,
+}
+
+impl std::fmt::Display for EntryPointAnchor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EntryPointAnchor::Smbios2 => write!(f, "_SM_"),
+            EntryPointAnchor::Smbios3 => write!(f, "_SM3_"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryPoint {
+    pub anchor: EntryPointAnchor,
+    pub checksum: u8,
+    pub length: u8,
+    pub major_version: u8,
+    pub minor_version: u8,
+    pub revision: u8,
+    // 2.x only
+    pub max_structure_size: Option<u16>,
+    pub intermediate_checksum: Option<u8>,
+    pub num_structures: Option<u16>,
+    pub bcd_revision: Option<u8>,
+    // 3.x only
+    pub docrev: Option<u8>,
+    // Table length for 2.x, maximum table size for 3.x.
+    pub table_length: u32,
+    pub table_address: u64,
+}
+
+impl EntryPoint {
+    pub fn try_from_bytes(buf: &mut Bytes) -> Result<Self, ParseError> {
+        let truncated = |field, offset| ParseError::Truncated {
+            structure: "EntryPoint",
+            field,
+            offset,
+        };
+
+        if buf.starts_with(b"_SM_") {
+            if buf.remaining() < 0x1F {
+                return Err(truncated("anchor", buf.remaining()));
+            }
+
+            buf.advance(4);
+            let checksum = buf.get_u8();
+            let length = buf.get_u8();
+            let major_version = buf.get_u8();
+            let minor_version = buf.get_u8();
+            let max_structure_size = buf.get_u16_le();
+            let revision = buf.get_u8();
+            buf.advance(5); // formatted area
+            buf.advance(5); // intermediate anchor
+            let intermediate_checksum = buf.get_u8();
+            let table_length = buf.get_u16_le() as u32;
+            let table_address = buf.get_u32_le() as u64;
+            let num_structures = buf.get_u16_le();
+            let bcd_revision = buf.get_u8();
+
+            Ok(EntryPoint {
+                anchor: EntryPointAnchor::Smbios2,
+                checksum,
+                length,
+                major_version,
+                minor_version,
+                revision,
+                max_structure_size: Some(max_structure_size),
+                intermediate_checksum: Some(intermediate_checksum),
+                num_structures: Some(num_structures),
+                bcd_revision: Some(bcd_revision),
+                docrev: None,
+                table_length,
+                table_address,
+            })
+        } else if buf.starts_with(b"_SM3_") {
+            if buf.remaining() < 0x18 {
+                return Err(truncated("anchor", buf.remaining()));
+            }
+
+            buf.advance(5);
+            let checksum = buf.get_u8();
+            let length = buf.get_u8();
+            let major_version = buf.get_u8();
+            let minor_version = buf.get_u8();
+            let docrev = buf.get_u8();
+            let revision = buf.get_u8();
+            buf.advance(1); // reserved
+            let table_length = buf.get_u32_le();
+            let table_address = buf.get_u64_le();
+
+            Ok(EntryPoint {
+                anchor: EntryPointAnchor::Smbios3,
+                checksum,
+                length,
+                major_version,
+                minor_version,
+                revision,
+                max_structure_size: None,
+                intermediate_checksum: None,
+                num_structures: None,
+                bcd_revision: None,
+                docrev: Some(docrev),
+                table_length,
+                table_address,
+            })
+        } else {
+            Err(ParseError::InvalidAnchor)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StringEncoding {
+    #[default]
+    Utf8Lossy,
+    Latin1,
+}
+
+impl StringEncoding {
+    pub fn decode(&self, bytes: &[u8]) -> String {
+        match self {
+            StringEncoding::Utf8Lossy => String::from_utf8_lossy(bytes).to_string(),
+            // Latin-1 bytes are the first 256 Unicode code points.
+            StringEncoding::Latin1 => bytes.iter().map(|&b| b as char).collect(),
+        }
+    }
+
+    pub fn decode_into(&self, bytes: &[u8], out: &mut String) {
+        match self {
+            StringEncoding::Utf8Lossy => out.push_str(&String::from_utf8_lossy(bytes)),
+            StringEncoding::Latin1 => out.extend(bytes.iter().map(|&b| b as char)),
+        }
+    }
+}
+
 pub struct RawSmbiosData {
     pub used_20_calling_method: u8,
     pub smbios_major_version: u8,
     pub smbios_minior_version: u8,
     pub dmi_revision: u8,
     pub length: u32,
+    pub num_structures: Option<u16>,
     pub smbios_table_data: Bytes,
+    pub source: SmbiosSource,
+    pub entry_point: Option<EntryPointAnchor>,
+    pub string_encoding: StringEncoding,
+    pub resync: bool,
 }
 
 impl RawSmbiosData {
@@ -93,6 +473,168 @@ impl RawSmbiosData {
         self.smbios_major_version > major
             || self.smbios_major_version == major && self.smbios_minior_version >= minor
     }
+
+    pub fn approx_heap_bytes(&self) -> usize {
+        self.smbios_table_data.len()
+    }
+
+    // Some firmware declares versions that never existed; read them the way
+    // dmidecode does.
+    pub fn effective_version(&self) -> (u8, u8) {
+        match (self.smbios_major_version, self.smbios_minior_version) {
+            (2, 33) => (2, 3),
+            (2, 51) => (2, 6),
+            version => version,
+        }
+    }
+
+    // FNV-1a over the structure table, for telling snapshots apart.
+    pub fn content_hash(&self) -> u64 {
+        self.smbios_table_data
+            .iter()
+            .fold(0xCBF2_9CE4_8422_2325, |hash, &b| {
+                (hash ^ b as u64).wrapping_mul(0x0000_0100_0000_01B3)
+            })
+    }
+
+    pub fn structures(&self) -> StructureIter {
+        // Anything past the declared length (e.g. RSMB zero padding) is ignored.
+        let data = match self.length as usize {
+            0 => self.smbios_table_data.clone(),
+            length => self
+                .smbios_table_data
+                .slice(..length.min(self.smbios_table_data.len())),
+        };
+
+        StructureIter {
+            total: data.len(),
+            data,
+            encoding: self.string_encoding,
+            resync: self.resync,
+            resyncs: vec![],
+            error: None,
+        }
+    }
+
+    pub fn from_entry_point(
+        entry: &EntryPoint,
+        smbios_table_data: Bytes,
+        source: SmbiosSource,
+    ) -> Self {
+        match entry.anchor {
+            EntryPointAnchor::Smbios2 => RawSmbiosData {
+                used_20_calling_method: 1,
+                smbios_major_version: entry.major_version,
+                smbios_minior_version: entry.minor_version,
+                dmi_revision: entry.revision,
+                length: entry.table_length,
+                num_structures: entry.num_structures,
+                smbios_table_data,
+                source,
+                entry_point: Some(entry.anchor),
+                string_encoding: StringEncoding::default(),
+                resync: false,
+            },
+            EntryPointAnchor::Smbios3 => RawSmbiosData {
+                used_20_calling_method: 0,
+                smbios_major_version: entry.major_version,
+                smbios_minior_version: entry.minor_version,
+                dmi_revision: entry.docrev.unwrap_or_default(),
+                length: 0,
+                num_structures: None,
+                smbios_table_data,
+                source,
+                entry_point: Some(entry.anchor),
+                string_encoding: StringEncoding::default(),
+                resync: false,
+            },
+        }
+    }
+
+    // The stream holds the entry point structure (0x1F bytes for `_SM_`,
+    // 0x18 bytes for `_SM3_`) immediately followed by the structure table.
+    // A 2.x table must be exactly the entry point's table length; a 3.x
+    // table length is only a maximum, so the stream may end earlier.
+    pub fn from_reader(mut reader: impl Read) -> Result<Self, error::Error> {
+        let mut entry = vec![0; 0x18];
+        reader.read_exact(&mut entry)?;
+        if entry.starts_with(b"_SM_") {
+            entry.resize(0x1F, 0);
+            reader.read_exact(&mut entry[0x18..])?;
+        }
+        let entry = EntryPoint::try_from_bytes(&mut Bytes::from(entry))?;
+
+        let mut data = vec![];
+        reader
+            .take(entry.table_length as u64)
+            .read_to_end(&mut data)?;
+        if entry.anchor == EntryPointAnchor::Smbios2 && data.len() < entry.table_length as usize {
+            return Err(ParseError::Truncated {
+                structure: "RawSmbiosData",
+                field: "smbios_table_data",
+                offset: data.len(),
+            }
+            .into());
+        }
+
+        Ok(RawSmbiosData::from_entry_point(
+            &entry,
+            Bytes::from(data),
+            SmbiosSource::Buffer,
+        ))
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, error::Error> {
+        let path = path.as_ref();
+        let mut smbios = RawSmbiosData::from_reader(std::fs::File::open(path)?)?;
+        smbios.source = SmbiosSource::File(path.to_path_buf());
+        Ok(smbios)
+    }
+
+    pub fn from_stdin() -> Result<Self, error::Error> {
+        let mut smbios = RawSmbiosData::from_reader(std::io::stdin().lock())?;
+        smbios.source = SmbiosSource::Stdin;
+        Ok(smbios)
+    }
+
+    pub fn try_from_bytes(buf: &mut Bytes) -> Result<Self, ParseError> {
+        if buf.remaining() < 8 {
+            return Err(ParseError::Truncated {
+                structure: "RawSmbiosData",
+                field: "length",
+                offset: buf.remaining(),
+            });
+        }
+
+        let used_20_calling_method = buf.get_u8();
+        let smbios_major_version = buf.get_u8();
+        let smbios_minior_version = buf.get_u8();
+        let dmi_revision = buf.get_u8();
+        let length = buf.get_u32_le();
+
+        if buf.remaining() < length as usize {
+            return Err(ParseError::Truncated {
+                structure: "RawSmbiosData",
+                field: "smbios_table_data",
+                offset: 8 + buf.remaining(),
+            });
+        }
+        let smbios_table_data = buf.split_to(length as usize);
+
+        Ok(RawSmbiosData {
+            used_20_calling_method,
+            smbios_major_version,
+            smbios_minior_version,
+            dmi_revision,
+            length,
+            num_structures: None,
+            smbios_table_data,
+            source: SmbiosSource::Buffer,
+            entry_point: None,
+            string_encoding: StringEncoding::default(),
+            resync: false,
+        })
+    }
 }
 
 impl From<&mut Bytes> for RawSmbiosData {
@@ -110,137 +652,736 @@ impl From<&mut Bytes> for RawSmbiosData {
             smbios_minior_version,
             dmi_revision,
             length,
+            num_structures: None,
             smbios_table_data,
+            source: SmbiosSource::Buffer,
+            entry_point: None,
+            string_encoding: StringEncoding::default(),
+            resync: false,
         }
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RawSmbiosTable {
     pub table_ty: u8,
     pub length: u8,
     pub handle: u16,
+    #[cfg_attr(feature = "serde", serde(with = "serialize::hex"))]
     pub body: Bytes,
+    #[cfg_attr(feature = "serde", serde(with = "serialize::hex_list"))]
     pub tailer: Vec<Vec<u8>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub encoding: StringEncoding,
+    #[cfg(feature = "arena")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) arena: Option<arena::ArenaStrings>,
 }
 
 impl RawSmbiosTable {
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
     pub fn get_string_by_index(&self, index: u8) -> Option<String> {
+        self.string_bytes_by_index(index)
+            .map(|v| self.encoding.decode(v))
+    }
+
+    pub fn string_bytes_by_index(&self, index: u8) -> Option<&[u8]> {
         if index < 1 {
             return None;
         }
 
         let i: usize = (index as usize) - 1;
-        self.tailer
-            .get(i)
-            .map(|v| String::from_utf8_lossy(v).to_string())
+        self.tailer.get(i).map(|v| v.as_slice())
     }
-}
 
-impl From<&mut Bytes> for RawSmbiosTable {
-    fn from(buf: &mut Bytes) -> Self {
+    pub fn try_from_bytes(buf: &mut Bytes) -> Result<Self, ParseError> {
+        let truncated = |field, offset| ParseError::Truncated {
+            structure: "RawSmbiosTable",
+            field,
+            offset,
+        };
+
+        if buf.remaining() < 1 {
+            return Err(truncated("table_ty", 0));
+        }
         let table_ty = buf.get_u8();
+
+        if buf.remaining() < 1 {
+            return Err(truncated("length", 1));
+        }
         let length = buf.get_u8();
+
+        if buf.remaining() < 2 {
+            return Err(truncated("handle", 2));
+        }
         let handle = buf.get_u16_le();
-        let body = buf.split_to((length - 4) as usize);
-        let mut tailer = vec![];
-        let mut value = vec![];
-        while buf.remaining() != 0 {
-            let mut c = buf.get_u8();
-            while c != 0 {
-                value.push(c);
-                c = buf.get_u8();
-            }
 
-            if !value.is_empty() {
-                tailer.push(value);
-            }
+        if length < 4 {
+            return Err(ParseError::InvalidLength { handle, length });
+        }
 
-            c = buf.get_u8();
-            if c == 0 {
-                break;
-            } else {
-                value = vec![c];
-            }
+        let body_length = (length - 4) as usize;
+        if buf.remaining() < body_length {
+            return Err(truncated("body", 4 + buf.remaining()));
+        }
+        let body = buf.split_to(body_length);
+
+        let remaining = buf.remaining();
+        let (tailer, terminated) = read_string_set(buf);
+        if !terminated {
+            let offset = 4 + body_length + remaining - buf.remaining();
+            return Err(truncated("strings", offset));
         }
 
+        Ok(RawSmbiosTable {
+            table_ty,
+            length,
+            handle,
+            body,
+            tailer,
+            encoding: StringEncoding::default(),
+            #[cfg(feature = "arena")]
+            arena: None,
+        })
+    }
+
+    pub fn approx_heap_bytes(&self) -> usize {
+        self.tailer.capacity() * std::mem::size_of::<Vec<u8>>()
+            + self.tailer.iter().map(|s| s.capacity()).sum::<usize>()
+    }
+}
+
+// The string set ends at the first pair of consecutive NULs; a structure
+// without strings is just that pair. Returns the complete strings and
+// whether the terminating pair was found before the buffer ran out.
+fn read_string_set(buf: &mut Bytes) -> (Vec<Vec<u8>>, bool) {
+    let mut strings = vec![];
+    let mut value = vec![];
+    let mut last_nul = false;
+    while buf.has_remaining() {
+        let c = buf.get_u8();
+        if c != 0 {
+            value.push(c);
+            last_nul = false;
+            continue;
+        }
+
+        if last_nul {
+            return (strings, true);
+        }
+        if !value.is_empty() {
+            strings.push(std::mem::take(&mut value));
+        }
+        last_nul = true;
+    }
+
+    (strings, false)
+}
+
+// Lenient: a truncated header, body or string set keeps whatever was read.
+impl From<&mut Bytes> for RawSmbiosTable {
+    fn from(buf: &mut Bytes) -> Self {
+        let mut header = [0; 4];
+        let header_length = buf.remaining().min(header.len());
+        buf.copy_to_slice(&mut header[..header_length]);
+        let [table_ty, length, handle_lo, handle_hi] = header;
+        let handle = u16::from_le_bytes([handle_lo, handle_hi]);
+
+        let body_length = (length.saturating_sub(4) as usize).min(buf.remaining());
+        let body = buf.split_to(body_length);
+        let (tailer, _) = read_string_set(buf);
+
         RawSmbiosTable {
             table_ty,
             length,
             handle,
             body,
             tailer,
+            encoding: StringEncoding::default(),
+            #[cfg(feature = "arena")]
+            arena: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Resync {
+    pub offset: usize,
+    pub skipped: usize,
+}
+
+pub struct StructureIter {
+    data: Bytes,
+    encoding: StringEncoding,
+    resync: bool,
+    total: usize,
+    resyncs: Vec<Resync>,
+    error: Option<(usize, ParseError)>,
+}
+
+impl StructureIter {
+    pub fn resyncs(&self) -> &[Resync] {
+        &self.resyncs
+    }
+
+    // The offset and reason iteration stopped early, if it did.
+    pub fn error(&self) -> Option<(usize, &ParseError)> {
+        self.error.as_ref().map(|(offset, error)| (*offset, error))
+    }
+
+    fn is_plausible_header(data: &[u8]) -> bool {
+        match data {
+            // Reserved types are neither defined by the spec nor OEM-specific.
+            [ty, length, ..] => {
+                !(MAX_SPEC_TABLE_TY + 1..126).contains(ty)
+                    && *length >= 4
+                    && *length as usize <= data.len()
+            }
+            _ => false,
+        }
+    }
+
+    // Skips to just past the next double NUL until a plausible header follows.
+    fn skip_to_next_header(&mut self) {
+        let offset = self.total - self.data.len();
+        while !self.data.is_empty() && !Self::is_plausible_header(&self.data) {
+            let skip = match self.data.windows(2).position(|w| w == [0, 0]) {
+                Some(i) => i + 2,
+                None => self.data.len(),
+            };
+            self.data.advance(skip);
+        }
+
+        let skipped = self.total - self.data.len() - offset;
+        if skipped > 0 {
+            self.resyncs.push(Resync { offset, skipped });
+        }
+    }
+}
+
+impl Iterator for StructureIter {
+    type Item = RawSmbiosTable;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.resync {
+            self.skip_to_next_header();
+        }
+
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let offset = self.total - self.data.len();
+        let mut data = self.data.clone();
+        let mut table = match RawSmbiosTable::try_from_bytes(&mut data) {
+            Ok(table) => {
+                self.data = data;
+                table
+            }
+            // A cut-short body or string set still yields what was read.
+            Err(error @ ParseError::Truncated { offset: at, .. }) if at >= 4 => {
+                self.error = Some((offset, error));
+                RawSmbiosTable::from(&mut std::mem::take(&mut self.data))
+            }
+            Err(error) => {
+                self.error = Some((offset, error));
+                self.data.clear();
+                return None;
+            }
+        };
+        table.encoding = self.encoding;
+        if table.table_ty == 127 {
+            self.data.clear();
+        }
+        Some(table)
+    }
+}
+
+macro_rules! smbios_tables {
+    ($($ty: literal => $name: ident),* $(,)?) => {
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize),
+            serde(tag = "type")
+        )]
+        pub enum SmbiosTable {
+            $($name($name),)*
+            Other(RawSmbiosTable),
+        }
+
+        impl SmbiosTable {
+            pub fn table_ty(&self) -> u8 {
+                match self {
+                    $(SmbiosTable::$name(t) => t.table_ty(),)*
+                    SmbiosTable::Other(t) => t.table_ty,
+                }
+            }
+
+            pub fn length(&self) -> u8 {
+                match self {
+                    $(SmbiosTable::$name(t) => t.length(),)*
+                    SmbiosTable::Other(t) => t.length,
+                }
+            }
+
+            pub fn handle(&self) -> u16 {
+                match self {
+                    $(SmbiosTable::$name(t) => t.handle(),)*
+                    SmbiosTable::Other(t) => t.handle,
+                }
+            }
+
+            pub fn approx_heap_bytes(&self) -> usize {
+                match self {
+                    $(SmbiosTable::$name(t) => t.approx_heap_bytes(),)*
+                    SmbiosTable::Other(t) => t.approx_heap_bytes(),
+                }
+            }
+
+            pub fn length_is_valid(&self, smbios: &RawSmbiosData) -> bool {
+                match self {
+                    $(SmbiosTable::$name(t) => t.length_is_valid(smbios),)*
+                    SmbiosTable::Other(_) => true,
+                }
+            }
+
+            pub fn field_names(&self) -> &'static [&'static str] {
+                match self {
+                    $(SmbiosTable::$name(_) => $name::FIELD_NAMES,)*
+                    SmbiosTable::Other(_) => &[],
+                }
+            }
+
+            pub fn missing_fields(&self) -> Vec<&'static str> {
+                match self {
+                    $(SmbiosTable::$name(t) => t.missing_fields(),)*
+                    SmbiosTable::Other(_) => vec![],
+                }
+            }
+        }
+
+        impl From<RawSmbiosTable> for SmbiosTable {
+            fn from(raw: RawSmbiosTable) -> Self {
+                match raw.table_ty {
+                    $($ty => SmbiosTable::$name($name::from_raw_table(&raw)),)*
+                    _ => SmbiosTable::Other(raw),
+                }
+            }
+        }
+    };
+}
+
+smbios_tables! {
+    0 => Bios,
+    1 => System,
+    2 => BaseBoard,
+    3 => Chassis,
+    4 => Processor,
+    5 => MemoryController,
+    6 => MemoryModule,
+    7 => Cache,
+    8 => PortConnector,
+    9 => SystemSlots,
+    10 => OnBoardDevices,
+    11 => OemStrings,
+    12 => SystemConfigurationOptions,
+    13 => BiosLanguage,
+    14 => GroupAssociations,
+    15 => SystemEventLog,
+    16 => PhysicalMemoryArray,
+    17 => MemoryDevice,
+    18 => B32MemoryError,
+    19 => MemoryArrayMappedAddress,
+    20 => MemoryDeviceMappedAddress,
+    21 => BuiltinPointingDevice,
+    22 => PortableBattery,
+    23 => SystemReset,
+    24 => HardwareSecurity,
+    25 => SystemPowerControls,
+    26 => VoltageProbe,
+    27 => CoolingDevice,
+    28 => TemperatureProbe,
+    29 => ElectricalCurrentProbe,
+    30 => OutOfBandRemoteAccess,
+    32 => SystemBoot,
+    33 => B64MemoryError,
+    34 => ManagementDevice,
+    35 => ManagementDeviceComponent,
+    36 => ManagementDeviceThresholdData,
+    37 => MemoryChannel,
+    38 => IpmiDevice,
+    39 => SystemPowerSupply,
+    40 => Additional,
+    41 => OnboardDevicesExtended,
+    42 => ManagementControllerHostInterface,
+    43 => TpmDevice,
+    44 => ProcessorAdditional,
+    45 => FirmwareInventory,
+    46 => StringProperty,
+    126 => Inactive,
+    127 => EnfOfTable,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmbiosMeta {
+    pub source: String,
+    pub captured_at: String,
+    pub entry_point: Option<String>,
+    pub declared_version: String,
+    pub effective_version: String,
+    pub tool_version: String,
+    pub content_hash: String,
+}
+
+impl SmbiosMeta {
+    pub fn new(data: &RawSmbiosData, captured_at: SystemTime) -> Self {
+        let (major, minor) = data.effective_version();
+        SmbiosMeta {
+            source: data.source.to_string(),
+            captured_at: get_rfc3339_str(captured_at),
+            entry_point: data.entry_point.map(|e| e.to_string()),
+            declared_version: format!(
+                "{}.{}",
+                data.smbios_major_version, data.smbios_minior_version
+            ),
+            effective_version: format!("{}.{}", major, minor),
+            tool_version: env!("CARGO_PKG_VERSION").to_string(),
+            content_hash: format!("fnv1a64:{:016x}", data.content_hash()),
+        }
+    }
+}
+
+pub struct SmbiosTables {
+    data: RawSmbiosData,
+    tables: Vec<SmbiosTable>,
+    meta: SmbiosMeta,
+    #[cfg(feature = "arena")]
+    arena: Option<std::sync::Arc<arena::Arena>>,
+}
+
+impl SmbiosTables {
+    pub fn data(&self) -> &RawSmbiosData {
+        &self.data
+    }
+
+    pub fn meta(&self) -> &SmbiosMeta {
+        &self.meta
+    }
+
+    // Same as `SmbiosTables::from`, with the capture time read from `clock`.
+    pub fn from_with_clock(data: RawSmbiosData, clock: impl FnOnce() -> SystemTime) -> Self {
+        let meta = SmbiosMeta::new(&data, clock());
+        let tables = data.structures().map(SmbiosTable::from).collect();
+        SmbiosTables {
+            data,
+            tables,
+            meta,
+            #[cfg(feature = "arena")]
+            arena: None,
         }
     }
+
+    pub fn tables(&self) -> &[SmbiosTable] {
+        &self.tables
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, SmbiosTable> {
+        self.tables.iter()
+    }
+
+    pub fn get_by_handle(&self, handle: u16) -> Option<&SmbiosTable> {
+        self.tables.iter().find(|t| t.handle() == handle)
+    }
+
+    pub fn total_enabled_cores(&self) -> u32 {
+        self.populated_processors()
+            .filter_map(|p| p.core_enabled_mixed())
+            .map(u32::from)
+            .sum()
+    }
+
+    pub fn total_enabled_threads(&self) -> u32 {
+        self.populated_processors()
+            .filter_map(|p| p.thread_count_mixed())
+            .map(u32::from)
+            .sum()
+    }
+
+    pub fn total_memory_bytes(&self) -> u64 {
+        self.tables
+            .iter()
+            .filter_map(|t| match t {
+                SmbiosTable::MemoryDevice(d) => d.size_bytes(),
+                _ => None,
+            })
+            .sum()
+    }
+
+    // (populated, total) memory device slots.
+    pub fn memory_slots(&self) -> (usize, usize) {
+        self.tables
+            .iter()
+            .filter_map(|t| match t {
+                SmbiosTable::MemoryDevice(d) => Some(d.is_populated() == Some(true)),
+                _ => None,
+            })
+            .fold((0, 0), |(populated, total), p| {
+                (populated + usize::from(p), total + 1)
+            })
+    }
+
+    fn populated_processors(&self) -> impl Iterator<Item = &Processor> {
+        self.tables.iter().filter_map(|t| match t {
+            SmbiosTable::Processor(p) if p.is_populated() == Some(true) => Some(p),
+            _ => None,
+        })
+    }
+
+    pub fn is_virtual_machine(&self) -> bool {
+        self.hypervisor().is_some()
+    }
+
+    pub fn hypervisor(&self) -> Option<Hypervisor> {
+        for table in &self.tables {
+            let found = match table {
+                SmbiosTable::System(s) => {
+                    Hypervisor::from_strings(s.manufacturer(), s.product_name())
+                }
+                SmbiosTable::Chassis(c) => Hypervisor::from_strings(c.manufacturer(), None),
+                _ => None,
+            };
+            if found.is_some() {
+                return found;
+            }
+        }
+
+        // The chassis type is not a signal: SMBIOS has no virtual chassis type,
+        // and the "Other" that QEMU and VMware report is common on physical
+        // boards too.
+
+        // Firmware that sets the bit without naming itself, or a hypervisor not listed above.
+        let flagged = self.tables.iter().any(|t| match t {
+            SmbiosTable::Bios(b) => b.characteristics().is_some_and(|c| c.is_virtual_machine()),
+            _ => false,
+        });
+        flagged.then_some(Hypervisor::Other)
+    }
+
+    pub fn approx_heap_bytes(&self) -> usize {
+        let bytes = self.data.approx_heap_bytes()
+            + self.tables.capacity() * std::mem::size_of::<SmbiosTable>()
+            + self
+                .tables
+                .iter()
+                .map(|t| t.approx_heap_bytes())
+                .sum::<usize>();
+
+        #[cfg(feature = "arena")]
+        let bytes = bytes + self.arena.as_ref().map_or(0, |a| a.approx_heap_bytes());
+
+        bytes
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hypervisor {
+    VMware,
+    VirtualBox,
+    Qemu,
+    Kvm,
+    Xen,
+    HyperV,
+    AmazonEc2,
+    Other,
+}
+
+impl Hypervisor {
+    pub fn from_strings(manufacturer: Option<&str>, product: Option<&str>) -> Option<Self> {
+        let manufacturer = manufacturer.unwrap_or_default().trim();
+        let product = product.unwrap_or_default().trim();
+        let either = |s: &str| manufacturer.contains(s) || product.contains(s);
+
+        if either("VMware") {
+            Some(Hypervisor::VMware)
+        } else if manufacturer == "innotek GmbH" || either("VirtualBox") {
+            Some(Hypervisor::VirtualBox)
+        } else if product.contains("KVM") {
+            Some(Hypervisor::Kvm)
+        } else if either("QEMU") {
+            Some(Hypervisor::Qemu)
+        } else if manufacturer == "Xen" || product.contains("HVM domU") {
+            Some(Hypervisor::Xen)
+        } else if manufacturer == "Microsoft Corporation" && product == "Virtual Machine" {
+            Some(Hypervisor::HyperV)
+        } else if manufacturer == "Amazon EC2" && !product.ends_with(".metal") {
+            // Bare metal instances report the same manufacturer.
+            Some(Hypervisor::AmazonEc2)
+        } else {
+            None
+        }
+    }
+}
+
+impl std::fmt::Display for Hypervisor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Hypervisor::VMware => "VMware",
+            Hypervisor::VirtualBox => "VirtualBox",
+            Hypervisor::Qemu => "QEMU",
+            Hypervisor::Kvm => "KVM",
+            Hypervisor::Xen => "Xen",
+            Hypervisor::HyperV => "Hyper-V",
+            Hypervisor::AmazonEc2 => "Amazon EC2",
+            Hypervisor::Other => "Other",
+        })
+    }
+}
+
+impl From<RawSmbiosData> for SmbiosTables {
+    fn from(data: RawSmbiosData) -> Self {
+        SmbiosTables::from_with_clock(data, SystemTime::now)
+    }
+}
+
+#[derive(Default)]
+pub struct SmbiosCache {
+    tables: OnceLock<SmbiosTables>,
+}
+
+impl SmbiosCache {
+    pub const fn new() -> Self {
+        SmbiosCache {
+            tables: OnceLock::new(),
+        }
+    }
+
+    pub fn get(&self) -> Option<&SmbiosTables> {
+        self.tables.get()
+    }
+
+    // Concurrent first calls may each run `load`, but all of them get the
+    // snapshot that was stored first. A failed load leaves the cache empty
+    // so that the next call retries.
+    pub fn get_or_load<F>(&self, load: F) -> Result<&SmbiosTables, error::Error>
+    where
+        F: FnOnce() -> Result<SmbiosTables, error::Error>,
+    {
+        if let Some(tables) = self.tables.get() {
+            return Ok(tables);
+        }
+
+        let tables = load()?;
+        Ok(self.tables.get_or_init(|| tables))
+    }
+}
+
+#[cfg(all(
+    feature = "acquire",
+    any(target_family = "unix", target_family = "windows")
+))]
+pub fn cached() -> Result<&'static SmbiosTables, error::Error> {
+    static TABLES: SmbiosCache = SmbiosCache::new();
+    TABLES.get_or_load(refresh)
+}
+
+#[cfg(all(
+    feature = "acquire",
+    any(target_family = "unix", target_family = "windows")
+))]
+pub fn refresh() -> Result<SmbiosTables, error::Error> {
+    Ok(SmbiosTables::from(get_smbios()?))
 }
 
 #[derive(SMBIOS)]
+#[smbios(min_length(v2_0 = 0x12, v2_4 = 0x18, v3_1 = 0x1A))]
 pub struct Bios {
     table_ty: u8,
     length: u8,
     handle: u16,
-    vendor: Option<String>,
-    bios_version: Option<String>,
+    #[smbios(label = "Vendor")]
+    vendor: Option<SmbiosString>,
+    #[smbios(label = "Version")]
+    bios_version: Option<SmbiosString>,
+    #[smbios(label = "Address", fmt = "hex")]
+    #[smbios(label = "Runtime Size", with = "runtime_size_kb", unit = "kB")]
     bios_starting_address: Option<u16>,
-    bios_release_date: Option<String>,
+    #[smbios(label = "Release Date")]
+    bios_release_date: Option<SmbiosString>,
+    #[smbios(label = "ROM Size", with = "bios_rom_size_ex", unit = "kB")]
     bios_rom_size: Option<u8>,
+    #[smbios(label = "Characteristics", with = "bios_characteristics_str")]
+    #[smbios(bitflags(
+        "",
+        "",
+        "",
+        "BIOS characteristics not supported",
+        "ISA is supported",
+        "MCA is supported",
+        "EISA is supported",
+        "PCI is supported",
+        "PC Card (PCMCIA) is supported",
+        "PNP is supported",
+        "APM is supported",
+        "BIOS is upgradeable",
+        "BIOS shadowing is allowed",
+        "VLB is supported",
+        "ESCD support is available",
+        "Boot from CD is supported",
+        "Selectable boot is supported",
+        "BIOS ROM is socketed",
+        "Boot from PC Card (PCMCIA) is supported",
+        "EDD is supported",
+        "Japanese floppy for NEC 9800 1.2 MB is supported (int 13h)",
+        "Japanese floppy for Toshiba 1.2 MB is supported (int 13h)",
+        "5.25\"/360 kB floppy services are supported (int 13h)",
+        "5.25\"/1.2 MB floppy services are supported (int 13h)",
+        "3.5\"/720 kB floppy services are supported (int 13h)",
+        "3.5\"/2.88 MB floppy services are supported (int 13h)",
+        "Print screen service is supported (int 5h)",
+        "8042 keyboard services are supported (int 9h)",
+        "Serial services are supported (int 14h)",
+        "Printer services are supported (int 17h)",
+        "CGA/mono video services are supported (int 10h)",
+        "NEC PC-98",
+    ))]
     bios_characteristics: Option<u64>,
+    #[smbios(min_version = "2.4")]
+    #[smbios(
+        label = "Extended Characteristics",
+        with = "bios_characteristics_ex_str"
+    )]
     bios_characteristics_ex: Option<[u8; 2]>,
+    #[smbios(min_version = "2.4")]
+    #[smbios(label = "BIOS Revision", with = "system_bios_release")]
     system_bios_major_release: Option<u8>,
+    #[smbios(min_version = "2.4")]
     system_bios_minor_release: Option<u8>,
+    #[smbios(min_version = "2.4")]
+    #[smbios(label = "Firmware Revision", with = "embedded_ctrl_firmware_release")]
     embedded_ctrl_firmware_major_release: Option<u8>,
+    #[smbios(min_version = "2.4")]
     embedded_ctrl_firmware_minor_release: Option<u8>,
+    #[smbios(min_version = "3.1")]
     ex_bios_rom_size: Option<u16>,
 }
 
 impl Bios {
-    pub fn bios_rom_size_ex(&self) -> Option<u16> {
-        self.bios_rom_size().map(|size| {
-            if size == 0xFF {
-                self.ex_bios_rom_size.unwrap()
-            } else {
-                ((size as u16) + 1) * 64
-            }
-        })
+    pub const fn bios_starting_address_note() -> &'static str {
+        "real-mode segment of the BIOS image, meaningless on UEFI systems"
     }
 
-    pub fn bios_characteristics_str(&self) -> Option<Vec<String>> {
-        let chars = [
-            "",
-            "",
-            "",
-            "BIOS characteristics not supported",
-            "ISA is supported",
-            "MCA is supported",
-            "EISA is supported",
-            "PCI is supported",
-            "PC Card (PCMCIA) is supported",
-            "PNP is supported",
-            "APM is supported",
-            "BIOS is upgradeable",
-            "BIOS shadowing is allowed",
-            "VLB is supported",
-            "ESCD support is available",
-            "Boot from CD is supported",
-            "Selectable boot is supported",
-            "BIOS ROM is socketed",
-            "Boot from PC Card (PCMCIA) is supported",
-            "EDD is supported",
-            "Japanese floppy for NEC 9800 1.2 MB is supported (int 13h)",
-            "Japanese floppy for Toshiba 1.2 MB is supported (int 13h)",
-            "5.25\"/360 kB floppy services are supported (int 13h)",
-            "5.25\"/1.2 MB floppy services are supported (int 13h)",
-            "3.5\"/720 kB floppy services are supported (int 13h)",
-            "3.5\"/2.88 MB floppy services are supported (int 13h)",
-            "Print screen service is supported (int 5h)",
-            "8042 keyboard services are supported (int 9h)",
-            "Serial services are supported (int 14h)",
-            "Printer services are supported (int 17h)",
-            "CGA/mono video services are supported (int 10h)",
-            "NEC PC-98",
-        ];
-
-        self.bios_characteristics()
-            .map(|v| get_flag_strings(v, &chars))
+    pub fn bios_rom_size_ex(&self) -> Option<u16> {
+        self.bios_rom_size().and_then(|size| {
+            if size == 0xFF {
+                self.ex_bios_rom_size
+            } else {
+                Some(((size as u16) + 1) * 64)
+            }
+        })
     }
 
     pub fn bios_characteristics_ex_str(&self) -> Option<Vec<String>> {
@@ -299,24 +1440,164 @@ impl Bios {
         self.bios_starting_address()
             .map(|a| (0x10000 - (a as u32)) * 16 / 1024)
     }
+
+    pub fn bios_release_date_parsed(&self) -> Option<(u16, u8, u8)> {
+        self.bios_release_date().and_then(parse_date_str)
+    }
+
+    pub fn characteristics(&self) -> Option<BiosCharacteristics> {
+        self.bios_characteristics().map(|bits| BiosCharacteristics {
+            bits,
+            ex_bits: self.bios_characteristics_ex.map_or(0, u16::from_le_bytes),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BiosCharacteristics {
+    bits: u64,
+    ex_bits: u16,
+}
+
+impl BiosCharacteristics {
+    pub fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    // Extension byte 1 in the low byte, extension byte 2 in the high byte.
+    pub fn ex_bits(&self) -> u16 {
+        self.ex_bits
+    }
+
+    pub fn is_supported(&self) -> bool {
+        !self.bit(3)
+    }
+
+    pub fn supports_isa(&self) -> bool {
+        self.bit(4)
+    }
+
+    pub fn supports_pci(&self) -> bool {
+        self.bit(7)
+    }
+
+    pub fn supports_pnp(&self) -> bool {
+        self.bit(9)
+    }
+
+    pub fn supports_apm(&self) -> bool {
+        self.bit(10)
+    }
+
+    pub fn is_upgradeable(&self) -> bool {
+        self.bit(11)
+    }
+
+    pub fn allows_shadowing(&self) -> bool {
+        self.bit(12)
+    }
+
+    pub fn supports_boot_from_cd(&self) -> bool {
+        self.bit(15)
+    }
+
+    pub fn supports_selectable_boot(&self) -> bool {
+        self.bit(16)
+    }
+
+    pub fn is_rom_socketed(&self) -> bool {
+        self.bit(17)
+    }
+
+    pub fn supports_edd(&self) -> bool {
+        self.bit(19)
+    }
+
+    pub fn supports_acpi(&self) -> bool {
+        self.ex_bit(0)
+    }
+
+    pub fn supports_usb_legacy(&self) -> bool {
+        self.ex_bit(1)
+    }
+
+    pub fn supports_bios_boot_specification(&self) -> bool {
+        self.ex_bit(8)
+    }
+
+    pub fn supports_network_boot(&self) -> bool {
+        self.ex_bit(9)
+    }
+
+    pub fn supports_uefi(&self) -> bool {
+        self.ex_bit(11)
+    }
+
+    pub fn is_virtual_machine(&self) -> bool {
+        self.ex_bit(12)
+    }
+
+    fn bit(&self, n: u32) -> bool {
+        (self.bits & (1 << n)) != 0
+    }
+
+    fn ex_bit(&self, n: u32) -> bool {
+        (self.ex_bits & (1 << n)) != 0
+    }
 }
 
 #[derive(SMBIOS)]
+#[smbios(min_length(v2_0 = 0x08, v2_1 = 0x19, v2_4 = 0x1B))]
 pub struct System {
     table_ty: u8,
     length: u8,
     handle: u16,
-    manufacturer: Option<String>,
-    product_name: Option<String>,
-    version: Option<String>,
-    serial_number: Option<String>,
+    #[smbios(label = "Manufacturer")]
+    manufacturer: Option<SmbiosString>,
+    #[smbios(label = "Product Name")]
+    product_name: Option<SmbiosString>,
+    #[smbios(label = "Version")]
+    version: Option<SmbiosString>,
+    #[smbios(label = "Serial Number")]
+    serial_number: Option<SmbiosString>,
+    #[smbios(min_version = "2.1")]
     uuid: Option<[u8; 16]>,
+    #[smbios(min_version = "2.1")]
+    #[smbios(label = "Wake-up Type", with = "wakeup_ty_str")]
     wakeup_ty: Option<u8>,
-    sku_number: Option<String>,
-    family: Option<String>,
+    #[smbios(min_version = "2.4")]
+    #[smbios(label = "SKU Number")]
+    sku_number: Option<SmbiosString>,
+    #[smbios(min_version = "2.4")]
+    #[smbios(label = "Family")]
+    family: Option<SmbiosString>,
 }
 
 impl System {
+    pub fn uuid_bytes(&self) -> Option<[u8; 16]> {
+        self.uuid
+    }
+
+    pub fn uuid_string(&self, smbios: &RawSmbiosData) -> Option<String> {
+        self.uuid.map(|mut u| {
+            if u.iter().all(|&b| b == 0xFF) {
+                return "Not Present".to_string();
+            }
+            if u.iter().all(|&b| b == 0x00) {
+                return "Not Settable".to_string();
+            }
+
+            if smbios.is_later(2, 6) {
+                u[0..4].reverse();
+                u[4..6].reverse();
+                u[6..8].reverse();
+            }
+
+            get_uuid_str(&u)
+        })
+    }
+
+    #[cfg(feature = "uuid")]
     pub fn get_uuid(&self, smbios: &RawSmbiosData) -> Option<Uuid> {
         self.uuid.map(|u| {
             if smbios.is_later(2, 6) {
@@ -327,153 +1608,275 @@ impl System {
         })
     }
 
-    pub fn wakeup_ty_str(&self) -> Option<&'static str> {
-        self.wakeup_ty.map(|w| match w {
-            0 => "Reserved",
-            1 => "Other",
-            2 => "Unknown",
-            3 => "APM Timer",
-            4 => "Modem Ring",
-            5 => "LAN Remote",
-            6 => "Power Switch",
-            7 => "PCI PME#",
-            8 => "AC Power Restored",
-            _ => unreachable!(),
+    pub fn wakeup_ty_str(&self) -> Option<String> {
+        self.wakeup_ty.map(|w| {
+            let ty = match w {
+                0 => "Reserved",
+                1 => "Other",
+                2 => "Unknown",
+                3 => "APM Timer",
+                4 => "Modem Ring",
+                5 => "LAN Remote",
+                6 => "Power Switch",
+                7 => "PCI PME#",
+                8 => "AC Power Restored",
+                v => return format!("Unknown (0x{:02X})", v),
+            };
+            ty.to_string()
         })
     }
 }
 
 #[derive(SMBIOS)]
+#[smbios(min_length(v2_0 = 0x08))]
 pub struct BaseBoard {
     table_ty: u8,
     length: u8,
     handle: u16,
-    manufacturer: Option<String>,
-    product: Option<String>,
-    version: Option<String>,
-    serial_number: Option<String>,
-    asset_tag: Option<String>,
+    #[smbios(label = "Manufacturer")]
+    manufacturer: Option<SmbiosString>,
+    #[smbios(label = "Product Name")]
+    product: Option<SmbiosString>,
+    #[smbios(label = "Version")]
+    version: Option<SmbiosString>,
+    #[smbios(label = "Serial Number")]
+    serial_number: Option<SmbiosString>,
+    #[smbios(label = "Asset Tag")]
+    asset_tag: Option<SmbiosString>,
+    #[smbios(label = "Features", with = "feature_flags_str")]
+    #[smbios(bitflags(
+        "Board is a hosting board",
+        "Board requires at least one daughter board",
+        "Board is removable",
+        "Board is replaceable",
+        "Board is hot swappable",
+        "",
+        "",
+    ))]
     feature_flags: Option<u8>,
-    location: Option<String>,
+    #[smbios(label = "Location In Chassis")]
+    location: Option<SmbiosString>,
+    #[smbios(label = "Chassis Handle", fmt = "hex")]
     chassis_handle: Option<u16>,
+    #[smbios(label = "Type", with = "board_ty_str")]
     board_ty: Option<u8>,
     num_contained_object: Option<u8>,
     #[smbios(length = "num_contained_object")]
+    #[smbios(label = "Contained Object Handles", fmt = "hex")]
     contained_object_handle: Option<Vec<u16>>,
 }
 
 impl BaseBoard {
-    pub fn feature_flags_str(&self) -> Option<Vec<String>> {
-        let feats = [
-            "Board is a hosting board",
-            "Board requires at least one daughter board",
-            "Board is removable",
-            "Board is replaceable",
-            "Board is hot swappable",
-            "",
-            "",
-        ];
-
-        self.feature_flags()
-            .map(|v| get_flag_strings(v as u64, &feats))
-    }
-
-    pub fn board_ty_str(&self) -> Option<&'static str> {
+    pub fn board_ty_str(&self) -> Option<String> {
         self.board_ty().map(get_board_ty_str)
     }
 }
 
 #[derive(SMBIOS)]
+#[smbios(min_length(v2_0 = 0x09, v2_1 = 0x0D, v2_3 = 0x15))]
 pub struct Chassis {
     table_ty: u8,
     length: u8,
     handle: u16,
-    manufacturer: Option<String>,
+    #[smbios(label = "Manufacturer")]
+    manufacturer: Option<SmbiosString>,
+    #[smbios(packed("chassis_ty", "ty_lock"))]
+    #[smbios(label = "Type", with = "ty_str")]
+    #[smbios(label = "Lock", with = "ty_lock_str")]
     ty: Option<u8>,
-    version: Option<String>,
-    serial_number: Option<String>,
-    asset_tag_number: Option<String>,
+    #[smbios(label = "Version")]
+    version: Option<SmbiosString>,
+    #[smbios(label = "Serial Number")]
+    serial_number: Option<SmbiosString>,
+    #[smbios(label = "Asset Tag")]
+    asset_tag_number: Option<SmbiosString>,
+    #[smbios(min_version = "2.1")]
+    #[smbios(label = "Boot-up State", with = "boot_up_state_str")]
     boot_up_state: Option<u8>,
+    #[smbios(min_version = "2.1")]
+    #[smbios(label = "Power Supply State", with = "power_supply_state_str")]
     power_supply_state: Option<u8>,
+    #[smbios(min_version = "2.1")]
+    #[smbios(label = "Thermal State", with = "thermal_state_str")]
     thermal_state: Option<u8>,
+    #[smbios(min_version = "2.1")]
+    #[smbios(label = "Security Status", with = "security_status_str")]
     security_status: Option<u8>,
+    #[smbios(min_version = "2.3")]
+    #[smbios(label = "OEM Information", fmt = "hex")]
     oem_defined: Option<u32>,
+    #[smbios(min_version = "2.3")]
+    #[smbios(label = "Height", with = "height_str")]
     height: Option<u8>,
+    #[smbios(min_version = "2.3")]
+    #[smbios(label = "Number of Power Cords", with = "num_power_cords_str")]
     num_power_cords: Option<u8>,
+    #[smbios(min_version = "2.3")]
     contained_element_count: Option<u8>,
+    #[smbios(min_version = "2.3")]
     contained_element_record_length: Option<u8>,
+    #[smbios(min_version = "2.3")]
     #[smbios(
-        length = "contained_element_count.map(|c| contained_element_record_length.map(|l| c * l)).flatten()"
+        length_bytes = "contained_element_count.and_then(|c| contained_element_record_length.map(|l| c as usize * l as usize))"
     )]
+    #[smbios(label = "Contained Elements", with = "contained_elements_str")]
     contained_elements: Option<Vec<u8>>,
-    sku_number: Option<String>,
+    #[smbios(min_version = "2.7")]
+    #[smbios(label = "SKU Number")]
+    sku_number: Option<SmbiosString>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, SmbiosEnum)]
+pub enum ChassisType {
+    #[smbios(value = 0x01)]
+    Other,
+    #[smbios(value = 0x02, name = "Unknown")]
+    UnknownType,
+    #[smbios(value = 0x03)]
+    Desktop,
+    #[smbios(value = 0x04, name = "Low Profile Desktop")]
+    LowProfileDesktop,
+    #[smbios(value = 0x05, name = "Pizza Box")]
+    PizzaBox,
+    #[smbios(value = 0x06, name = "Mini Tower")]
+    MiniTower,
+    #[smbios(value = 0x07)]
+    Tower,
+    #[smbios(value = 0x08)]
+    Portable,
+    #[smbios(value = 0x09)]
+    Laptop,
+    #[smbios(value = 0x0A)]
+    Notebook,
+    #[smbios(value = 0x0B, name = "Hand Held")]
+    HandHeld,
+    #[smbios(value = 0x0C, name = "Docking Station")]
+    DockingStation,
+    #[smbios(value = 0x0D, name = "All In One")]
+    AllInOne,
+    #[smbios(value = 0x0E, name = "Sub Notebook")]
+    SubNotebook,
+    #[smbios(value = 0x0F, name = "Space-saving")]
+    SpaceSaving,
+    #[smbios(value = 0x10, name = "Lunch Box")]
+    LunchBox,
+    #[smbios(value = 0x11, name = "Main Server Chassis")]
+    MainServerChassis,
+    #[smbios(value = 0x12, name = "Expansion Chassis")]
+    ExpansionChassis,
+    #[smbios(value = 0x13)]
+    SubChassis,
+    #[smbios(value = 0x14, name = "Bus Expansion Chassis")]
+    BusExpansionChassis,
+    #[smbios(value = 0x15, name = "Peripheral Chassis")]
+    PeripheralChassis,
+    #[smbios(value = 0x16, name = "RAID Chassis")]
+    RaidChassis,
+    #[smbios(value = 0x17, name = "Rack Mount Chassis")]
+    RackMountChassis,
+    #[smbios(value = 0x18, name = "Sealed-case PC")]
+    SealedCasePc,
+    #[smbios(value = 0x19, name = "Multi-system chassis")]
+    MultiSystemChassis,
+    #[smbios(value = 0x1A, name = "Compact PCI")]
+    CompactPci,
+    #[smbios(value = 0x1B, name = "Advanced TCA")]
+    AdvancedTca,
+    #[smbios(value = 0x1C)]
+    Blade,
+    #[smbios(value = 0x1D, name = "Blade Enclosure")]
+    BladeEnclosure,
+    #[smbios(value = 0x1E)]
+    Tablet,
+    #[smbios(value = 0x1F)]
+    Convertible,
+    #[smbios(value = 0x20)]
+    Detachable,
+    #[smbios(value = 0x21, name = "IoT Gateway")]
+    IotGateway,
+    #[smbios(value = 0x22, name = "Embedded PC")]
+    EmbeddedPc,
+    #[smbios(value = 0x23, name = "Mini PC")]
+    MiniPc,
+    #[smbios(value = 0x24, name = "Stick PC")]
+    StickPc,
+    Unknown(u64),
 }
 
 impl Chassis {
-    pub fn ty_str(&self) -> Option<&'static str> {
-        self.ty().map(|t| match t & 0x3F {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "Desktop",
-            0x04 => "Low Profile Desktop",
-            0x05 => "Pizza Box",
-            0x06 => "Mini Tower",
-            0x07 => "Tower",
-            0x08 => "Portable",
-            0x09 => "Laptop",
-            0x0A => "Notebook",
-            0x0B => "Hand Held",
-            0x0C => "Docking Station",
-            0x0D => "All In One",
-            0x0E => "Sub Notebook",
-            0x0F => "Space-saving",
-            0x10 => "Lunch Box",
-            0x11 => "Main Server Chassis",
-            0x12 => "Expansion Chassis",
-            0x13 => "SubChassis",
-            0x14 => "Bus Expansion Chassis",
-            0x15 => "Peripheral Chassis",
-            0x16 => "RAID Chassis",
-            0x17 => "Rack Mount Chassis",
-            0x18 => "Sealed-case PC",
-            0x19 => "Multi-system chassis",
-            0x1A => "Compact PCI",
-            0x1B => "Advanced TCA",
-            0x1C => "Blade",
-            0x1D => "Blade Enclosure",
-            0x1E => "Tablet",
-            0x1F => "Convertible",
-            0x20 => "Detachable",
-            0x21 => "IoT Gateway",
-            0x22 => "Embedded PC",
-            0x23 => "Mini PC",
-            0x24 => "Stick PC",
-            _ => unreachable!(),
-        })
+    pub fn chassis_ty(&self) -> Option<ChassisType> {
+        self.ty().map(|t| ChassisType::from_raw((t & 0x7F) as u64))
+    }
+
+    pub fn ty_str(&self) -> Option<String> {
+        self.chassis_ty().map(|t| t.to_string())
     }
 
     pub fn ty_lock(&self) -> Option<bool> {
         self.ty().map(|t| (t & 0x80) != 0)
     }
 
-    pub fn boot_up_state_str(&self) -> Option<&'static str> {
+    pub fn ty_lock_str(&self) -> Option<&'static str> {
+        self.ty_lock()
+            .map(|l| if l { "Present" } else { "Not Present" })
+    }
+
+    pub fn contained_elements_str(&self) -> Option<Vec<String>> {
+        let len = self.contained_element_record_length()? as usize;
+        let elements = self.contained_elements()?;
+        if len < 3 {
+            return None;
+        }
+
+        Some(
+            elements
+                .chunks_exact(len)
+                .map(|e| {
+                    let ty_str = if (e[0] & 0x80) > 0 {
+                        get_table_name_by_id(e[0] & 0x7F)
+                            .unwrap_or("Unknown")
+                            .to_string()
+                    } else {
+                        get_board_ty_str(e[0] & 0x7F)
+                    };
+                    format!("{} ({}-{})", ty_str, e[1], e[2])
+                })
+                .collect(),
+        )
+    }
+
+    pub fn height_str(&self) -> Option<String> {
+        self.height().map(|h| match h {
+            0 => "Unspecified".to_string(),
+            h => format!("{} U", h),
+        })
+    }
+
+    pub fn num_power_cords_str(&self) -> Option<String> {
+        self.num_power_cords().map(|n| match n {
+            0 => "Unspecified".to_string(),
+            n => format!("{}", n),
+        })
+    }
+
+    pub fn boot_up_state_str(&self) -> Option<String> {
         self.boot_up_state.map(|s| self.get_chassis_state(s))
     }
 
-    pub fn power_supply_state_str(&self) -> Option<&'static str> {
+    pub fn power_supply_state_str(&self) -> Option<String> {
         self.power_supply_state.map(|s| self.get_chassis_state(s))
     }
 
-    pub fn thermal_state_str(&self) -> Option<&'static str> {
+    pub fn thermal_state_str(&self) -> Option<String> {
         self.thermal_state.map(|s| self.get_chassis_state(s))
     }
 
-    pub fn security_status_str(&self) -> Option<&'static str> {
+    pub fn security_status_str(&self) -> Option<String> {
         self.security_status
             .map(|s| self.get_chassis_security_status(s))
     }
 
-    fn get_chassis_state(&self, state: u8) -> &'static str {
+    fn get_chassis_state(&self, state: u8) -> String {
         match state {
             0x01 => "Other",
             0x02 => "Unknown",
@@ -481,339 +1884,561 @@ impl Chassis {
             0x04 => "Warning",
             0x05 => "Critical",
             0x06 => "Non-recoverable",
-            _ => unreachable!(),
+            v => return format!("Unknown (0x{:02X})", v),
         }
+        .to_string()
     }
 
-    fn get_chassis_security_status(&self, state: u8) -> &'static str {
+    fn get_chassis_security_status(&self, state: u8) -> String {
         match state {
             0x01 => "Other",
             0x02 => "Unknown",
             0x03 => "None",
             0x04 => "External interface locked out",
             0x05 => "External interface enabled",
-            _ => unreachable!(),
+            v => return format!("Unknown (0x{:02X})", v),
         }
+        .to_string()
     }
 }
 
 #[derive(SMBIOS)]
+#[smbios(min_length(v2_0 = 0x1A, v2_3 = 0x23, v2_5 = 0x28, v2_6 = 0x2A, v3_0 = 0x30))]
 pub struct Processor {
     table_ty: u8,
     length: u8,
     handle: u16,
-    socket_designation: Option<String>,
+    socket_designation: Option<SmbiosString>,
     processor_ty: Option<u8>,
     processor_family: Option<u8>,
-    processor_manufacturer: Option<String>,
+    processor_manufacturer: Option<SmbiosString>,
     processor_id: Option<u64>,
-    processor_version: Option<String>,
+    processor_version: Option<SmbiosString>,
+    #[smbios(packed("voltage_str"))]
     voltage: Option<u8>,
     external_clock: Option<u16>,
     max_speed: Option<u16>,
     current_speed: Option<u16>,
+    #[smbios(packed("is_populated", "status_str"))]
     status: Option<u8>,
-    processor_upgrade: Option<u8>,
+    #[smbios(enum)]
+    processor_upgrade: Option<ProcessorUpgrade>,
+    #[smbios(min_version = "2.1")]
+    #[smbios(handle_to = "Cache")]
     l1_cache_handle: Option<u16>,
+    #[smbios(min_version = "2.1")]
+    #[smbios(handle_to = "Cache")]
     l2_cache_handle: Option<u16>,
+    #[smbios(min_version = "2.1")]
+    #[smbios(handle_to = "Cache")]
     l3_cache_handle: Option<u16>,
-    serial_number: Option<String>,
-    asset_tag: Option<String>,
-    part_number: Option<String>,
+    #[smbios(min_version = "2.3")]
+    serial_number: Option<SmbiosString>,
+    #[smbios(min_version = "2.3")]
+    asset_tag: Option<SmbiosString>,
+    #[smbios(min_version = "2.3")]
+    part_number: Option<SmbiosString>,
+    #[smbios(min_version = "2.5")]
     core_count: Option<u8>,
+    #[smbios(min_version = "2.5")]
     core_enabled: Option<u8>,
+    #[smbios(min_version = "2.5")]
     thread_count: Option<u8>,
+    #[smbios(min_version = "2.5")]
     processor_characteristics: Option<u16>,
+    #[smbios(min_version = "2.6")]
     processor_family2: Option<u16>,
+    #[smbios(min_version = "3.0")]
     core_count2: Option<u16>,
+    #[smbios(min_version = "3.0")]
     core_enabled2: Option<u16>,
+    #[smbios(min_version = "3.0")]
     thread_count2: Option<u16>,
+    #[smbios(min_version = "3.6")]
     thread_enabled: Option<u16>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, SmbiosEnum)]
+pub enum ProcessorUpgrade {
+    #[smbios(value = 0x01)]
+    Other,
+    #[smbios(value = 0x02, name = "Unknown")]
+    UnknownType,
+    #[smbios(value = 0x03, name = "Daughter Board")]
+    DaughterBoard,
+    #[smbios(value = 0x04, name = "ZIF Socket")]
+    ZifSocket,
+    #[smbios(value = 0x05, name = "Replaceable Piggy Back")]
+    ReplaceablePiggyBack,
+    #[smbios(value = 0x06, name = "None")]
+    NoUpgrade,
+    #[smbios(value = 0x07, name = "LIF Socket")]
+    LifSocket,
+    #[smbios(value = 0x08, name = "Slot 1")]
+    Slot1,
+    #[smbios(value = 0x09, name = "Slot 2")]
+    Slot2,
+    #[smbios(value = 0x0A, name = "370-pin socket")]
+    Socket370Pin,
+    #[smbios(value = 0x0B, name = "Slot A")]
+    SlotA,
+    #[smbios(value = 0x0C, name = "Slot M")]
+    SlotM,
+    #[smbios(value = 0x0D, name = "Socket 423")]
+    Socket423,
+    #[smbios(value = 0x0E, name = "Socket A (Socket 462)")]
+    SocketA,
+    #[smbios(value = 0x0F, name = "Socket 478")]
+    Socket478,
+    #[smbios(value = 0x10, name = "Socket 754")]
+    Socket754,
+    #[smbios(value = 0x11, name = "Socket 940")]
+    Socket940,
+    #[smbios(value = 0x12, name = "Socket 939")]
+    Socket939,
+    #[smbios(value = 0x13, name = "Socket mPGA604")]
+    SocketMpga604,
+    #[smbios(value = 0x14, name = "Socket LGA771")]
+    SocketLga771,
+    #[smbios(value = 0x15, name = "Socket LGA775")]
+    SocketLga775,
+    #[smbios(value = 0x16, name = "Socket S1")]
+    SocketS1,
+    #[smbios(value = 0x17, name = "Socket AM2")]
+    SocketAm2,
+    #[smbios(value = 0x18, name = "Socket F (1207)")]
+    SocketF,
+    #[smbios(value = 0x19, name = "Socket LGA1366")]
+    SocketLga1366,
+    #[smbios(value = 0x1A, name = "Socket G34")]
+    SocketG34,
+    #[smbios(value = 0x1B, name = "Socket AM3")]
+    SocketAm3,
+    #[smbios(value = 0x1C, name = "Socket C32")]
+    SocketC32,
+    #[smbios(value = 0x1D, name = "Socket LGA1156")]
+    SocketLga1156,
+    #[smbios(value = 0x1E, name = "Socket LGA1567")]
+    SocketLga1567,
+    #[smbios(value = 0x1F, name = "Socket PGA988A")]
+    SocketPga988A,
+    #[smbios(value = 0x20, name = "Socket BGA1288")]
+    SocketBga1288,
+    #[smbios(value = 0x21, name = "Socket rPGA988B")]
+    SocketRpga988B,
+    #[smbios(value = 0x22, name = "Socket BGA1023")]
+    SocketBga1023,
+    #[smbios(value = 0x23, name = "Socket BGA1224")]
+    SocketBga1224,
+    #[smbios(value = 0x24, name = "Socket LGA1155")]
+    SocketLga1155,
+    #[smbios(value = 0x25, name = "Socket LGA1356")]
+    SocketLga1356,
+    #[smbios(value = 0x26, name = "Socket LGA2011")]
+    SocketLga2011,
+    #[smbios(value = 0x27, name = "Socket FS1")]
+    SocketFs1,
+    #[smbios(value = 0x28, name = "Socket FS2")]
+    SocketFs2,
+    #[smbios(value = 0x29, name = "Socket FM1")]
+    SocketFm1,
+    #[smbios(value = 0x2A, name = "Socket FM2")]
+    SocketFm2,
+    #[smbios(value = 0x2B, name = "Socket LGA2011-3")]
+    SocketLga2011_3,
+    #[smbios(value = 0x2C, name = "Socket LGA1356-3")]
+    SocketLga1356_3,
+    #[smbios(value = 0x2D, name = "Socket LGA1150")]
+    SocketLga1150,
+    #[smbios(value = 0x2E, name = "Socket BGA1168")]
+    SocketBga1168,
+    #[smbios(value = 0x2F, name = "Socket BGA1234")]
+    SocketBga1234,
+    #[smbios(value = 0x30, name = "Socket BGA1364")]
+    SocketBga1364,
+    #[smbios(value = 0x31, name = "Socket AM4")]
+    SocketAm4,
+    #[smbios(value = 0x32, name = "Socket LGA1151")]
+    SocketLga1151,
+    #[smbios(value = 0x33, name = "Socket BGA1356")]
+    SocketBga1356,
+    #[smbios(value = 0x34, name = "Socket BGA1440")]
+    SocketBga1440,
+    #[smbios(value = 0x35, name = "Socket BGA1515")]
+    SocketBga1515,
+    #[smbios(value = 0x36, name = "Socket LGA3647-1")]
+    SocketLga3647_1,
+    #[smbios(value = 0x37, name = "Socket SP3")]
+    SocketSp3,
+    #[smbios(value = 0x38, name = "Socket SP3r2")]
+    SocketSp3r2,
+    #[smbios(value = 0x39, name = "Socket LGA2066")]
+    SocketLga2066,
+    #[smbios(value = 0x3A, name = "Socket BGA1392")]
+    SocketBga1392,
+    #[smbios(value = 0x3B, name = "Socket BGA1510")]
+    SocketBga1510,
+    #[smbios(value = 0x3C, name = "Socket BGA1528")]
+    SocketBga1528,
+    #[smbios(value = 0x3D, name = "Socket LGA4189")]
+    SocketLga4189,
+    #[smbios(value = 0x3E, name = "Socket LGA1200")]
+    SocketLga1200,
+    #[smbios(value = 0x3F, name = "Socket LGA4677")]
+    SocketLga4677,
+    #[smbios(value = 0x40, name = "Socket LGA1700")]
+    SocketLga1700,
+    #[smbios(value = 0x41, name = "Socket BGA1744")]
+    SocketBga1744,
+    #[smbios(value = 0x42, name = "Socket BGA1781")]
+    SocketBga1781,
+    #[smbios(value = 0x43, name = "Socket BGA1211")]
+    SocketBga1211,
+    #[smbios(value = 0x44, name = "Socket BGA2422")]
+    SocketBga2422,
+    #[smbios(value = 0x45, name = "Socket LGA1211")]
+    SocketLga1211,
+    #[smbios(value = 0x46, name = "Socket LGA2422")]
+    SocketLga2422,
+    #[smbios(value = 0x47, name = "Socket LGA5773")]
+    SocketLga5773,
+    #[smbios(value = 0x48, name = "Socket BGA5773")]
+    SocketBga5773,
+    #[smbios(value = 0x49, name = "Socket AM5")]
+    SocketAm5,
+    #[smbios(value = 0x4A, name = "Socket SP5")]
+    SocketSp5,
+    #[smbios(value = 0x4B, name = "Socket SP6")]
+    SocketSp6,
+    #[smbios(value = 0x4C, name = "Socket BGA883")]
+    SocketBga883,
+    #[smbios(value = 0x4D, name = "Socket BGA1190")]
+    SocketBga1190,
+    #[smbios(value = 0x4E, name = "Socket BGA4129")]
+    SocketBga4129,
+    #[smbios(value = 0x4F, name = "Socket LGA4710")]
+    SocketLga4710,
+    #[smbios(value = 0x50, name = "Socket LGA7529")]
+    SocketLga7529,
+    #[smbios(value = 0x51, name = "Socket BGA1964")]
+    SocketBga1964,
+    #[smbios(value = 0x52, name = "Socket BGA1792")]
+    SocketBga1792,
+    #[smbios(value = 0x53, name = "Socket BGA2049")]
+    SocketBga2049,
+    #[smbios(value = 0x54, name = "Socket BGA2551")]
+    SocketBga2551,
+    #[smbios(value = 0x55, name = "Socket LGA1851")]
+    SocketLga1851,
+    #[smbios(value = 0x56, name = "Socket BGA2114")]
+    SocketBga2114,
+    #[smbios(value = 0x57, name = "Socket BGA2833")]
+    SocketBga2833,
+    Unknown(u64),
+}
+
 impl Processor {
-    pub fn processor_ty_str(&self) -> Option<&'static str> {
-        self.processor_ty().map(|t| match t {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "Central Processor",
-            0x04 => "Central Processor",
-            0x05 => "DSP Processor",
-            0x06 => "Video Processor",
-            _ => unreachable!(),
+    pub const fn current_speed_note() -> &'static str {
+        "speed at boot time, not the live operating frequency"
+    }
+
+    pub fn processor_ty_str(&self) -> Option<String> {
+        self.processor_ty().map(|t| {
+            match t {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "Central Processor",
+                0x04 => "Math Processor",
+                0x05 => "DSP Processor",
+                0x06 => "Video Processor",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 
-    pub fn processor_family_str(&self) -> Option<&'static str> {
-        self.processor_family().map(|f| match f {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "8086",
-            0x04 => "80286",
-            0x05 => "Intel386 processor",
-            0x06 => "Intel486 processor",
-            0x07 => "8087",
-            0x08 => "80287",
-            0x09 => "80387",
-            0x0A => "80487",
-            0x0B => "Intel Pentium processor",
-            0x0C => "Pentium Pro processor",
-            0x0D => "Pentium II processor",
-            0x0E => "Pentium processor with MMX technology",
-            0x0F => "Intel Celeron processor",
-
-            0x10 => "Pentium II Xeon processor",
-            0x11 => "Pentium III processor",
-            0x12 => "M1 Family",
-            0x13 => "M2 Family",
-            0x14 => "Intel Celeron M processor",
-            0x15 => "Intel Pentium 4 HT processor",
-            //0x16 => "",
-            //0x17 => "",
-            0x18 => "AMD Duron Processor Family",
-            0x19 => "K5 Family",
-            0x1A => "K6 Family",
-            0x1B => "K6-2",
-            0x1C => "K6-3",
-            0x1D => "AMD Athlon Processor Family",
-            0x1E => "AMD29000 Family",
-            0x1F => "K6-2+",
-
-            0x20 => "Power PC Family",
-            0x21 => "Power PC 601",
-            0x22 => "Power PC 603",
-            0x23 => "Power PC 603+",
-            0x24 => "Power PC 604",
-            0x25 => "Power PC 620",
-            0x26 => "Power PC x704",
-            0x27 => "Power PC 750",
-            0x28 => "Intel Core Duo processor",
-            0x29 => "Intel Core Duo mobile processor",
-            0x2A => "Intel Core Solo mobile processor",
-            0x2B => "Intel Atom processor",
-            0x2C => "Intel Core M processor",
-            0x2D => "Intel Core m3 processor",
-            0x2E => "Intel Core m5 processor",
-            0x2F => "Intel Core m7 processor",
-
-            0x30 => "Alpha Family",
-            0x31 => "Alpha 21064",
-            0x32 => "Alpha 21066",
-            0x33 => "Alpha 21164",
-            0x34 => "Alpha 21164PC",
-            0x35 => "Alpha 21164a",
-            0x36 => "Alpha 21264",
-            0x37 => "Alpha 21364",
-            0x38 => "AMD Turion II Ultra Dual-Core Mobile M Processor Family",
-            0x39 => "AMD Turion II Dual-Core Mobile M Processor Family",
-            0x3A => "AMD Athlon II Dual-Core M Processor Family",
-            0x3B => "AMD Opteron 6100 Series Processor",
-            0x3C => "AMD Opteron 4100 Series Processor",
-            0x3D => "AMD Opteron 6200 Series Processor",
-            0x3E => "AMD Opteron 4200 Series Processor",
-            0x3F => "AMD FX Series Processor",
-
-            0x40 => "MIPS Family",
-            0x41 => "MIPS R4000",
-            0x42 => "MIPS R4200",
-            0x43 => "MIPS R4400",
-            0x44 => "MIPS R4600",
-            0x45 => "MIPS R10000",
-            0x46 => "AMD C-Series Processor",
-            0x47 => "AMD E-Series Processor",
-            0x48 => "AMD A-Series Processor",
-            0x49 => "AMD G-Series Processor",
-            0x4A => "AMD Z-Series Processor",
-            0x4B => "AMD R-Series Processor",
-            0x4C => "AMD Opteron 4300 Series Processor",
-            0x4D => "AMD Opteron 6300 Series Processor",
-            0x4E => "AMD Opteron 3300 Series Processor",
-            0x4F => "AMD FirePro Series Processor",
-
-            0x50 => "SPARC Family",
-            0x51 => "SuperSPARC",
-            0x52 => "microSPARC II",
-            0x53 => "microSPARC IIep",
-            0x54 => "UltraSPARC",
-            0x55 => "UltraSPARC II",
-            0x56 => "UltraSPARC Iii",
-            0x57 => "UltraSPARC III",
-            0x58 => "UltraSPARC IIIi",
-            //0x59 => "",
-            //0x5A => "",
-            //0x5B => "",
-            //0x5C => "",
-            //0x5D => "",
-            //0x5E => "",
-            //0x5F => "",
-            0x60 => "68040 Family",
-            0x61 => "68xxx",
-            0x62 => "68000",
-            0x63 => "68010",
-            0x64 => "68020",
-            0x65 => "68030",
-            0x66 => "AMD Athlon X4 Quad-Core Processor Family",
-            0x67 => "AMD Opteron X1000 Series Processor",
-            0x68 => "AMD Opteron X2000 Series APU",
-            0x69 => "AMD Opteron A-Series Processor",
-            0x6A => "AMD Opteron X3000 Series APU",
-            0x6B => "AMD Zen Processor Family",
-            //0x6C => "",
-            //0x6D => "",
-            //0x6E => "",
-            //0x6F => "",
-            0x70 => "Hobbit Family",
-            //0x71 => "",
-            //0x72 => "",
-            //0x73 => "",
-            //0x74 => "",
-            //0x75 => "",
-            //0x76 => "",
-            //0x77 => "",
-            0x78 => "Crusoe TM5000 Family",
-            0x79 => "Crusoe TM3000 Family",
-            0x7A => "Efficeon TM8000 Family",
-            //0x7B => "",
-            //0x7C => "",
-            //0x7D => "",
-            //0x7E => "",
-            //0x7F => "",
-            0x80 => "Weitek",
-            //0x81 => "",
-            0x82 => "Itanium processor",
-            0x83 => "AMD Athlon 64 Processor Family",
-            0x84 => "AMD Opteron Processor Family",
-            0x85 => "AMD Sempron Processor Family",
-            0x86 => "AMD Turion 64 Mobile Technology",
-            0x87 => "Dual-Core AMD Opteron Processor Family",
-            0x88 => "AMD Athlon 64 X2 Dual-Core Processor Family",
-            0x89 => "AMD Turion 64 X2 Mobile Technology",
-            0x8A => "Quad-Core AMD Opteron Processor Family",
-            0x8B => "Third-Generation AMD Opteron Processor Family",
-            0x8C => "AMD Phenom FX Quad-Core Processor Family",
-            0x8D => "AMD Phenom X4 Quad-Core Processor Family",
-            0x8E => "AMD Phenom X2 Dual-Core Processor Family",
-            0x8F => "AMD Athlon X2 Dual-Core Processor Family",
-
-            0x90 => "PA-RISC Family",
-            0x91 => "PA-RISC 8500",
-            0x92 => "PA-RISC 8000",
-            0x93 => "PA-RISC 7300LC",
-            0x94 => "PA-RISC 7200",
-            0x95 => "PA-RISC 7100LC",
-            0x96 => "PA-RISC 7100",
-            //0x97 => "",
-            //0x98 => "",
-            //0x99 => "",
-            //0x9A => "",
-            //0x9B => "",
-            //0x9C => "",
-            //0x9D => "",
-            //0x9E => "",
-            //0x9F => "",
-            0xA0 => "V30 Family",
-            0xA1 => "Quad-Core Intel Xeon processor 3200 Series",
-            0xA2 => "Dual-Core Intel Xeon processor 3000 Series",
-            0xA3 => "Quad-Core Intel Xeon processor 5300 Series",
-            0xA4 => "Dual-Core Intel Xeon processor 5100 Series",
-            0xA5 => "Dual-Core Intel Xeon processor 5000 Series",
-            0xA6 => "Dual-Core Intel Xeon processor LV",
-            0xA7 => "Dual-Core Intel Xeon processor ULV",
-            0xA8 => "Dual-Core Intel Xeon processor 7100 Series",
-            0xA9 => "Quad-Core Intel Xeon processor 5400 Series",
-            0xAA => "Quad-Core Intel Xeon processor",
-            0xAB => "Dual-Core Intel Xeon processor 5200 Series",
-            0xAC => "Dual-Core Intel Xeon processor 7200 Series",
-            0xAD => "Quad-Core Intel Xeon processor 7300 Series",
-            0xAE => "Quad-Core Intel Xeon processor 7400 Series",
-            0xAF => "Multi-Core Intel Xeon processor 7400 Series",
-
-            0xB0 => "Pentium III Xeon processor",
-            0xB1 => "Pentium III Processor with Intel  SpeedStep Technology",
-            0xB2 => "Pentium 4 Processor",
-            0xB3 => "Intel Xeon processor",
-            0xB4 => "AS400 Family",
-            0xB5 => "Intel Xeon processor MP",
-            0xB6 => "AMD Athlon XP Processor Family",
-            0xB7 => "AMD Athlon MP Processor Family",
-            0xB8 => "Intel Itanium 2 processor",
-            0xB9 => "Intel Pentium M processor",
-            0xBA => "Intel Celeron D processor",
-            0xBB => "Intel Pentium D processor",
-            0xBC => "Intel Pentium Processor Extreme Edition",
-            0xBD => "Intel Core Solo Processor",
-            //0xBE => "",
-            0xBF => "Intel Core 2 Duo Processor",
-
-            0xC0 => "Intel Core 2 Solo processor",
-            0xC1 => "Intel Core 2 Extreme processor",
-            0xC2 => "Intel Core 2 Quad processor",
-            0xC3 => "Intel Core 2 Extreme mobile processor",
-            0xC4 => "Intel Core 2 Duo mobile processor",
-            0xC5 => "Intel Core 2 Solo mobile processor",
-            0xC6 => "Intel Core i7 processor",
-            0xC7 => "Dual-Core Intel Celeron processor",
-            0xC8 => "IBM390 Family",
-            0xC9 => "G4",
-            0xCA => "G5",
-            0xCB => "ESA/390 G6",
-            0xCC => "z/Architecture base",
-            0xCD => "Intel Core i5 processor",
-            0xCE => "Intel Core i3 processor",
-            0xCF => "Intel Core i9 processor",
-
-            //0xD0 => "",
-            //0xD1 => "",
-            0xD2 => "VIA C7-M Processor Family",
-            0xD3 => "VIA C7-D Processor Family",
-            0xD4 => "VIA C7 Processor Family",
-            0xD5 => "VIA Eden Processor Family",
-            0xD6 => "Multi-Core Intel Xeon processor",
-            0xD7 => "Dual-Core Intel Xeon processor 3xxx Series",
-            0xD8 => "Quad-Core Intel Xeon processor 3xxx Series",
-            0xD9 => "VIA Nano Processor Family",
-            0xDA => "Dual-Core Intel Xeon processor 5xxx Series",
-            0xDB => "Quad-Core Intel Xeon processor 5xxx Series",
-            //0xDC => "",
-            0xDD => "Dual-Core Intel Xeon processor 7xxx Series",
-            0xDE => "Quad-Core Intel Xeon processor 7xxx Series",
-            0xDF => "Multi-Core Intel Xeon processor 7xxx Series",
-
-            0xE0 => "Multi-Core Intel Xeon processor 3400 Series",
-            //0xE1 => "",
-            //0xE2 => "",
-            //0xE3 => "",
-            0xE4 => "AMD Opteron 3000 Series Processor",
-            0xE5 => "AMD Sempron II Processor",
-            0xE6 => "Embedded AMD Opteron Quad-Core Processor Family",
-            0xE7 => "AMD Phenom Triple-Core Processor Family",
-            0xE8 => "AMD Turion Ultra Dual-Core Mobile Processor Family",
-            0xE9 => "AMD Turion Dual-Core Mobile Processor Family",
-            0xEA => "AMD Athlon Dual-Core Processor Family",
-            0xEB => "AMD Sempron SI Processor Family",
-            0xEC => "AMD Phenom II Processor Family",
-            0xED => "AMD Athlon II Processor Family",
-            0xEE => "Six-Core AMD Opteron Processor Family",
-            0xEF => "AMD Sempron M Processor Family",
-
-            //0xF0 => "",
-            //0xF1 => "",
-            //0xF2 => "",
-            //0xF3 => "",
-            //0xF4 => "",
-            //0xF5 => "",
-            //0xF6 => "",
-            //0xF7 => "",
-            //0xF8 => "",
-            //0xF9 => "",
-            0xFA => "i860",
-            0xFB => "i960",
-            //0xFC => "",
-            //0xFD => "",
-            0xFE => self.processor_family2_str().unwrap(),
-            0xFF => "Reserved",
+    // Status bit 6 is set when the socket holds a CPU.
+    pub fn is_populated(&self) -> Option<bool> {
+        self.status().map(|s| (s & 0x40) != 0)
+    }
 
-            _ => unreachable!(),
+    pub fn processor_family_str(&self) -> Option<String> {
+        self.processor_family().map(|f| {
+            let family = match f {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "8086",
+                0x04 => "80286",
+                0x05 => "Intel386 processor",
+                0x06 => "Intel486 processor",
+                0x07 => "8087",
+                0x08 => "80287",
+                0x09 => "80387",
+                0x0A => "80487",
+                0x0B => "Intel Pentium processor",
+                0x0C => "Pentium Pro processor",
+                0x0D => "Pentium II processor",
+                0x0E => "Pentium processor with MMX technology",
+                0x0F => "Intel Celeron processor",
+
+                0x10 => "Pentium II Xeon processor",
+                0x11 => "Pentium III processor",
+                0x12 => "M1 Family",
+                0x13 => "M2 Family",
+                0x14 => "Intel Celeron M processor",
+                0x15 => "Intel Pentium 4 HT processor",
+                0x16 => "Intel Processor",
+                //0x17 => "",
+                0x18 => "AMD Duron Processor Family",
+                0x19 => "K5 Family",
+                0x1A => "K6 Family",
+                0x1B => "K6-2",
+                0x1C => "K6-3",
+                0x1D => "AMD Athlon Processor Family",
+                0x1E => "AMD29000 Family",
+                0x1F => "K6-2+",
+
+                0x20 => "Power PC Family",
+                0x21 => "Power PC 601",
+                0x22 => "Power PC 603",
+                0x23 => "Power PC 603+",
+                0x24 => "Power PC 604",
+                0x25 => "Power PC 620",
+                0x26 => "Power PC x704",
+                0x27 => "Power PC 750",
+                0x28 => "Intel Core Duo processor",
+                0x29 => "Intel Core Duo mobile processor",
+                0x2A => "Intel Core Solo mobile processor",
+                0x2B => "Intel Atom processor",
+                0x2C => "Intel Core M processor",
+                0x2D => "Intel Core m3 processor",
+                0x2E => "Intel Core m5 processor",
+                0x2F => "Intel Core m7 processor",
+
+                0x30 => "Alpha Family",
+                0x31 => "Alpha 21064",
+                0x32 => "Alpha 21066",
+                0x33 => "Alpha 21164",
+                0x34 => "Alpha 21164PC",
+                0x35 => "Alpha 21164a",
+                0x36 => "Alpha 21264",
+                0x37 => "Alpha 21364",
+                0x38 => "AMD Turion II Ultra Dual-Core Mobile M Processor Family",
+                0x39 => "AMD Turion II Dual-Core Mobile M Processor Family",
+                0x3A => "AMD Athlon II Dual-Core M Processor Family",
+                0x3B => "AMD Opteron 6100 Series Processor",
+                0x3C => "AMD Opteron 4100 Series Processor",
+                0x3D => "AMD Opteron 6200 Series Processor",
+                0x3E => "AMD Opteron 4200 Series Processor",
+                0x3F => "AMD FX Series Processor",
+
+                0x40 => "MIPS Family",
+                0x41 => "MIPS R4000",
+                0x42 => "MIPS R4200",
+                0x43 => "MIPS R4400",
+                0x44 => "MIPS R4600",
+                0x45 => "MIPS R10000",
+                0x46 => "AMD C-Series Processor",
+                0x47 => "AMD E-Series Processor",
+                0x48 => "AMD A-Series Processor",
+                0x49 => "AMD G-Series Processor",
+                0x4A => "AMD Z-Series Processor",
+                0x4B => "AMD R-Series Processor",
+                0x4C => "AMD Opteron 4300 Series Processor",
+                0x4D => "AMD Opteron 6300 Series Processor",
+                0x4E => "AMD Opteron 3300 Series Processor",
+                0x4F => "AMD FirePro Series Processor",
+
+                0x50 => "SPARC Family",
+                0x51 => "SuperSPARC",
+                0x52 => "microSPARC II",
+                0x53 => "microSPARC IIep",
+                0x54 => "UltraSPARC",
+                0x55 => "UltraSPARC II",
+                0x56 => "UltraSPARC Iii",
+                0x57 => "UltraSPARC III",
+                0x58 => "UltraSPARC IIIi",
+                //0x59 => "",
+                //0x5A => "",
+                //0x5B => "",
+                //0x5C => "",
+                //0x5D => "",
+                //0x5E => "",
+                //0x5F => "",
+                0x60 => "68040 Family",
+                0x61 => "68xxx",
+                0x62 => "68000",
+                0x63 => "68010",
+                0x64 => "68020",
+                0x65 => "68030",
+                0x66 => "AMD Athlon X4 Quad-Core Processor Family",
+                0x67 => "AMD Opteron X1000 Series Processor",
+                0x68 => "AMD Opteron X2000 Series APU",
+                0x69 => "AMD Opteron A-Series Processor",
+                0x6A => "AMD Opteron X3000 Series APU",
+                0x6B => "AMD Zen Processor Family",
+                //0x6C => "",
+                //0x6D => "",
+                //0x6E => "",
+                //0x6F => "",
+                0x70 => "Hobbit Family",
+                //0x71 => "",
+                //0x72 => "",
+                //0x73 => "",
+                //0x74 => "",
+                //0x75 => "",
+                //0x76 => "",
+                //0x77 => "",
+                0x78 => "Crusoe TM5000 Family",
+                0x79 => "Crusoe TM3000 Family",
+                0x7A => "Efficeon TM8000 Family",
+                //0x7B => "",
+                //0x7C => "",
+                //0x7D => "",
+                //0x7E => "",
+                //0x7F => "",
+                0x80 => "Weitek",
+                //0x81 => "",
+                0x82 => "Itanium processor",
+                0x83 => "AMD Athlon 64 Processor Family",
+                0x84 => "AMD Opteron Processor Family",
+                0x85 => "AMD Sempron Processor Family",
+                0x86 => "AMD Turion 64 Mobile Technology",
+                0x87 => "Dual-Core AMD Opteron Processor Family",
+                0x88 => "AMD Athlon 64 X2 Dual-Core Processor Family",
+                0x89 => "AMD Turion 64 X2 Mobile Technology",
+                0x8A => "Quad-Core AMD Opteron Processor Family",
+                0x8B => "Third-Generation AMD Opteron Processor Family",
+                0x8C => "AMD Phenom FX Quad-Core Processor Family",
+                0x8D => "AMD Phenom X4 Quad-Core Processor Family",
+                0x8E => "AMD Phenom X2 Dual-Core Processor Family",
+                0x8F => "AMD Athlon X2 Dual-Core Processor Family",
+
+                0x90 => "PA-RISC Family",
+                0x91 => "PA-RISC 8500",
+                0x92 => "PA-RISC 8000",
+                0x93 => "PA-RISC 7300LC",
+                0x94 => "PA-RISC 7200",
+                0x95 => "PA-RISC 7100LC",
+                0x96 => "PA-RISC 7100",
+                //0x97 => "",
+                //0x98 => "",
+                //0x99 => "",
+                //0x9A => "",
+                //0x9B => "",
+                //0x9C => "",
+                //0x9D => "",
+                //0x9E => "",
+                //0x9F => "",
+                0xA0 => "V30 Family",
+                0xA1 => "Quad-Core Intel Xeon processor 3200 Series",
+                0xA2 => "Dual-Core Intel Xeon processor 3000 Series",
+                0xA3 => "Quad-Core Intel Xeon processor 5300 Series",
+                0xA4 => "Dual-Core Intel Xeon processor 5100 Series",
+                0xA5 => "Dual-Core Intel Xeon processor 5000 Series",
+                0xA6 => "Dual-Core Intel Xeon processor LV",
+                0xA7 => "Dual-Core Intel Xeon processor ULV",
+                0xA8 => "Dual-Core Intel Xeon processor 7100 Series",
+                0xA9 => "Quad-Core Intel Xeon processor 5400 Series",
+                0xAA => "Quad-Core Intel Xeon processor",
+                0xAB => "Dual-Core Intel Xeon processor 5200 Series",
+                0xAC => "Dual-Core Intel Xeon processor 7200 Series",
+                0xAD => "Quad-Core Intel Xeon processor 7300 Series",
+                0xAE => "Quad-Core Intel Xeon processor 7400 Series",
+                0xAF => "Multi-Core Intel Xeon processor 7400 Series",
+
+                0xB0 => "Pentium III Xeon processor",
+                0xB1 => "Pentium III Processor with Intel  SpeedStep Technology",
+                0xB2 => "Pentium 4 Processor",
+                0xB3 => "Intel Xeon processor",
+                0xB4 => "AS400 Family",
+                0xB5 => "Intel Xeon processor MP",
+                0xB6 => "AMD Athlon XP Processor Family",
+                0xB7 => "AMD Athlon MP Processor Family",
+                0xB8 => "Intel Itanium 2 processor",
+                0xB9 => "Intel Pentium M processor",
+                0xBA => "Intel Celeron D processor",
+                0xBB => "Intel Pentium D processor",
+                0xBC => "Intel Pentium Processor Extreme Edition",
+                0xBD => "Intel Core Solo Processor",
+                //0xBE => "",
+                0xBF => "Intel Core 2 Duo Processor",
+
+                0xC0 => "Intel Core 2 Solo processor",
+                0xC1 => "Intel Core 2 Extreme processor",
+                0xC2 => "Intel Core 2 Quad processor",
+                0xC3 => "Intel Core 2 Extreme mobile processor",
+                0xC4 => "Intel Core 2 Duo mobile processor",
+                0xC5 => "Intel Core 2 Solo mobile processor",
+                0xC6 => "Intel Core i7 processor",
+                0xC7 => "Dual-Core Intel Celeron processor",
+                0xC8 => "IBM390 Family",
+                0xC9 => "G4",
+                0xCA => "G5",
+                0xCB => "ESA/390 G6",
+                0xCC => "z/Architecture base",
+                0xCD => "Intel Core i5 processor",
+                0xCE => "Intel Core i3 processor",
+                0xCF => "Intel Core i9 processor",
+
+                //0xD0 => "",
+                //0xD1 => "",
+                0xD2 => "VIA C7-M Processor Family",
+                0xD3 => "VIA C7-D Processor Family",
+                0xD4 => "VIA C7 Processor Family",
+                0xD5 => "VIA Eden Processor Family",
+                0xD6 => "Multi-Core Intel Xeon processor",
+                0xD7 => "Dual-Core Intel Xeon processor 3xxx Series",
+                0xD8 => "Quad-Core Intel Xeon processor 3xxx Series",
+                0xD9 => "VIA Nano Processor Family",
+                0xDA => "Dual-Core Intel Xeon processor 5xxx Series",
+                0xDB => "Quad-Core Intel Xeon processor 5xxx Series",
+                //0xDC => "",
+                0xDD => "Dual-Core Intel Xeon processor 7xxx Series",
+                0xDE => "Quad-Core Intel Xeon processor 7xxx Series",
+                0xDF => "Multi-Core Intel Xeon processor 7xxx Series",
+
+                0xE0 => "Multi-Core Intel Xeon processor 3400 Series",
+                //0xE1 => "",
+                //0xE2 => "",
+                //0xE3 => "",
+                0xE4 => "AMD Opteron 3000 Series Processor",
+                0xE5 => "AMD Sempron II Processor",
+                0xE6 => "Embedded AMD Opteron Quad-Core Processor Family",
+                0xE7 => "AMD Phenom Triple-Core Processor Family",
+                0xE8 => "AMD Turion Ultra Dual-Core Mobile Processor Family",
+                0xE9 => "AMD Turion Dual-Core Mobile Processor Family",
+                0xEA => "AMD Athlon Dual-Core Processor Family",
+                0xEB => "AMD Sempron SI Processor Family",
+                0xEC => "AMD Phenom II Processor Family",
+                0xED => "AMD Athlon II Processor Family",
+                0xEE => "Six-Core AMD Opteron Processor Family",
+                0xEF => "AMD Sempron M Processor Family",
+
+                //0xF0 => "",
+                //0xF1 => "",
+                //0xF2 => "",
+                //0xF3 => "",
+                //0xF4 => "",
+                //0xF5 => "",
+                //0xF6 => "",
+                //0xF7 => "",
+                //0xF8 => "",
+                //0xF9 => "",
+                0xFA => "i860",
+                0xFB => "i960",
+                //0xFC => "",
+                //0xFD => "",
+                0xFE => {
+                    return self
+                        .processor_family2_str()
+                        .unwrap_or_else(|| "Unknown".to_string())
+                }
+                0xFF => "Reserved",
+
+                v => return format!("Unknown (0x{:02X})", v),
+            };
+            family.to_string()
         })
     }
 
@@ -832,105 +2457,27 @@ impl Processor {
         })
     }
 
-    pub fn status_str(&self) -> Option<&'static str> {
+    pub fn status_str(&self) -> Option<String> {
         self.status().map(|s| {
-            if (s & 0x40) == 0 {
-                return "Unpopulated";
+            if self.is_populated() == Some(false) {
+                return "Unpopulated".to_string();
             }
 
-            match s & 0x0F {
+            match s & 0x07 {
                 0x00 => "Unknown",
                 0x01 => "Enabled",
                 0x02 => "Disabled by User",
                 0x03 => "Disabled By BIOS",
                 0x04 => "Idle",
                 0x07 => "Other",
-                _ => unreachable!(),
+                v => return format!("Unknown (0x{:02X})", v),
             }
+            .to_string()
         })
     }
 
-    pub fn processor_upgrade_str(&self) -> Option<&'static str> {
-        self.processor_upgrade().map(|u| match u {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "Daughter Board",
-            0x04 => "ZIF Socket",
-            0x05 => "Replaceable Piggy Back",
-            0x06 => "None",
-            0x07 => "LIF Socket",
-            0x08 => "Slot 1",
-            0x09 => "Slot 2",
-            0x0A => "370-pin socket",
-            0x0B => "Slot A",
-            0x0C => "Slot M",
-            0x0D => "Socket 423",
-            0x0E => "Socket A (Socket 462)",
-            0x0F => "Socket 478",
-
-            0x10 => "Socket 754",
-            0x11 => "Socket 940",
-            0x12 => "Socket 939",
-            0x13 => "Socket mPGA604",
-            0x14 => "Socket LGA771",
-            0x15 => "Socket LGA775",
-            0x16 => "Socket S1",
-            0x17 => "Socket AM2",
-            0x18 => "Socket F (1207)",
-            0x19 => "Socket LGA1366",
-            0x1A => "Socket G34",
-            0x1B => "Socket AM3",
-            0x1C => "Socket C32",
-            0x1D => "Socket LGA1156",
-            0x1E => "Socket LGA1567",
-            0x1F => "Socket PGA988A",
-
-            0x20 => "Socket BGA1288",
-            0x21 => "Socket rPGA988B",
-            0x22 => "Socket BGA1023",
-            0x23 => "Socket BGA1224",
-            0x24 => "Socket LGA1155",
-            0x25 => "Socket LGA1356",
-            0x26 => "Socket LGA2011",
-            0x27 => "Socket FS1",
-            0x28 => "Socket FS2",
-            0x29 => "Socket FM1",
-            0x2A => "Socket FM2",
-            0x2B => "Socket LGA2011-3",
-            0x2C => "Socket LGA1356-3",
-            0x2D => "Socket LGA1150",
-            0x2E => "Socket BGA1168",
-            0x2F => "Socket BGA1234",
-
-            0x30 => "Socket BGA1364",
-            0x31 => "Socket AM4",
-            0x32 => "Socket LGA1151",
-            0x33 => "Socket BGA1356",
-            0x34 => "Socket BGA1440",
-            0x35 => "Socket BGA1515",
-            0x36 => "Socket LGA3647-1",
-            0x37 => "Socket SP3",
-            0x38 => "Socket SP3r2",
-            0x39 => "Socket LGA2066",
-            0x3A => "Socket BGA1392",
-            0x3B => "Socket BGA1510",
-            0x3C => "Socket BGA1528",
-            0x3D => "Socket LGA4189",
-            0x3E => "Socket LGA1200",
-            0x3F => "Socket LGA4677",
-
-            0x40 => "Socket LGA1700",
-            0x41 => "Socket BGA1744",
-            0x42 => "Socket BGA1781",
-            0x43 => "Socket BGA1211",
-            0x44 => "Socket BGA2422",
-            0x45 => "Socket LGA1211",
-            0x46 => "Socket LGA2422",
-            0x47 => "Socket LGA5773",
-            0x48 => "Socket BGA5773",
-
-            _ => unreachable!(),
-        })
+    pub fn processor_upgrade_str(&self) -> Option<String> {
+        self.processor_upgrade().map(|u| u.to_string())
     }
 
     pub fn core_count_mixed(&self) -> Option<u16> {
@@ -963,43 +2510,46 @@ impl Processor {
             .map(|v| get_flag_strings(v as u64, &chars))
     }
 
-    pub fn processor_family2_str(&self) -> Option<&'static str> {
-        self.processor_family2().map(|f| match f {
-            0x0100 => "ARMv7",
-            0x0101 => "ARMv8",
-            0x0102 => "ARMv9",
-            //0x0103 => "",
-            0x0104 => "SH-3",
-            0x0105 => "SH-4",
-            0x0118 => "ARM",
-            0x0119 => "StrongARM",
-            0x012C => "6x86",
-            0x012D => "MediaGX",
-            0x012E => "MII",
-            0x0140 => "WinChip",
-            0x015E => "DSP",
-            0x01F4 => "Video Processor",
-            0x0200 => "RISC-V RV32",
-            0x0201 => "RISC-V RV64",
-            0x0202 => "RISC-V RV128",
-            0x0258 => "LoongArch",
-            0x0259 => "Loongson 1 Processor Family",
-            0x025A => "Loongson 2 Processor Family",
-            0x025B => "Loongson 3 Processor Family",
-            0x025C => "Loongson 2K Processor Family",
-            0x025D => "Loongson 3A Processor Family",
-            0x025E => "Loongson 3B Processor Family",
-            0x025F => "Loongson 3C Processor Family",
-            0x0260 => "Loongson 3D Processor Family",
-            0x0261 => "Loongson 3E Processor Family",
-            0x0262 => "Dual-Core Loongson 2K Processor 2xxx Series",
-            0x026C => "Quad-Core Loongson 3A Processor 5xxx Series",
-            0x026D => "Multi-Core Loongson 3A Processor 5xxx Series",
-            0x026E => "Quad-Core Loongson 3B Processor 5xxx Series",
-            0x026F => "Multi-Core Loongson 3B Processor 5xxx Series",
-            0x0270 => "Multi-Core Loongson 3C Processor 5xxx Series",
-            0x0271 => "Multi-Core Loongson 3D Processor 5xxx Series",
-            _ => unreachable!(),
+    pub fn processor_family2_str(&self) -> Option<String> {
+        self.processor_family2().map(|f| {
+            let family = match f {
+                0x0100 => "ARMv7",
+                0x0101 => "ARMv8",
+                0x0102 => "ARMv9",
+                //0x0103 => "",
+                0x0104 => "SH-3",
+                0x0105 => "SH-4",
+                0x0118 => "ARM",
+                0x0119 => "StrongARM",
+                0x012C => "6x86",
+                0x012D => "MediaGX",
+                0x012E => "MII",
+                0x0140 => "WinChip",
+                0x015E => "DSP",
+                0x01F4 => "Video Processor",
+                0x0200 => "RISC-V RV32",
+                0x0201 => "RISC-V RV64",
+                0x0202 => "RISC-V RV128",
+                0x0258 => "LoongArch",
+                0x0259 => "Loongson 1 Processor Family",
+                0x025A => "Loongson 2 Processor Family",
+                0x025B => "Loongson 3 Processor Family",
+                0x025C => "Loongson 2K Processor Family",
+                0x025D => "Loongson 3A Processor Family",
+                0x025E => "Loongson 3B Processor Family",
+                0x025F => "Loongson 3C Processor Family",
+                0x0260 => "Loongson 3D Processor Family",
+                0x0261 => "Loongson 3E Processor Family",
+                0x0262 => "Dual-Core Loongson 2K Processor 2xxx Series",
+                0x026C => "Quad-Core Loongson 3A Processor 5xxx Series",
+                0x026D => "Multi-Core Loongson 3A Processor 5xxx Series",
+                0x026E => "Quad-Core Loongson 3B Processor 5xxx Series",
+                0x026F => "Multi-Core Loongson 3B Processor 5xxx Series",
+                0x0270 => "Multi-Core Loongson 3C Processor 5xxx Series",
+                0x0271 => "Multi-Core Loongson 3D Processor 5xxx Series",
+                v => return format!("Unknown (0x{:04X})", v),
+            };
+            family.to_string()
         })
     }
 
@@ -1037,17 +2587,20 @@ pub struct MemoryController {
 }
 
 impl MemoryController {
-    pub fn error_detecting_method_str(&self) -> Option<&'static str> {
-        self.error_detecting_method().map(|e| match e {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "None",
-            0x04 => "8-bit Parity",
-            0x05 => "32-bit ECC",
-            0x06 => "64-bit ECC",
-            0x07 => "128-bit ECC",
-            0x08 => "CRC",
-            _ => unreachable!(),
+    pub fn error_detecting_method_str(&self) -> Option<String> {
+        self.error_detecting_method().map(|e| {
+            match e {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "None",
+                0x04 => "8-bit Parity",
+                0x05 => "32-bit ECC",
+                0x06 => "64-bit ECC",
+                0x07 => "128-bit ECC",
+                0x08 => "CRC",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 
@@ -1056,18 +2609,20 @@ impl MemoryController {
             .map(|e| self.get_error_correcting_capability(e))
     }
 
-    pub fn supported_interleave_str(&self) -> Option<&'static str> {
+    pub fn supported_interleave_str(&self) -> Option<String> {
         self.supported_interleave()
             .map(|i| self.get_memory_interleave(i))
     }
 
-    pub fn current_interleave_str(&self) -> Option<&'static str> {
+    pub fn current_interleave_str(&self) -> Option<String> {
         self.current_interleave()
             .map(|i| self.get_memory_interleave(i))
     }
 
     pub fn maximum_memory_module_size_mb(&self) -> Option<u32> {
-        self.maximum_memory_module_size().map(|s| 1 << s)
+        // The size is a power of two; anything past 2^31 MB cannot be real.
+        self.maximum_memory_module_size()
+            .and_then(|s| 1u32.checked_shl(s as u32))
     }
 
     pub fn maximum_memory_total_size_mb(&self) -> Option<u32> {
@@ -1075,7 +2630,9 @@ impl MemoryController {
             self.maximum_memory_module_size(),
             self.num_associated_memory_slots(),
         ) {
-            return Some((1u32 << module) * (count as u32));
+            return 1u32
+                .checked_shl(module as u32)
+                .and_then(|size| size.checked_mul(count as u32));
         }
 
         None
@@ -1103,7 +2660,7 @@ impl MemoryController {
         get_flag_strings(value as u64, &caps)
     }
 
-    fn get_memory_interleave(&self, value: u8) -> &'static str {
+    fn get_memory_interleave(&self, value: u8) -> String {
         match value {
             0x01 => "Other",
             0x02 => "Unknown",
@@ -1112,8 +2669,9 @@ impl MemoryController {
             0x05 => "Four-Way Interleave",
             0x06 => "Eight-Way Interleave",
             0x07 => "Sixteen-Way Interleave",
-            _ => unreachable!(),
+            v => return format!("Unknown (0x{:02X})", v),
         }
+        .to_string()
     }
 }
 
@@ -1122,29 +2680,64 @@ pub struct MemoryModule {
     table_ty: u8,
     length: u8,
     handle: u16,
-    socket_designation: Option<String>,
+    socket_designation: Option<SmbiosString>,
+    #[smbios(packed("bank_connections_str"))]
     bank_connections: Option<u8>,
     current_speed: Option<u8>,
     current_memory_ty: Option<u16>,
+    #[smbios(packed("installed_size_str"))]
     installed_size: Option<u8>,
+    #[smbios(packed("enabled_size_str"))]
     enabled_size: Option<u8>,
     error_status: Option<u8>,
 }
 
 impl MemoryModule {
+    pub fn bank_connections_str(&self) -> Option<String> {
+        self.bank_connections().map(|v| {
+            if v == 0xFF {
+                "None".to_string()
+            } else if (v & 0xF0) == 0xF0 {
+                format!("{}", v & 0xF0)
+            } else if (v & 0x0F) == 0x0F {
+                format!("{}", v >> 4)
+            } else {
+                format!("{} {}", v >> 4, v & 0x0F)
+            }
+        })
+    }
+
     pub fn current_memory_ty_str(&self) -> Option<Vec<String>> {
         self.current_memory_ty().map(get_memory_ty_str)
     }
+
+    pub fn installed_size_str(&self) -> Option<String> {
+        self.installed_size().map(get_memory_module_size_str)
+    }
+
+    pub fn enabled_size_str(&self) -> Option<String> {
+        self.enabled_size().map(get_memory_module_size_str)
+    }
 }
 
 #[derive(SMBIOS)]
+#[smbios(min_length(v2_0 = 0x0F, v2_1 = 0x13, v3_1 = 0x1B))]
 pub struct Cache {
     table_ty: u8,
     length: u8,
     handle: u16,
-    socket_designation: Option<String>,
+    socket_designation: Option<SmbiosString>,
+    #[smbios(packed(
+        "operational_mode",
+        "enabled",
+        "location",
+        "cache_socketed",
+        "cache_level"
+    ))]
     cache_configuration: Option<u16>,
+    #[smbios(packed("maximum_cache_size_kb"))]
     maximum_cache_size: Option<u16>,
+    #[smbios(packed("installed_size_kb"))]
     installed_size: Option<u16>,
     supported_sram_ty: Option<u16>,
     current_sram_ty: Option<u16>,
@@ -1197,6 +2790,14 @@ impl Cache {
         self.cache_configuration().map(|c| ((c & 0x0007) as u8) + 1)
     }
 
+    pub fn maximum_cache_size_kb(&self) -> Option<u32> {
+        self.maximum_cache_size().map(get_cache_size_kb)
+    }
+
+    pub fn installed_size_kb(&self) -> Option<u32> {
+        self.installed_size().map(get_cache_size_kb)
+    }
+
     pub fn supported_sram_ty_str(&self) -> Option<Vec<String>> {
         self.supported_sram_ty().map(|v| self.get_sram_ty(v))
     }
@@ -1205,46 +2806,55 @@ impl Cache {
         self.current_sram_ty().map(|v| self.get_sram_ty(v))
     }
 
-    pub fn error_correction_ty_str(&self) -> Option<&'static str> {
-        self.error_correction_ty().map(|t| match t {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "None",
-            0x04 => "Parity",
-            0x05 => "Single-bit ECC",
-            0x06 => "Multi-bit ECC",
-            _ => unreachable!(),
+    pub fn error_correction_ty_str(&self) -> Option<String> {
+        self.error_correction_ty().map(|t| {
+            match t {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "None",
+                0x04 => "Parity",
+                0x05 => "Single-bit ECC",
+                0x06 => "Multi-bit ECC",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 
-    pub fn system_cache_ty_str(&self) -> Option<&'static str> {
-        self.system_cache_ty().map(|t| match t {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "Instruction",
-            0x04 => "Data",
-            0x05 => "Unified",
-            _ => unreachable!(),
+    pub fn system_cache_ty_str(&self) -> Option<String> {
+        self.system_cache_ty().map(|t| {
+            match t {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "Instruction",
+                0x04 => "Data",
+                0x05 => "Unified",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 
-    pub fn associativity_str(&self) -> Option<&'static str> {
-        self.associativity().map(|a| match a {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "Direct Mapped",
-            0x04 => "2-way Set-Associative",
-            0x05 => "4-way Set-Associative",
-            0x06 => "Fully Associative",
-            0x07 => "8-way Set-Associative",
-            0x08 => "16-way Set-Associative",
-            0x09 => "12-way Set-Associative",
-            0x0A => "24-way Set-Associative",
-            0x0B => "32-way Set-Associative",
-            0x0C => "48-way Set-Associative",
-            0x0D => "64-way Set-Associative",
-            0x0E => "20-way Set-Associative",
-            _ => unreachable!(),
+    pub fn associativity_str(&self) -> Option<String> {
+        self.associativity().map(|a| {
+            match a {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "Direct Mapped",
+                0x04 => "2-way Set-Associative",
+                0x05 => "4-way Set-Associative",
+                0x06 => "Fully Associative",
+                0x07 => "8-way Set-Associative",
+                0x08 => "16-way Set-Associative",
+                0x09 => "12-way Set-Associative",
+                0x0A => "24-way Set-Associative",
+                0x0B => "32-way Set-Associative",
+                0x0C => "48-way Set-Associative",
+                0x0D => "64-way Set-Associative",
+                0x0E => "20-way Set-Associative",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 
@@ -1268,70 +2878,73 @@ pub struct PortConnector {
     table_ty: u8,
     length: u8,
     handle: u16,
-    internal_reference_designator: Option<String>,
+    internal_reference_designator: Option<SmbiosString>,
     internal_connector_ty: Option<u8>,
-    external_reference_designator: Option<String>,
+    external_reference_designator: Option<SmbiosString>,
     external_connector_ty: Option<u8>,
     port_ty: Option<u8>,
 }
 
 impl PortConnector {
-    pub fn internal_connector_ty_str(&self) -> Option<&'static str> {
+    pub fn internal_connector_ty_str(&self) -> Option<String> {
         self.internal_connector_ty
             .map(|t| self.get_port_connector_ty(t))
     }
 
-    pub fn external_connector_ty_str(&self) -> Option<&'static str> {
+    pub fn external_connector_ty_str(&self) -> Option<String> {
         self.external_connector_ty
             .map(|t| self.get_port_connector_ty(t))
     }
 
-    pub fn port_ty_str(&self) -> Option<&'static str> {
-        self.port_ty().map(|t| match t {
-            0x00 => "None",
-            0x01 => "Parallel Port XT/AT Compatible",
-            0x02 => "Parallel Port PS/2",
-            0x03 => "Parallel Port ECP",
-            0x04 => "Parallel Port EPP",
-            0x05 => "Parallel Port ECP/EPP",
-            0x06 => "Serial Port XT/AT Compatible",
-            0x07 => "Serial Port 16450 Compatible",
-            0x08 => "Serial Port 16550 Compatible",
-            0x09 => "Serial Port 16550A Compatible",
-            0x0A => "SCSI Port",
-            0x0B => "MIDI Port",
-            0x0C => "Joy Stick Port",
-            0x0D => "Keyboard Port",
-            0x0E => "Mouse Port",
-            0x0F => "SSA SCSI",
-            0x10 => "USB",
-            0x11 => "FireWire (IEEE P1394)",
-            0x12 => "PCMCIA Type I",
-            0x13 => "PCMCIA Type II",
-            0x14 => "PCMCIA Type III",
-            0x15 => "Card bus",
-            0x16 => "Access Bus Port",
-            0x17 => "SCSI II",
-            0x18 => "SCSI Wide",
-            0x19 => "PC-98",
-            0x1A => "PC-98-Hireso",
-            0x1B => "PC-H98",
-            0x1C => "Video Port",
-            0x1D => "Audio Port",
-            0x1E => "Modem Port",
-            0x1F => "Network Port",
-            0x20 => "SATA",
-            0x21 => "SAS",
-            0x22 => "MFDP (Multi-Function Display Port)",
-            0x23 => "Thunderbolt",
-            0xA0 => "8251 Compatible",
-            0xA1 => "8251 FIFO Compatible",
-            0xFF => "Other",
-            _ => unreachable!(),
+    pub fn port_ty_str(&self) -> Option<String> {
+        self.port_ty().map(|t| {
+            match t {
+                0x00 => "None",
+                0x01 => "Parallel Port XT/AT Compatible",
+                0x02 => "Parallel Port PS/2",
+                0x03 => "Parallel Port ECP",
+                0x04 => "Parallel Port EPP",
+                0x05 => "Parallel Port ECP/EPP",
+                0x06 => "Serial Port XT/AT Compatible",
+                0x07 => "Serial Port 16450 Compatible",
+                0x08 => "Serial Port 16550 Compatible",
+                0x09 => "Serial Port 16550A Compatible",
+                0x0A => "SCSI Port",
+                0x0B => "MIDI Port",
+                0x0C => "Joy Stick Port",
+                0x0D => "Keyboard Port",
+                0x0E => "Mouse Port",
+                0x0F => "SSA SCSI",
+                0x10 => "USB",
+                0x11 => "FireWire (IEEE P1394)",
+                0x12 => "PCMCIA Type I",
+                0x13 => "PCMCIA Type II",
+                0x14 => "PCMCIA Type III",
+                0x15 => "Card bus",
+                0x16 => "Access Bus Port",
+                0x17 => "SCSI II",
+                0x18 => "SCSI Wide",
+                0x19 => "PC-98",
+                0x1A => "PC-98-Hireso",
+                0x1B => "PC-H98",
+                0x1C => "Video Port",
+                0x1D => "Audio Port",
+                0x1E => "Modem Port",
+                0x1F => "Network Port",
+                0x20 => "SATA",
+                0x21 => "SAS",
+                0x22 => "MFDP (Multi-Function Display Port)",
+                0x23 => "Thunderbolt",
+                0xA0 => "8251 Compatible",
+                0xA1 => "8251 FIFO Compatible",
+                0xFF => "Other",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 
-    fn get_port_connector_ty(&self, value: u8) -> &'static str {
+    fn get_port_connector_ty(&self, value: u8) -> String {
         match value {
             0x00 => "None",
             0x01 => "Centronics",
@@ -1375,8 +2988,9 @@ impl PortConnector {
             0xA3 => "PC-98Note",
             0xA4 => "PC-98Full",
             0xFF => "Other",
-            _ => unreachable!(),
+            v => return format!("Unknown (0x{:02X})", v),
         }
+        .to_string()
     }
 }
 
@@ -1399,11 +3013,12 @@ impl SystemSlotsPeerDevice {
 }
 
 #[derive(SMBIOS)]
+#[smbios(min_length(v2_0 = 0x0C, v2_1 = 0x0D, v2_6 = 0x11, v3_2 = 0x13))]
 pub struct SystemSlots {
     table_ty: u8,
     length: u8,
     handle: u16,
-    slot_designation: Option<String>,
+    slot_designation: Option<SmbiosString>,
     slot_ty: Option<u8>,
     slot_data_bus_width: Option<u8>,
     current_usage: Option<u8>,
@@ -1420,122 +3035,148 @@ pub struct SystemSlots {
     peer_groups: Option<Vec<SystemSlotsPeerDevice>>,
     slot_information: Option<u8>,
     slot_physical_width: Option<u8>,
-    slot_pitch: Option<u8>,
+    slot_pitch: Option<u16>,
     slot_height: Option<u8>,
 }
 
 impl SystemSlots {
-    pub fn slot_ty_str(&self) -> Option<&'static str> {
-        self.slot_ty().map(|t| match t {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "ISA",
-            0x04 => "MCA",
-            0x05 => "EISA",
-            0x06 => "PCI",
-            0x07 => "PCMCIA",
-            0x08 => "VL-VESA",
-            0x09 => "Proprietary",
-            0x0A => "Processor Card Slot",
-            0x0B => "Proprietary Memory Card Slot",
-            0x0C => "I/O Riser Card Slot",
-            0x0D => "NuBus",
-            0x0E => "PCI - 66MHz Capable",
-            0x0F => "AGP",
-            0x10 => "AGP 2X",
-            0x11 => "AGP 4X",
-            0x12 => "PCI-X",
-            0x13 => "AGP 8X",
-            0x14 => "M.2 Socket 1-DP",
-            0x15 => "M.2 Socket 1-SD",
-            0x16 => "M.2 Socket 2",
-            0x17 => "M.2 Socket 3",
-            0x18 => "MXM Type I",
-            0x19 => "MXM Type II",
-            0x1A => "MXM Type III (standard connector)",
-            0x1B => "MXM Type III (HE connector)",
-            0x1C => "MXM Type IV",
-            0x1D => "MXM 3.0 Type A",
-            0x1E => "MXM 3.0 Type B",
-            0x1F => "PCI Express Gen 2 SFF-8639",
-            0x20 => "PCI Express Gen 3 SFF-8639",
-            0x21 => "PCI Express Mini 52-pin with bottom-side keep-outs",
-            0x22 => "PCI Express Mini 52-pin without bottom-side keep-outs",
-            0x23 => "PCI Express Mini 76-pin",
-            0x24 => "PCI Express Gen 4 SFF-8639",
-            0x25 => "PCI Express Gen 5 SFF-8639",
-            0x26 => "OCP NIC 3.0 Small Form Factor",
-            0x27 => "OCP NIC 3.0 Large Form Factor",
-            0x28 => "OCP NIC Prior to 3.0",
-            0x30 => "CXL Flexbus 1.0",
-            0xA0 => "PC-98/C20",
-            0xA1 => "PC-98/C24",
-            0xA2 => "PC-98/E",
-            0xA3 => "PC-98/Local Bus",
-            0xA4 => "PC-98/Card",
-            0xA5 => "PCI Express",
-            0xA6 => "PCI Express x1",
-            0xA7 => "PCI Express x2",
-            0xA8 => "PCI Express x4",
-            0xA9 => "PCI Express x8",
-            0xAA => "PCI Express x16",
-            0xAB => "PCI Express Gen 2",
-            0xAC => "PCI Express Gen 2 x1",
-            0xAD => "PCI Express Gen 2 x2",
-            0xAE => "PCI Express Gen 2 x4",
-            0xAF => "PCI Express Gen 2 x8",
-            0xB0 => "PCI Express Gen 2 x16",
-            0xB1 => "PCI Express Gen 3",
-            0xB2 => "PCI Express Gen 3 x1",
-            0xB3 => "PCI Express Gen 3 x2",
-            0xB4 => "PCI Express Gen 3 x4",
-            0xB5 => "PCI Express Gen 3 x8",
-            0xB6 => "PCI Express Gen 3 x16",
-            // 0xB7 => "",
-            0xB8 => "PCI Express Gen 4",
-            0xB9 => "PCI Express Gen 4 x1",
-            0xBA => "PCI Express Gen 4 x2",
-            0xBB => "PCI Express Gen 4 x4",
-            0xBC => "PCI Express Gen 4 x8",
-            0xBD => "PCI Express Gen 4 x16",
-            0xBE => "PCI Express Gen 5",
-            0xBF => "PCI Express Gen 5 x1",
-            0xC0 => "PCI Express Gen 5 x2",
-            0xC1 => "PCI Express Gen 5 x4",
-            0xC2 => "PCI Express Gen 5 x8",
-            0xC3 => "PCI Express Gen 5 x16",
-            0xC4 => "PCI Express Gen 6 and Beyond",
-            0xC5 => "Enterprise and Datacenter 1U E1 Form Factor Slot",
-            0xC6 => "Enterprise and Datacenter 3\" E3 Form Factor Slot",
-            _ => unreachable!(),
+    pub fn slot_ty_str(&self) -> Option<String> {
+        self.slot_ty().map(|t| {
+            match t {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "ISA",
+                0x04 => "MCA",
+                0x05 => "EISA",
+                0x06 => "PCI",
+                0x07 => "PCMCIA",
+                0x08 => "VL-VESA",
+                0x09 => "Proprietary",
+                0x0A => "Processor Card Slot",
+                0x0B => "Proprietary Memory Card Slot",
+                0x0C => "I/O Riser Card Slot",
+                0x0D => "NuBus",
+                0x0E => "PCI - 66MHz Capable",
+                0x0F => "AGP",
+                0x10 => "AGP 2X",
+                0x11 => "AGP 4X",
+                0x12 => "PCI-X",
+                0x13 => "AGP 8X",
+                0x14 => "M.2 Socket 1-DP",
+                0x15 => "M.2 Socket 1-SD",
+                0x16 => "M.2 Socket 2",
+                0x17 => "M.2 Socket 3",
+                0x18 => "MXM Type I",
+                0x19 => "MXM Type II",
+                0x1A => "MXM Type III (standard connector)",
+                0x1B => "MXM Type III (HE connector)",
+                0x1C => "MXM Type IV",
+                0x1D => "MXM 3.0 Type A",
+                0x1E => "MXM 3.0 Type B",
+                0x1F => "PCI Express Gen 2 SFF-8639",
+                0x20 => "PCI Express Gen 3 SFF-8639",
+                0x21 => "PCI Express Mini 52-pin with bottom-side keep-outs",
+                0x22 => "PCI Express Mini 52-pin without bottom-side keep-outs",
+                0x23 => "PCI Express Mini 76-pin",
+                0x24 => "PCI Express Gen 4 SFF-8639",
+                0x25 => "PCI Express Gen 5 SFF-8639",
+                0x26 => "OCP NIC 3.0 Small Form Factor",
+                0x27 => "OCP NIC 3.0 Large Form Factor",
+                0x28 => "OCP NIC Prior to 3.0",
+                0x30 => "CXL Flexbus 1.0",
+                0xA0 => "PC-98/C20",
+                0xA1 => "PC-98/C24",
+                0xA2 => "PC-98/E",
+                0xA3 => "PC-98/Local Bus",
+                0xA4 => "PC-98/Card",
+                0xA5 => "PCI Express",
+                0xA6 => "PCI Express x1",
+                0xA7 => "PCI Express x2",
+                0xA8 => "PCI Express x4",
+                0xA9 => "PCI Express x8",
+                0xAA => "PCI Express x16",
+                0xAB => "PCI Express Gen 2",
+                0xAC => "PCI Express Gen 2 x1",
+                0xAD => "PCI Express Gen 2 x2",
+                0xAE => "PCI Express Gen 2 x4",
+                0xAF => "PCI Express Gen 2 x8",
+                0xB0 => "PCI Express Gen 2 x16",
+                0xB1 => "PCI Express Gen 3",
+                0xB2 => "PCI Express Gen 3 x1",
+                0xB3 => "PCI Express Gen 3 x2",
+                0xB4 => "PCI Express Gen 3 x4",
+                0xB5 => "PCI Express Gen 3 x8",
+                0xB6 => "PCI Express Gen 3 x16",
+                // 0xB7 => "",
+                0xB8 => "PCI Express Gen 4",
+                0xB9 => "PCI Express Gen 4 x1",
+                0xBA => "PCI Express Gen 4 x2",
+                0xBB => "PCI Express Gen 4 x4",
+                0xBC => "PCI Express Gen 4 x8",
+                0xBD => "PCI Express Gen 4 x16",
+                0xBE => "PCI Express Gen 5",
+                0xBF => "PCI Express Gen 5 x1",
+                0xC0 => "PCI Express Gen 5 x2",
+                0xC1 => "PCI Express Gen 5 x4",
+                0xC2 => "PCI Express Gen 5 x8",
+                0xC3 => "PCI Express Gen 5 x16",
+                0xC4 => "PCI Express Gen 6 and Beyond",
+                0xC5 => "Enterprise and Datacenter 1U E1 Form Factor Slot",
+                0xC6 => "Enterprise and Datacenter 3\" E3 Form Factor Slot",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 
-    pub fn slot_data_bus_width_str(&self) -> Option<&'static str> {
+    pub fn slot_information_str(&self) -> Option<String> {
+        let info = self.slot_information()?;
+        let pcie = self
+            .slot_ty()
+            .map(|t| matches!(t, 0x1F..=0x25 | 0xA5..=0xB6 | 0xB8..=0xC4))
+            .unwrap_or_default();
+
+        let value = if info == 0 {
+            "Unknown".to_string()
+        } else if pcie {
+            format!("PCIe Gen {}", info)
+        } else {
+            format!("{}", info)
+        };
+        Some(value)
+    }
+
+    pub fn slot_data_bus_width_str(&self) -> Option<String> {
         self.slot_data_bus_width()
             .map(|t| self.get_data_bus_width_str(t))
     }
 
-    pub fn current_usage_str(&self) -> Option<&'static str> {
-        self.current_usage().map(|u| match u {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "Available",
-            0x04 => "In use",
-            0x05 => "Unavailable",
-            _ => unreachable!(),
+    pub fn current_usage_str(&self) -> Option<String> {
+        self.current_usage().map(|u| {
+            match u {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "Available",
+                0x04 => "In use",
+                0x05 => "Unavailable",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 
-    pub fn slot_length_str(&self) -> Option<&'static str> {
-        self.slot_length().map(|l| match l {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "Short Length",
-            0x04 => "Long Length",
-            0x05 => "2.5\" drive form factor",
-            0x06 => "2.5\" drive form factor",
-            _ => unreachable!(),
+    pub fn slot_length_str(&self) -> Option<String> {
+        self.slot_length().map(|l| {
+            match l {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "Short Length",
+                0x04 => "Long Length",
+                0x05 => "2.5\" drive form factor",
+                0x06 => "3.5\" drive form factor",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 
@@ -1579,23 +3220,33 @@ impl SystemSlots {
         self.device_function_number().map(|n| n & 0x07)
     }
 
-    pub fn slot_physical_width_str(&self) -> Option<&'static str> {
+    pub fn slot_physical_width_str(&self) -> Option<String> {
         self.slot_physical_width()
             .map(|p| self.get_data_bus_width_str(p))
     }
 
-    pub fn slot_height_str(&self) -> Option<&'static str> {
-        self.slot_height().map(|h| match h {
-            0x00 => "Not applicable",
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "Full height",
-            0x04 => "Low-profile",
-            _ => unreachable!(),
+    pub fn slot_height_str(&self) -> Option<String> {
+        self.slot_height().map(|h| {
+            match h {
+                0x00 => "Not applicable",
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "Full height",
+                0x04 => "Low-profile",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
+        })
+    }
+
+    pub fn slot_pitch_str(&self) -> Option<String> {
+        self.slot_pitch().map(|p| match p {
+            0 => "Unknown".to_string(),
+            p => format!("{:.2} mm", p as f32 / 100.0),
         })
     }
 
-    pub fn get_data_bus_width_str(&self, value: u8) -> &'static str {
+    pub fn get_data_bus_width_str(&self, value: u8) -> String {
         match value {
             0x01 => "Other",
             0x02 => "Unknown",
@@ -1611,15 +3262,17 @@ impl SystemSlots {
             0x0C => "12x or x12",
             0x0D => "16x or x16",
             0x0E => "32x or x32",
-            _ => unreachable!(),
+            v => return format!("Unknown (0x{:02X})", v),
         }
+        .to_string()
     }
 }
 
 #[derive(SMBIOS)]
 pub struct OnBoardDevicesDevice {
+    #[smbios(packed("device_status", "device_ty_str"))]
     device_ty: Option<u8>,
-    description_string: Option<String>,
+    description_string: Option<SmbiosString>,
 }
 
 #[derive(SMBIOS)]
@@ -1627,40 +3280,35 @@ pub struct OnBoardDevices {
     table_ty: u8,
     length: u8,
     handle: u16,
-    #[smbios(length = "Some((length - 4) / 2)")]
+    #[smbios(length = "Some(length.saturating_sub(4) / 2)")]
     devices: Option<Vec<OnBoardDevicesDevice>>,
 }
 
+impl OnBoardDevicesDevice {
+    pub fn device_status(&self) -> Option<bool> {
+        self.device_ty().map(|t| (t & 0x80) == 0x80)
+    }
+
+    pub fn device_ty_str(&self) -> Option<String> {
+        self.device_ty()
+            .map(|t| get_onboard_device_ty_str(t & 0x7F))
+    }
+}
+
 impl OnBoardDevices {
-    pub fn get_device(&self) -> Option<Vec<(bool, &'static str, &str)>> {
+    pub fn get_device(&self) -> Option<Vec<(bool, String, &str)>> {
         self.devices().map(|devices| {
             let mut devs = vec![];
             for device in devices {
                 if let (Some(ty), Some(desc)) = (device.device_ty(), device.description_string()) {
                     let enabled = 0x80 & ty == 0x80;
-                    let dev = self.get_device_ty_str(0x7F & ty);
+                    let dev = get_onboard_device_ty_str(0x7F & ty);
                     devs.push((enabled, dev, desc));
                 }
             }
             devs
         })
     }
-
-    fn get_device_ty_str(&self, value: u8) -> &'static str {
-        match value {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "Video",
-            0x04 => "SCSI Controller",
-            0x05 => "Ethernet",
-            0x06 => "Tocken Ring",
-            0x07 => "Sound",
-            0x08 => "PATA Controller",
-            0x09 => "SATA Controller",
-            0x0A => "SAS Controller",
-            _ => unreachable!(),
-        }
-    }
 }
 
 #[derive(SMBIOS)]
@@ -1669,6 +3317,8 @@ pub struct OemStrings {
     length: u8,
     handle: u16,
     count: Option<u8>,
+    #[smbios(strings, length = "count")]
+    strings: Vec<String>,
 }
 
 #[derive(SMBIOS)]
@@ -1677,6 +3327,8 @@ pub struct SystemConfigurationOptions {
     length: u8,
     handle: u16,
     count: Option<u8>,
+    #[smbios(strings, length = "count")]
+    strings: Vec<String>,
 }
 
 #[derive(SMBIOS)]
@@ -1686,8 +3338,10 @@ pub struct BiosLanguage {
     handle: u16,
     installable_languages: Option<u8>,
     flags: Option<u8>,
-    reserved: Option<[u8; 15]>,
+    #[smbios(skip = 15)]
     current_language: Option<u8>,
+    #[smbios(strings, length = "installable_languages")]
+    languages: Vec<String>,
 }
 
 impl BiosLanguage {
@@ -1700,6 +3354,29 @@ impl BiosLanguage {
             }
         })
     }
+
+    pub fn current_language_str(&self) -> Option<&str> {
+        self.current_language()
+            .filter(|&i| i > 0)
+            .and_then(|i| self.languages.get((i - 1) as usize))
+            .map(|l| l.as_str())
+    }
+
+    pub fn abbreviated_languages(&self) -> Vec<String> {
+        if self.flags().map(|f| (0x01 & f) == 0x01).unwrap_or_default() {
+            self.languages.clone()
+        } else {
+            self.languages
+                .iter()
+                .map(|l| abbreviate_language(l))
+                .collect()
+        }
+    }
+}
+
+// "en|US|iso8859-1" -> "enUS"
+fn abbreviate_language(language: &str) -> String {
+    language.split('|').take(2).collect()
 }
 
 #[derive(SMBIOS)]
@@ -1713,8 +3390,8 @@ pub struct GroupAssociations {
     table_ty: u8,
     length: u8,
     handle: u16,
-    group_name: Option<String>,
-    #[smbios(length = "Some(((length - 5) / 3) as u8)")]
+    group_name: Option<SmbiosString>,
+    #[smbios(length = "Some(length.saturating_sub(5) / 3)")]
     items: Option<Vec<GroupAssociationsItem>>,
 }
 
@@ -1734,12 +3411,45 @@ pub struct SystemEventLog {
     num_supported_log_ty_desc: Option<u8>,
     length_each_log_ty_desc: Option<u8>,
     #[smbios(
-        length = "num_supported_log_ty_desc.map(|n| length_each_log_ty_desc.map(|l| n * l)).flatten()"
+        length_bytes = "num_supported_log_ty_desc.and_then(|n| length_each_log_ty_desc.map(|l| n as usize * l as usize))"
     )]
     list_supported_event_log_ty_desc: Option<Vec<u8>>,
 }
 
+impl SystemEventLog {
+    pub fn access_method_str(&self) -> Option<String> {
+        self.access_method().map(|m| {
+            match m {
+                0x00 => "Indexed I/O, one 8-bit index port, one 8-bit data port",
+                0x01 => "Indexed I/O, two 8-bit index ports, one 8-bit data port",
+                0x02 => "Indexed I/O, one 16-bit index port, one 8-bit data port",
+                0x03 => "Memory-mapped physical 32-bit address",
+                0x04 => "General-purpose non-volatile data functions",
+                0x80..=0xFF => "OEM-specific",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
+        })
+    }
+
+    pub fn access_method_address_str(&self) -> Option<String> {
+        let method = self.access_method()?;
+        self.access_method_address().map(|a| match method {
+            // The index port is in the low word and the data port in the high word.
+            0x00..=0x02 => format!(
+                "I/O port index 0x{:04X}, data 0x{:04X}",
+                a & 0xFFFF,
+                a >> 16
+            ),
+            0x03 => format!("Memory-mapped 0x{:08X}", a),
+            0x04 => format!("GPNV handle 0x{:04X}", a & 0xFFFF),
+            _ => format!("0x{:08X}", a),
+        })
+    }
+}
+
 #[derive(SMBIOS)]
+#[smbios(min_length(v2_1 = 0x0F, v2_7 = 0x17))]
 pub struct PhysicalMemoryArray {
     table_ty: u8,
     length: u8,
@@ -1754,192 +3464,340 @@ pub struct PhysicalMemoryArray {
 }
 
 impl PhysicalMemoryArray {
-    pub fn location_str(&self) -> Option<&'static str> {
-        self.location.map(|l| match l {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "System board or motherboard",
-            0x04 => "ISA add-on card",
-            0x05 => "EISA add-on card",
-            0x06 => "PCI add-on card",
-            0x07 => "MCA add-on card",
-            0x08 => "PCMCIA add-on card",
-            0x09 => "Proprietary add-on card",
-            0x0A => "NuBus",
-            0xA0 => "PC-98/C20 add-on card",
-            0xA1 => "PC-98/C24 add-on card",
-            0xA2 => "PC-98/E add-on card",
-            0xA3 => "PC-98/Local bus add-on card",
-            0xA4 => "CXL add-on card",
-            _ => unreachable!(),
+    pub fn location_str(&self) -> Option<String> {
+        self.location.map(|l| {
+            match l {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "System board or motherboard",
+                0x04 => "ISA add-on card",
+                0x05 => "EISA add-on card",
+                0x06 => "PCI add-on card",
+                0x07 => "MCA add-on card",
+                0x08 => "PCMCIA add-on card",
+                0x09 => "Proprietary add-on card",
+                0x0A => "NuBus",
+                0xA0 => "PC-98/C20 add-on card",
+                0xA1 => "PC-98/C24 add-on card",
+                0xA2 => "PC-98/E add-on card",
+                0xA3 => "PC-98/Local bus add-on card",
+                0xA4 => "CXL add-on card",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 
-    pub fn array_use_str(&self) -> Option<&'static str> {
-        self.array_use().map(|u| match u {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "System memory",
-            0x04 => "Video memory",
-            0x05 => "Flash memory",
-            0x06 => "Non-volatile RAM",
-            0x07 => "Cache memory",
-            _ => unreachable!(),
+    pub fn array_use_str(&self) -> Option<String> {
+        self.array_use().map(|u| {
+            match u {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "System memory",
+                0x04 => "Video memory",
+                0x05 => "Flash memory",
+                0x06 => "Non-volatile RAM",
+                0x07 => "Cache memory",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 
-    pub fn memory_error_correction_str(&self) -> Option<&'static str> {
-        self.memory_error_correction().map(|e| match e {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "None",
-            0x04 => "Parity",
-            0x05 => "Single-bit ECC",
-            0x06 => "Multi-bit ECC",
-            0x07 => "CRC",
-            _ => unreachable!(),
+    pub fn memory_error_correction_str(&self) -> Option<String> {
+        self.memory_error_correction().map(|e| {
+            match e {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "None",
+                0x04 => "Parity",
+                0x05 => "Single-bit ECC",
+                0x06 => "Multi-bit ECC",
+                0x07 => "CRC",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
+
+    pub fn error_information_handle_str(&self) -> Option<String> {
+        self.memory_error_information_handle()
+            .map(get_error_handle_str)
+    }
 }
 
 #[derive(SMBIOS)]
+#[smbios(min_length(
+    v2_1 = 0x15,
+    v2_3 = 0x1B,
+    v2_6 = 0x1C,
+    v2_7 = 0x22,
+    v2_8 = 0x28,
+    v3_2 = 0x54,
+    v3_3 = 0x5C,
+))]
 pub struct MemoryDevice {
     table_ty: u8,
     length: u8,
     handle: u16,
+    #[smbios(handle_to = "PhysicalMemoryArray")]
     physical_memory_array_handle: Option<u16>,
+    #[smbios(handle_to = "B32MemoryError")]
     memory_error_information_handle: Option<u16>,
     total_width: Option<u16>,
     data_width: Option<u16>,
     size: Option<u16>,
-    form_factor: Option<u8>,
+    #[smbios(enum)]
+    form_factor: Option<MemoryFormFactor>,
     device_set: Option<u8>,
-    device_locator: Option<String>,
-    bank_locator: Option<String>,
-    memory_ty: Option<u8>,
+    device_locator: Option<SmbiosString>,
+    bank_locator: Option<SmbiosString>,
+    #[smbios(enum)]
+    memory_ty: Option<MemoryType>,
+    #[smbios(bitflags(
+        "Reserved",
+        "Other",
+        "Unknown",
+        "Fast-paged",
+        "Static column",
+        "Pseudo-static",
+        "RAMBUS",
+        "Synchronous",
+        "CMOS",
+        "EDO",
+        "Window DRAM",
+        "Cache DRAM",
+        "Non-volatile",
+        "Registered",
+        "Unbuffered",
+        "LRDIMM",
+    ))]
     ty_detail: Option<u16>,
+    #[smbios(min_version = "2.3")]
     speed: Option<u16>,
-    manufacturer: Option<String>,
-    serial_number: Option<String>,
-    asset_tag: Option<String>,
-    part_number: Option<String>,
+    #[smbios(min_version = "2.3")]
+    manufacturer: Option<SmbiosString>,
+    #[smbios(min_version = "2.3")]
+    serial_number: Option<SmbiosString>,
+    #[smbios(min_version = "2.3")]
+    asset_tag: Option<SmbiosString>,
+    #[smbios(min_version = "2.3")]
+    part_number: Option<SmbiosString>,
+    #[smbios(min_version = "2.6")]
     attributes: Option<u8>,
+    #[smbios(min_version = "2.7")]
     extended_size: Option<u32>,
+    #[smbios(min_version = "2.7")]
     configured_memory_speed: Option<u16>,
+    #[smbios(min_version = "2.8")]
     minimum_voltage: Option<u16>,
+    #[smbios(min_version = "2.8")]
     maximum_voltage: Option<u16>,
+    #[smbios(min_version = "2.8")]
     configured_voltage: Option<u16>,
+    #[smbios(min_version = "3.2")]
     memory_technology: Option<u8>,
+    #[smbios(min_version = "3.2")]
     memory_operating_mode_capability: Option<u16>,
-    firmware_version: Option<String>,
+    #[smbios(min_version = "3.2")]
+    firmware_version: Option<SmbiosString>,
+    #[smbios(min_version = "3.2")]
     module_manufacturer_id: Option<u16>,
+    #[smbios(min_version = "3.2")]
     module_product_id: Option<u16>,
+    #[smbios(min_version = "3.2")]
     memory_subsystem_ctrl_manufacturer_id: Option<u16>,
+    #[smbios(min_version = "3.2")]
     memory_subsystem_ctrl_product_id: Option<u16>,
+    #[smbios(min_version = "3.2")]
     non_volatile_size: Option<u64>,
+    #[smbios(min_version = "3.2")]
     volatile_size: Option<u64>,
+    #[smbios(min_version = "3.2")]
     cache_size: Option<u64>,
+    #[smbios(min_version = "3.2")]
     logical_size: Option<u64>,
+    #[smbios(min_version = "3.3")]
     extended_speed: Option<u32>,
+    #[smbios(min_version = "3.3")]
     extended_configured_memory_speed: Option<u32>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, SmbiosEnum)]
+pub enum MemoryFormFactor {
+    #[smbios(value = 0x01)]
+    Other,
+    #[smbios(value = 0x02, name = "Unknown")]
+    UnknownType,
+    #[smbios(value = 0x03, name = "SIMM")]
+    Simm,
+    #[smbios(value = 0x04, name = "SIP")]
+    Sip,
+    #[smbios(value = 0x05)]
+    Chip,
+    #[smbios(value = 0x06, name = "DIP")]
+    Dip,
+    #[smbios(value = 0x07, name = "ZIP")]
+    Zip,
+    #[smbios(value = 0x08, name = "Proprietary Card")]
+    ProprietaryCard,
+    #[smbios(value = 0x09, name = "DIMM")]
+    Dimm,
+    #[smbios(value = 0x0A, name = "TSOP")]
+    Tsop,
+    #[smbios(value = 0x0B, name = "Row of chips")]
+    RowOfChips,
+    #[smbios(value = 0x0C, name = "RIMM")]
+    Rimm,
+    #[smbios(value = 0x0D, name = "SODIMM")]
+    Sodimm,
+    #[smbios(value = 0x0E, name = "SRIMM")]
+    Srimm,
+    #[smbios(value = 0x0F, name = "FB-DIMM")]
+    FbDimm,
+    #[smbios(value = 0x10)]
+    Die,
+    #[smbios(value = 0x11, name = "CAMM")]
+    Camm,
+    Unknown(u64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, SmbiosEnum)]
+pub enum MemoryType {
+    #[smbios(value = 0x01)]
+    Other,
+    #[smbios(value = 0x02, name = "Unknown")]
+    UnknownType,
+    #[smbios(value = 0x03, name = "DRAM")]
+    Dram,
+    #[smbios(value = 0x04, name = "EDRAM")]
+    Edram,
+    #[smbios(value = 0x05, name = "VRAM")]
+    Vram,
+    #[smbios(value = 0x06, name = "SRAM")]
+    Sram,
+    #[smbios(value = 0x07, name = "RAM")]
+    Ram,
+    #[smbios(value = 0x08, name = "ROM")]
+    Rom,
+    #[smbios(value = 0x09, name = "FLASH")]
+    Flash,
+    #[smbios(value = 0x0A, name = "EEPROM")]
+    Eeprom,
+    #[smbios(value = 0x0B, name = "FEPROM")]
+    Feprom,
+    #[smbios(value = 0x0C, name = "EPROM")]
+    Eprom,
+    #[smbios(value = 0x0D, name = "CDRAM")]
+    Cdram,
+    #[smbios(value = 0x0E, name = "3DRAM")]
+    Dram3D,
+    #[smbios(value = 0x0F, name = "SDRAM")]
+    Sdram,
+    #[smbios(value = 0x10, name = "SGRAM")]
+    Sgram,
+    #[smbios(value = 0x11, name = "RDRAM")]
+    Rdram,
+    #[smbios(value = 0x12, name = "DDR")]
+    Ddr,
+    #[smbios(value = 0x13, name = "DDR2")]
+    Ddr2,
+    #[smbios(value = 0x14, name = "DDR2 FB-DIMM")]
+    Ddr2FbDimm,
+    #[smbios(value = 0x18, name = "DDR3")]
+    Ddr3,
+    #[smbios(value = 0x19, name = "FBD2")]
+    Fbd2,
+    #[smbios(value = 0x1A, name = "DDR4")]
+    Ddr4,
+    #[smbios(value = 0x1B, name = "LPDDR")]
+    Lpddr,
+    #[smbios(value = 0x1C, name = "LPDDR2")]
+    Lpddr2,
+    #[smbios(value = 0x1D, name = "LPDDR3")]
+    Lpddr3,
+    #[smbios(value = 0x1E, name = "LPDDR4")]
+    Lpddr4,
+    #[smbios(value = 0x1F, name = "Logical non-volatile device")]
+    LogicalNonVolatileDevice,
+    #[smbios(value = 0x20, name = "HBM")]
+    Hbm,
+    #[smbios(value = 0x21, name = "HBM2")]
+    Hbm2,
+    #[smbios(value = 0x22, name = "DDR5")]
+    Ddr5,
+    #[smbios(value = 0x23, name = "LPDDR5")]
+    Lpddr5,
+    #[smbios(value = 0x24, name = "HBM3")]
+    Hbm3,
+    Unknown(u64),
+}
+
 impl MemoryDevice {
-    pub fn form_factor_str(&self) -> Option<&'static str> {
-        self.form_factor().map(|f| match f {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "SIMM",
-            0x04 => "SIP",
-            0x05 => "Chip",
-            0x06 => "DIP",
-            0x07 => "ZIP",
-            0x08 => "Proprietary Card",
-            0x09 => "DIMM",
-            0x0A => "TSOP",
-            0x0B => "Row of chips",
-            0x0C => "RIMM",
-            0x0D => "SODIMM",
-            0x0E => "SRIMM",
-            0x0F => "FB-DIMM",
-            0x10 => "Die",
-            _ => unreachable!(),
-        })
+    pub const fn speed_note() -> &'static str {
+        "maximum rated speed of the module, not the configured speed"
     }
 
-    pub fn memory_ty_str(&self) -> Option<&'static str> {
-        self.memory_ty().map(|t| match t {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "DRAM",
-            0x04 => "EDRAM",
-            0x05 => "VRAM",
-            0x06 => "SRAM",
-            0x07 => "RAM",
-            0x08 => "ROM",
-            0x09 => "FLASH",
-            0x0A => "EEPROM",
-            0x0B => "FEPROM",
-            0x0C => "EPROM",
-            0x0D => "CDRAM",
-            0x0E => "3DRAM",
-            0x0F => "SDRAM",
-            0x10 => "SGRAM",
-            0x11 => "RDRAM",
-            0x12 => "DDR",
-            0x13 => "DDR2",
-            0x14 => "DDR2 FB-DIMM",
-            0x18 => "DDR3",
-            0x19 => "FBD2",
-            0x1A => "DDR4",
-            0x1B => "LPDDR",
-            0x1C => "LPDDR2",
-            0x1D => "LPDDR3",
-            0x1E => "LPDDR4",
-            0x1F => "Logical non-volatile device",
-            0x20 => "HBM",
-            0x21 => "HBM2",
-            0x22 => "DDR5",
-            0x23 => "LPDDR5",
-            0x24 => "HBM3",
-            _ => unreachable!(),
-        })
+    // A size of 0 is "No Module Installed"; an unknown size still has a module.
+    pub fn is_populated(&self) -> Option<bool> {
+        self.size().map(|s| s != 0)
     }
 
-    pub fn ty_detail_str(&self) -> Option<Vec<String>> {
-        let details = vec![
-            "Reserved",
-            "Other",
-            "Unknown",
-            "Fast-paged",
-            "Static column",
-            "Pseudo-static",
-            "RAMBUS",
-            "Synchronous",
-            "CMOS",
-            "EDO",
-            "Window DRAM",
-            "Cache DRAM",
-            "Non-volatile",
-            "Registered",
-            "Unbuffered",
-            "LRDIMM",
-        ];
+    // 0 means no module is installed; 0xFFFF means the size is unknown.
+    pub fn size_bytes(&self) -> Option<u64> {
+        match self.size()? {
+            0xFFFF => None,
+            0x7FFF => self
+                .extended_size()
+                .map(|s| u64::from(s & 0x7FFF_FFFF) << 20),
+            s if (s & 0x8000) != 0 => Some(u64::from(s & 0x7FFF) << 10),
+            s => Some(u64::from(s) << 20),
+        }
+    }
 
-        self.ty_detail()
-            .map(|v| get_flag_strings(v as u64, &details))
+    pub fn speed_str(&self) -> Option<String> {
+        self.speed()
+            .map(|s| get_memory_speed_str(s, self.extended_speed()))
     }
 
-    pub fn memory_technology_str(&self) -> Option<&'static str> {
-        self.memory_technology().map(|t| match t {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "DRAM",
-            0x04 => "NVDIMM-N",
-            0x05 => "NVDIMM-F",
-            0x06 => "NVDIMM-P",
-            0x07 => "Intel Optane",
-            _ => unreachable!(),
+    pub fn has_ecc(&self) -> Option<bool> {
+        match (self.total_width()?, self.data_width()?) {
+            (0xFFFF, _) | (_, 0xFFFF) => None,
+            (total, data) => Some(total > data),
+        }
+    }
+
+    pub fn configured_memory_speed_str(&self) -> Option<String> {
+        self.configured_memory_speed()
+            .map(|s| get_memory_speed_str(s, self.extended_configured_memory_speed()))
+    }
+
+    pub fn error_information_handle_str(&self) -> Option<String> {
+        self.memory_error_information_handle()
+            .map(get_error_handle_str)
+    }
+
+    pub fn form_factor_str(&self) -> Option<String> {
+        self.form_factor().map(|f| f.to_string())
+    }
+
+    pub fn memory_ty_str(&self) -> Option<String> {
+        self.memory_ty().map(|t| t.to_string())
+    }
+
+    pub fn memory_technology_str(&self) -> Option<String> {
+        self.memory_technology().map(|t| {
+            match t {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "DRAM",
+                0x04 => "NVDIMM-N",
+                0x05 => "NVDIMM-F",
+                0x06 => "NVDIMM-P",
+                0x07 => "Intel Optane",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 
@@ -1953,7 +3811,8 @@ impl MemoryDevice {
             "Block-accessible persistent memory",
         ];
 
-        self.ty_detail().map(|v| get_flag_strings(v as u64, &caps))
+        self.memory_operating_mode_capability()
+            .map(|v| get_flag_strings(v as u64, &caps))
     }
 }
 
@@ -1972,44 +3831,53 @@ pub struct B32MemoryError {
 }
 
 impl B32MemoryError {
-    pub fn error_ty_str(&self) -> Option<&'static str> {
-        self.error_ty().map(|t| match t {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "OK",
-            0x04 => "Bad read",
-            0x05 => "Parity error",
-            0x06 => "Single-bit error",
-            0x07 => "Double-bit error",
-            0x08 => "Multi-bit error",
-            0x09 => "Nibble error",
-            0x0A => "Checksum error",
-            0x0B => "CRC error",
-            0x0C => "Corrected single-bit error",
-            0x0D => "Corrected error",
-            0x0E => "Uncorrectable error",
-            _ => unreachable!(),
+    pub fn error_ty_str(&self) -> Option<String> {
+        self.error_ty().map(|t| {
+            match t {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "OK",
+                0x04 => "Bad read",
+                0x05 => "Parity error",
+                0x06 => "Single-bit error",
+                0x07 => "Double-bit error",
+                0x08 => "Multi-bit error",
+                0x09 => "Nibble error",
+                0x0A => "Checksum error",
+                0x0B => "CRC error",
+                0x0C => "Corrected single-bit error",
+                0x0D => "Corrected error",
+                0x0E => "Uncorrectable error",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 
-    pub fn error_granularity_str(&self) -> Option<&'static str> {
-        self.error_granularity().map(|t| match t {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "Device level",
-            0x04 => "Memory partition level",
-            _ => unreachable!(),
+    pub fn error_granularity_str(&self) -> Option<String> {
+        self.error_granularity().map(|t| {
+            match t {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "Device level",
+                0x04 => "Memory partition level",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 
-    pub fn error_operation_str(&self) -> Option<&'static str> {
-        self.error_operation().map(|t| match t {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "Read",
-            0x04 => "Write",
-            0x05 => "Partial write",
-            _ => unreachable!(),
+    pub fn error_operation_str(&self) -> Option<String> {
+        self.error_operation().map(|t| {
+            match t {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "Read",
+                0x04 => "Write",
+                0x05 => "Partial write",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 }
@@ -2034,7 +3902,9 @@ pub struct MemoryDeviceMappedAddress {
     handle: u16,
     starting_address: Option<u32>,
     ending_address: Option<u32>,
+    #[smbios(handle_to = "MemoryDevice")]
     memory_device_handle: Option<u16>,
+    #[smbios(handle_to = "MemoryArrayMappedAddress")]
     memory_array_mapped_address_handle: Option<u16>,
     partition_row_position: Option<u8>,
     interleave_position: Option<u8>,
@@ -2058,23 +3928,62 @@ pub struct PortableBattery {
     table_ty: u8,
     length: u8,
     handle: u16,
-    location: Option<String>,
-    manufacturer: Option<String>,
-    manufacturer_date: Option<String>,
-    serial_number: Option<String>,
-    device_name: Option<String>,
+    location: Option<SmbiosString>,
+    manufacturer: Option<SmbiosString>,
+    manufacturer_date: Option<SmbiosString>,
+    serial_number: Option<SmbiosString>,
+    device_name: Option<SmbiosString>,
     device_chemistry: Option<u8>,
     design_capacity: Option<u16>,
     design_voltage: Option<u16>,
-    sbds_version_number: Option<String>,
+    sbds_version_number: Option<SmbiosString>,
     maximum_error_in_battery_data: Option<u8>,
     sbds_serial_number: Option<u16>,
     sbds_manufacturer_date: Option<u16>,
-    sbds_device_chemistry: Option<String>,
+    sbds_device_chemistry: Option<SmbiosString>,
     design_capacity_multiplier: Option<u8>,
     oem_specific: Option<u32>,
 }
 
+impl PortableBattery {
+    pub fn manufacturer_date_parsed(&self) -> Option<(u16, u8, u8)> {
+        if let Some(date) = self.manufacturer_date() {
+            return parse_date_str(date);
+        }
+
+        self.sbds_manufacturer_date_parsed()
+    }
+
+    pub fn sbds_manufacturer_date_parsed(&self) -> Option<(u16, u8, u8)> {
+        // Bits 15:9 year since 1980, 8:5 month, 4:0 day.
+        self.sbds_manufacturer_date()
+            .map(|d| (1980 + (d >> 9), ((d >> 5) & 0x0F) as u8, (d & 0x1F) as u8))
+    }
+
+    pub fn device_chemistry_str(&self) -> Option<String> {
+        self.device_chemistry().map(|c| {
+            match c {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "Lead Acid",
+                0x04 => "Nickel Cadmium",
+                0x05 => "Nickel metal hydride",
+                0x06 => "Lithium-ion",
+                0x07 => "Zinc air",
+                0x08 => "Lithium Polymer",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
+        })
+    }
+
+    // The multiplier is absent before SMBIOS 2.2 and means 1 there.
+    pub fn design_capacity_mwh(&self) -> Option<u32> {
+        let multiplier = self.design_capacity_multiplier().unwrap_or(1).max(1);
+        self.design_capacity().map(|c| c as u32 * multiplier as u32)
+    }
+}
+
 #[derive(SMBIOS)]
 pub struct SystemReset {
     table_ty: u8,
@@ -2092,12 +4001,12 @@ impl SystemReset {
         self.capabilities().map(|cap| (cap & 0x01) == 0x01)
     }
 
-    pub fn boot_option(&self) -> Option<&'static str> {
+    pub fn boot_option(&self) -> Option<String> {
         self.capabilities()
             .map(|cap| self.get_boot_option(cap >> 1))
     }
 
-    pub fn boot_option_on_limit(&self) -> Option<&'static str> {
+    pub fn boot_option_on_limit(&self) -> Option<String> {
         self.capabilities()
             .map(|cap| self.get_boot_option(cap >> 3))
     }
@@ -2106,13 +4015,14 @@ impl SystemReset {
         self.capabilities().map(|cap| (cap & 0x20) == 0x20)
     }
 
-    fn get_boot_option(&self, value: u8) -> &'static str {
+    fn get_boot_option(&self, value: u8) -> String {
         match value & 0x03 {
             0x01 => "Operating system",
             0x02 => "System utilities",
             0x03 => "Do not reboot",
-            _ => unreachable!(),
+            v => return format!("Unknown (0x{:02X})", v),
         }
+        .to_string()
     }
 }
 
@@ -2141,45 +4051,51 @@ pub struct VoltageProbe {
     table_ty: u8,
     length: u8,
     handle: u16,
-    description: Option<String>,
+    description: Option<SmbiosString>,
+    #[smbios(packed("location_str", "status_str"))]
     location_and_status: Option<u8>,
-    maximum_value: Option<u16>,
-    minimum_value: Option<u16>,
+    maximum_value: Option<i16>,
+    minimum_value: Option<i16>,
     resolution: Option<u16>,
-    tolerance: Option<u16>,
+    tolerance: Option<i16>,
     accuracy: Option<u16>,
     oem_defined: Option<u32>,
-    nominal_value: Option<u16>,
+    nominal_value: Option<i16>,
 }
 
 impl VoltageProbe {
-    pub fn location_str(&self) -> Option<&'static str> {
-        self.location_and_status().map(|l| match l & 0x1F {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "Processor",
-            0x04 => "Disk",
-            0x05 => "Peripheral Bay",
-            0x06 => "System Management Module",
-            0x07 => "Motherboard",
-            0x08 => "Memory Module",
-            0x09 => "Processor Module",
-            0x0A => "Power Unit",
-            0x0B => "Add-in Card",
-            _ => unreachable!(),
-        })
+    pub fn maximum_value_str(&self) -> Option<String> {
+        get_signed_probe_value_str(self.maximum_value(), 1000, 3, " V")
     }
 
-    pub fn status_str(&self) -> Option<&'static str> {
-        self.location_and_status().map(|s| match s >> 5 {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "OK",
-            0x04 => "Non-critical",
-            0x05 => "Critical",
-            0x06 => "Non-recoverable",
-            _ => unreachable!(),
-        })
+    pub fn minimum_value_str(&self) -> Option<String> {
+        get_signed_probe_value_str(self.minimum_value(), 1000, 3, " V")
+    }
+
+    pub fn resolution_str(&self) -> Option<String> {
+        get_probe_value_str(self.resolution(), 10, 1, " mV")
+    }
+
+    pub fn tolerance_str(&self) -> Option<String> {
+        get_signed_probe_value_str(self.tolerance(), 1000, 3, " V")
+    }
+
+    pub fn accuracy_str(&self) -> Option<String> {
+        get_probe_value_str(self.accuracy(), 100, 2, "%")
+    }
+
+    pub fn nominal_value_str(&self) -> Option<String> {
+        get_signed_probe_value_str(self.nominal_value(), 1000, 3, " V")
+    }
+
+    pub fn location_str(&self) -> Option<String> {
+        self.location_and_status()
+            .map(|l| get_probe_location_str(l & 0x1F, false))
+    }
+
+    pub fn status_str(&self) -> Option<String> {
+        self.location_and_status()
+            .map(|s| get_probe_status_str(s >> 5))
     }
 }
 
@@ -2188,41 +4104,46 @@ pub struct CoolingDevice {
     table_ty: u8,
     length: u8,
     handle: u16,
+    #[smbios(handle_to = "TemperatureProbe")]
     temperature_probe_handle: Option<u16>,
+    #[smbios(packed("device_ty_str", "status_str"))]
     device_ty_and_status: Option<u8>,
     cooling_unit_group: Option<u8>,
     oem_defined: Option<u32>,
     nominal_speed: Option<u16>,
-    description: Option<String>,
+    description: Option<SmbiosString>,
 }
 
 impl CoolingDevice {
-    pub fn device_ty_str(&self) -> Option<&'static str> {
-        self.device_ty_and_status().map(|t| match t & 0x1F {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "Fan",
-            0x04 => "Centrifugal Blower",
-            0x05 => "Chip Fan",
-            0x06 => "Cabinet Fan",
-            0x07 => "Power Supply Fan",
-            0x08 => "Heat Pipe",
-            0x09 => "Integrated Refrigeration",
-            0x0A => "Active Cooling",
-            0x0B => "Passive Cooling",
-            _ => unreachable!(),
+    pub fn device_ty_str(&self) -> Option<String> {
+        self.device_ty_and_status().map(|t| {
+            match t & 0x1F {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "Fan",
+                0x04 => "Centrifugal Blower",
+                0x05 => "Chip Fan",
+                0x06 => "Cabinet Fan",
+                0x07 => "Power Supply Fan",
+                0x08 => "Heat Pipe",
+                0x09 => "Integrated Refrigeration",
+                0x0A => "Active Cooling",
+                0x0B => "Passive Cooling",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 
-    pub fn status_str(&self) -> Option<&'static str> {
-        self.device_ty_and_status().map(|s| match s >> 5 {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "OK",
-            0x04 => "Non-critical",
-            0x05 => "Critical",
-            0x06 => "Non-recoverable",
-            _ => unreachable!(),
+    pub fn status_str(&self) -> Option<String> {
+        self.device_ty_and_status()
+            .map(|s| get_probe_status_str(s >> 5))
+    }
+
+    pub fn nominal_speed_str(&self) -> Option<String> {
+        self.nominal_speed().map(|s| match s {
+            0x8000 => "Unknown".to_string(),
+            _ => format!("{} rpm", s),
         })
     }
 }
@@ -2232,49 +4153,51 @@ pub struct TemperatureProbe {
     table_ty: u8,
     length: u8,
     handle: u16,
-    description: Option<String>,
+    description: Option<SmbiosString>,
+    #[smbios(packed("location_str", "status_str"))]
     location_and_status: Option<u8>,
-    maximum_value: Option<u16>,
-    minimum_value: Option<u16>,
+    maximum_value: Option<i16>,
+    minimum_value: Option<i16>,
     resolution: Option<u16>,
-    tolerance: Option<u16>,
+    tolerance: Option<i16>,
     accuracy: Option<u16>,
     oem_defined: Option<u32>,
-    nominal_value: Option<u16>,
+    nominal_value: Option<i16>,
 }
 
 impl TemperatureProbe {
-    pub fn location_str(&self) -> Option<&'static str> {
-        self.location_and_status().map(|l| match l & 0x1F {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "Processor",
-            0x04 => "Disk",
-            0x05 => "Peripheral Bay",
-            0x06 => "System Management Module",
-            0x07 => "Motherboard",
-            0x08 => "Memory Module",
-            0x09 => "Processor Module",
-            0x0A => "Power Unit",
-            0x0B => "Add-in Card",
-            0x0C => "Front Panel Board",
-            0x0D => "Back Panel Board",
-            0x0E => "Power System Board",
-            0x0F => "Drive Back Plane",
-            _ => unreachable!(),
-        })
+    pub fn maximum_value_str(&self) -> Option<String> {
+        get_signed_probe_value_str(self.maximum_value(), 10, 1, " deg C")
     }
 
-    pub fn status_str(&self) -> Option<&'static str> {
-        self.location_and_status().map(|s| match s >> 5 {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "OK",
-            0x04 => "Non-critical",
-            0x05 => "Critical",
-            0x06 => "Non-recoverable",
-            _ => unreachable!(),
-        })
+    pub fn minimum_value_str(&self) -> Option<String> {
+        get_signed_probe_value_str(self.minimum_value(), 10, 1, " deg C")
+    }
+
+    pub fn resolution_str(&self) -> Option<String> {
+        get_probe_value_str(self.resolution(), 1000, 3, " deg C")
+    }
+
+    pub fn tolerance_str(&self) -> Option<String> {
+        get_signed_probe_value_str(self.tolerance(), 10, 1, " deg C")
+    }
+
+    pub fn accuracy_str(&self) -> Option<String> {
+        get_probe_value_str(self.accuracy(), 100, 2, "%")
+    }
+
+    pub fn nominal_value_str(&self) -> Option<String> {
+        get_signed_probe_value_str(self.nominal_value(), 10, 1, " deg C")
+    }
+
+    pub fn location_str(&self) -> Option<String> {
+        self.location_and_status()
+            .map(|l| get_probe_location_str(l & 0x1F, true))
+    }
+
+    pub fn status_str(&self) -> Option<String> {
+        self.location_and_status()
+            .map(|s| get_probe_status_str(s >> 5))
     }
 }
 
@@ -2283,45 +4206,51 @@ pub struct ElectricalCurrentProbe {
     table_ty: u8,
     length: u8,
     handle: u16,
-    description: Option<String>,
+    description: Option<SmbiosString>,
+    #[smbios(packed("location_str", "status_str"))]
     location_and_status: Option<u8>,
-    maximum_value: Option<u16>,
-    minimum_value: Option<u16>,
+    maximum_value: Option<i16>,
+    minimum_value: Option<i16>,
     resolution: Option<u16>,
-    tolerance: Option<u16>,
+    tolerance: Option<i16>,
     accuracy: Option<u16>,
     oem_defined: Option<u32>,
-    nominal_value: Option<u16>,
+    nominal_value: Option<i16>,
 }
 
 impl ElectricalCurrentProbe {
-    pub fn location_str(&self) -> Option<&'static str> {
-        self.location_and_status().map(|l| match l & 0x1F {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "Processor",
-            0x04 => "Disk",
-            0x05 => "Peripheral Bay",
-            0x06 => "System Management Module",
-            0x07 => "Motherboard",
-            0x08 => "Memory Module",
-            0x09 => "Processor Module",
-            0x0A => "Power Unit",
-            0x0B => "Add-in Card",
-            _ => unreachable!(),
-        })
+    pub fn maximum_value_str(&self) -> Option<String> {
+        get_signed_probe_value_str(self.maximum_value(), 1000, 3, " A")
     }
 
-    pub fn status_str(&self) -> Option<&'static str> {
-        self.location_and_status().map(|s| match s >> 5 {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "OK",
-            0x04 => "Non-critical",
-            0x05 => "Critical",
-            0x06 => "Non-recoverable",
-            _ => unreachable!(),
-        })
+    pub fn minimum_value_str(&self) -> Option<String> {
+        get_signed_probe_value_str(self.minimum_value(), 1000, 3, " A")
+    }
+
+    pub fn resolution_str(&self) -> Option<String> {
+        get_probe_value_str(self.resolution(), 10, 1, " mA")
+    }
+
+    pub fn tolerance_str(&self) -> Option<String> {
+        get_signed_probe_value_str(self.tolerance(), 1000, 3, " A")
+    }
+
+    pub fn accuracy_str(&self) -> Option<String> {
+        get_probe_value_str(self.accuracy(), 100, 2, "%")
+    }
+
+    pub fn nominal_value_str(&self) -> Option<String> {
+        get_signed_probe_value_str(self.nominal_value(), 1000, 3, " A")
+    }
+
+    pub fn location_str(&self) -> Option<String> {
+        self.location_and_status()
+            .map(|l| get_probe_location_str(l & 0x1F, false))
+    }
+
+    pub fn status_str(&self) -> Option<String> {
+        self.location_and_status()
+            .map(|s| get_probe_status_str(s >> 5))
     }
 }
 
@@ -2330,7 +4259,7 @@ pub struct OutOfBandRemoteAccess {
     table_ty: u8,
     length: u8,
     handle: u16,
-    manufacturer_name: Option<String>,
+    manufacturer_name: Option<SmbiosString>,
     connections: Option<u8>,
 }
 
@@ -2339,26 +4268,28 @@ pub struct SystemBoot {
     table_ty: u8,
     length: u8,
     handle: u16,
-    reserved: Option<[u8; 6]>,
-    #[smbios(length = "Some(length - 10)")]
+    #[smbios(skip = 6, remaining)]
     boot_status: Option<Vec<u8>>,
 }
 
 impl SystemBoot {
-    pub fn boot_status_str(&self) -> Option<&'static str> {
-        self.boot_status().map(|s| match s[0] {
-            0x00 => "No errors detected",
-            0x01 => "No bootable media",
-            0x02 => "Operating system failed to load",
-            0x03 => "Firmware-detected hardware failure,",
-            0x04 => "Operating system-detected hardware failure",
-            0x05 => "User-requested boot",
-            0x06 => "System security violation",
-            0x07 => "Previously requested image",
-            0x08 => "System watchdog timer expired",
-            0x80..=0xBF => "Vendor/OEM-specific implementations",
-            0xC0..=0xFF => "Product-specific implementations",
-            _ => unreachable!(),
+    pub fn boot_status_str(&self) -> Option<String> {
+        self.boot_status().and_then(|s| s.first()).map(|s| {
+            match s {
+                0x00 => "No errors detected",
+                0x01 => "No bootable media",
+                0x02 => "Operating system failed to load",
+                0x03 => "Firmware-detected hardware failure,",
+                0x04 => "Operating system-detected hardware failure",
+                0x05 => "User-requested boot",
+                0x06 => "System security violation",
+                0x07 => "Previously requested image",
+                0x08 => "System watchdog timer expired",
+                0x80..=0xBF => "Vendor/OEM-specific implementations",
+                0xC0..=0xFF => "Product-specific implementations",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 }
@@ -2382,40 +4313,46 @@ pub struct ManagementDevice {
     table_ty: u8,
     length: u8,
     handle: u16,
-    description: Option<String>,
+    description: Option<SmbiosString>,
     ty: Option<u8>,
     address: Option<u32>,
     address_ty: Option<u8>,
 }
 
 impl ManagementDevice {
-    pub fn ty_str(&self) -> Option<&'static str> {
-        self.ty().map(|s| match s {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "National Semiconductor LM75",
-            0x04 => "National Semiconductor LM78",
-            0x05 => "National Semiconductor LM79",
-            0x06 => "National Semiconductor LM80",
-            0x07 => "National Semiconductor LM81",
-            0x08 => "Analog Devices ADM9240",
-            0x09 => "Dallas Semiconductor DS1780",
-            0x0A => "Maxim 1617",
-            0x0B => "Genesys GL518SM",
-            0x0C => "Winbond W83781D",
-            0x0D => "Holtek HT82H791",
-            _ => unreachable!(),
+    pub fn ty_str(&self) -> Option<String> {
+        self.ty().map(|s| {
+            match s {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "National Semiconductor LM75",
+                0x04 => "National Semiconductor LM78",
+                0x05 => "National Semiconductor LM79",
+                0x06 => "National Semiconductor LM80",
+                0x07 => "National Semiconductor LM81",
+                0x08 => "Analog Devices ADM9240",
+                0x09 => "Dallas Semiconductor DS1780",
+                0x0A => "Maxim 1617",
+                0x0B => "Genesys GL518SM",
+                0x0C => "Winbond W83781D",
+                0x0D => "Holtek HT82H791",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 
-    pub fn address_ty_str(&self) -> Option<&'static str> {
-        self.ty().map(|s| match s {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "I/O Port",
-            0x04 => "Memory",
-            0x05 => "SM Bus",
-            _ => unreachable!(),
+    pub fn address_ty_str(&self) -> Option<String> {
+        self.address_ty().map(|s| {
+            match s {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "I/O Port",
+                0x04 => "Memory",
+                0x05 => "SM Bus",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 }
@@ -2425,9 +4362,11 @@ pub struct ManagementDeviceComponent {
     table_ty: u8,
     length: u8,
     handle: u16,
-    description: Option<String>,
+    description: Option<SmbiosString>,
+    #[smbios(handle_to = "ManagementDevice")]
     management_device_handle: Option<u16>,
     component_handle: Option<u16>,
+    #[smbios(handle_to = "ManagementDeviceThresholdData")]
     threshold_handle: Option<u16>,
 }
 
@@ -2452,12 +4391,14 @@ pub struct MemoryChannel {
     channel_ty: Option<u8>,
     maximum_channel_load: Option<u8>,
     memory_device_count: Option<u8>,
-    meory1_device_load: Option<u8>,
-    memory_device1_handle: Option<u16>,
     #[smbios(length = "memory_device_count")]
-    memory_device_load: Option<Vec<u8>>,
-    #[smbios(length = "memory_device_count")]
-    memory_device_handle: Option<Vec<u16>>,
+    memory_devices: Option<Vec<MemoryChannelDevice>>,
+}
+
+#[derive(SMBIOS)]
+pub struct MemoryChannelDevice {
+    load: Option<u8>,
+    handle: Option<u16>,
 }
 
 #[derive(SMBIOS)]
@@ -2474,27 +4415,91 @@ pub struct IpmiDevice {
     interrupt_number: Option<u8>,
 }
 
+impl IpmiDevice {
+    pub fn register_spacing_str(&self) -> Option<String> {
+        self.base_address_modifier().map(|m| {
+            match m >> 6 {
+                0b00 => "Successive Byte Boundaries",
+                0b01 => "32-bit Boundaries",
+                0b10 => "16-byte Boundaries",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
+        })
+    }
+
+    pub fn base_address_ls_bit(&self) -> Option<u8> {
+        self.base_address_modifier().map(|m| (m >> 4) & 0x01)
+    }
+
+    pub fn interrupt_info_specified(&self) -> Option<bool> {
+        self.base_address_modifier().map(|m| (m & 0x08) != 0)
+    }
+
+    // Polarity and trigger mode are only meaningful when bit 3 is set.
+    pub fn interrupt_polarity_str(&self) -> Option<&'static str> {
+        self.base_address_modifier()
+            .filter(|m| (m & 0x08) != 0)
+            .map(|m| {
+                if (m & 0x02) != 0 {
+                    "Active High"
+                } else {
+                    "Active Low"
+                }
+            })
+    }
+
+    pub fn interrupt_trigger_str(&self) -> Option<&'static str> {
+        self.base_address_modifier()
+            .filter(|m| (m & 0x08) != 0)
+            .map(|m| if (m & 0x01) != 0 { "Level" } else { "Edge" })
+    }
+}
+
 #[derive(SMBIOS)]
 pub struct SystemPowerSupply {
     table_ty: u8,
     length: u8,
     handle: u16,
     power_unit_group: Option<u8>,
-    location: Option<String>,
-    device_name: Option<String>,
-    manufacturer: Option<String>,
-    serial_number: Option<String>,
-    asset_tag_number: Option<String>,
-    model_part_number: Option<String>,
-    revision_level: Option<String>,
+    location: Option<SmbiosString>,
+    device_name: Option<SmbiosString>,
+    manufacturer: Option<SmbiosString>,
+    serial_number: Option<SmbiosString>,
+    asset_tag_number: Option<SmbiosString>,
+    model_part_number: Option<SmbiosString>,
+    revision_level: Option<SmbiosString>,
     max_power_capacity: Option<u16>,
+    #[smbios(packed(
+        "hot_replaceable",
+        "present",
+        "unplugged",
+        "range_switching_str",
+        "status_str",
+        "ty_str"
+    ))]
     power_supply_characteristics: Option<u16>,
+    #[smbios(handle_to = "VoltageProbe")]
     input_voltage_probe_handle: Option<u16>,
+    #[smbios(handle_to = "CoolingDevice")]
     cooling_device_handle: Option<u16>,
+    #[smbios(handle_to = "ElectricalCurrentProbe")]
     input_current_probe_handle: Option<u16>,
 }
 
 impl SystemPowerSupply {
+    // 0x8000 means the capacity is unknown.
+    pub fn max_power_capacity_watts(&self) -> Option<u16> {
+        self.max_power_capacity().filter(|&c| c != 0x8000)
+    }
+
+    pub fn max_power_capacity_str(&self) -> Option<String> {
+        self.max_power_capacity().map(|c| match c {
+            0x8000 => "Unknown".to_string(),
+            c => format!("{} W", c),
+        })
+    }
+
     pub fn hot_replaceable(&self) -> Option<bool> {
         self.power_supply_characteristics.map(|c| c & 0x01 != 0x00)
     }
@@ -2508,15 +4513,18 @@ impl SystemPowerSupply {
             .map(|c| ((c >> 3) & 0x0F) as u8)
     }
 
-    pub fn range_switching_str(&self) -> Option<&'static str> {
-        self.status().map(|s| match s {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "Manual",
-            0x04 => "Auto-switch",
-            0x05 => "Wide range",
-            0x06 => "Not applicable",
-            _ => unreachable!(),
+    pub fn range_switching_str(&self) -> Option<String> {
+        self.range_switching().map(|s| {
+            match s {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "Manual",
+                0x04 => "Auto-switch",
+                0x05 => "Wide range",
+                0x06 => "Not applicable",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 
@@ -2525,14 +4533,17 @@ impl SystemPowerSupply {
             .map(|c| ((c >> 7) & 0x07) as u8)
     }
 
-    pub fn status_str(&self) -> Option<&'static str> {
-        self.status().map(|s| match s {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "OK",
-            0x04 => "Non-critical",
-            0x05 => "Critical",
-            _ => unreachable!(),
+    pub fn status_str(&self) -> Option<String> {
+        self.status().map(|s| {
+            match s {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "OK",
+                0x04 => "Non-critical",
+                0x05 => "Critical",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 
@@ -2541,17 +4552,20 @@ impl SystemPowerSupply {
             .map(|c| ((c >> 10) & 0x0F) as u8)
     }
 
-    pub fn ty_str(&self) -> Option<&'static str> {
-        self.ty().map(|s| match s {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "Linear",
-            0x04 => "Switching",
-            0x05 => "Battery",
-            0x06 => "UPS",
-            0x07 => "Converter",
-            0x08 => "Regulator",
-            _ => unreachable!(),
+    pub fn ty_str(&self) -> Option<String> {
+        self.ty().map(|s| {
+            match s {
+                0x01 => "Other",
+                0x02 => "Unknown",
+                0x03 => "Linear",
+                0x04 => "Switching",
+                0x05 => "Battery",
+                0x06 => "UPS",
+                0x07 => "Converter",
+                0x08 => "Regulator",
+                v => return format!("Unknown (0x{:02X})", v),
+            }
+            .to_string()
         })
     }
 
@@ -2560,14 +4574,48 @@ impl SystemPowerSupply {
     }
 }
 
+#[derive(SMBIOS)]
+pub struct AdditionalEntry {
+    entry_length: Option<u8>,
+    referenced_handle: Option<u16>,
+    referenced_offset: Option<u8>,
+    string: Option<SmbiosString>,
+    #[smbios(length_bytes = "entry_length.map(|l| (l as usize).saturating_sub(5))")]
+    value: Option<Vec<u8>>,
+}
+
+impl AdditionalEntry {
+    pub fn value_as_u64(&self) -> Option<u64> {
+        let value = self.value()?;
+        if value.is_empty() || value.len() > 8 {
+            return None;
+        }
+
+        let mut bytes = [0; 8];
+        bytes[..value.len()].copy_from_slice(value);
+        Some(u64::from_le_bytes(bytes))
+    }
+
+    // A one-byte value that augments a string field is a string number of
+    // the type 40 structure, not of the referenced one.
+    pub fn value_as_string<'a>(&self, table: &'a Additional) -> Option<&'a str> {
+        match self.value()? {
+            [index] if *index != 0 => table.strings().get(*index as usize - 1).map(|s| s.as_str()),
+            _ => None,
+        }
+    }
+}
+
 #[derive(SMBIOS)]
 pub struct Additional {
     table_ty: u8,
     length: u8,
     handle: u16,
+    #[smbios(strings, length = "Some(raw.tailer.len())")]
+    strings: Vec<String>,
     num_additional_information_entities: Option<u8>,
     #[smbios(length = "num_additional_information_entities")]
-    additional_information_entities: Option<Vec<u8>>,
+    additional_information_entities: Option<Vec<AdditionalEntry>>,
 }
 
 #[derive(SMBIOS)]
@@ -2575,7 +4623,8 @@ pub struct OnboardDevicesExtended {
     table_ty: u8,
     length: u8,
     handle: u16,
-    reference_designation: Option<String>,
+    reference_designation: Option<SmbiosString>,
+    #[smbios(packed("device_status", "device_ty_str"))]
     device_ty: Option<u8>,
     device_ty_instance: Option<u8>,
     segment_group_number: Option<u16>,
@@ -2588,26 +4637,9 @@ impl OnboardDevicesExtended {
         self.device_ty().map(|t| (t & 0x80) == 0x80)
     }
 
-    pub fn device_ty_str(&self) -> Option<&'static str> {
-        self.device_ty().map(|t| match t & 0x3F {
-            0x01 => "Other",
-            0x02 => "Unknown",
-            0x03 => "Video",
-            0x04 => "SCSI Controller",
-            0x05 => "Ethernet",
-            0x06 => "Token Ring",
-            0x07 => "Sound",
-            0x08 => "PATA Controller",
-            0x09 => "SATA Controller",
-            0x0A => "SAS Controller",
-            0x0B => "Wireless LAN",
-            0x0C => "Bluetooth",
-            0x0D => "WWAN",
-            0x0E => "eMMC",
-            0x0F => "NVMe Controller",
-            0x10 => "UFS Controller",
-            _ => unreachable!(),
-        })
+    pub fn device_ty_str(&self) -> Option<String> {
+        self.device_ty()
+            .map(|t| get_onboard_device_ty_str(t & 0x7F))
     }
 
     pub fn device_number(&self) -> Option<u8> {
@@ -2626,11 +4658,134 @@ pub struct ManagementControllerHostInterface {
     handle: u16,
     interface_ty: Option<u8>,
     interface_ty_specific_data_length: Option<u8>,
-    #[smbios(length = "interface_ty_specific_data_length")]
+    #[smbios(length_bytes = "interface_ty_specific_data_length")]
     interface_ty_specific_data: Option<Vec<u8>>,
     num_protocol_records: Option<u8>,
     #[smbios(length = "num_protocol_records")]
-    protocol_records: Option<Vec<u8>>,
+    protocol_records: Option<Vec<ProtocolRecord>>,
+}
+
+impl ManagementControllerHostInterface {
+    pub fn interface_ty_str(&self) -> Option<String> {
+        self.interface_ty().map(|t| {
+            let ty = match t {
+                0x40 => "Network",
+                0xF0 => "OEM",
+                v => return format!("Unknown (0x{:02X})", v),
+            };
+            ty.to_string()
+        })
+    }
+
+    pub fn redfish_interfaces(&self) -> Vec<RedfishOverIp> {
+        self.protocol_records()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|r| r.redfish_over_ip())
+            .collect()
+    }
+}
+
+#[derive(SMBIOS)]
+pub struct ProtocolRecord {
+    protocol_ty: Option<u8>,
+    protocol_ty_specific_data_length: Option<u8>,
+    #[smbios(length_bytes = "protocol_ty_specific_data_length")]
+    protocol_ty_specific_data: Option<Vec<u8>>,
+}
+
+impl ProtocolRecord {
+    pub fn protocol_ty_str(&self) -> Option<String> {
+        self.protocol_ty().map(|t| {
+            let ty = match t {
+                0x02 => "IPMI",
+                0x03 => "MCTP",
+                0x04 => "Redfish over IP",
+                0xF0 => "OEM",
+                v => return format!("Unknown (0x{:02X})", v),
+            };
+            ty.to_string()
+        })
+    }
+
+    pub fn redfish_over_ip(&self) -> Option<RedfishOverIp> {
+        if self.protocol_ty() != Some(0x04) {
+            return None;
+        }
+
+        RedfishOverIp::from_bytes(self.protocol_ty_specific_data()?)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedfishOverIp {
+    pub service_uuid: [u8; 16],
+    pub host_ip_assignment_ty: u8,
+    pub host_ip_address: Option<IpAddr>,
+    pub host_ip_mask: Option<IpAddr>,
+    pub service_ip_discovery_ty: u8,
+    pub service_ip_address: Option<IpAddr>,
+    pub service_ip_mask: Option<IpAddr>,
+    pub service_ip_port: u16,
+    pub service_vlan_id: u32,
+    pub service_hostname: Option<String>,
+}
+
+impl RedfishOverIp {
+    fn from_bytes(mut data: &[u8]) -> Option<Self> {
+        if data.remaining() < 91 {
+            return None;
+        }
+
+        let mut uuid = [0; 16];
+        data.copy_to_slice(&mut uuid);
+        let host_ip_assignment_ty = data.get_u8();
+        let host_ip_address_format = data.get_u8();
+        let host_ip_address = get_ip_address(host_ip_address_format, &mut data);
+        let host_ip_mask = get_ip_address(host_ip_address_format, &mut data);
+        let service_ip_discovery_ty = data.get_u8();
+        let service_ip_address_format = data.get_u8();
+        let service_ip_address = get_ip_address(service_ip_address_format, &mut data);
+        let service_ip_mask = get_ip_address(service_ip_address_format, &mut data);
+        let service_ip_port = data.get_u16_le();
+        let service_vlan_id = data.get_u32_le();
+        let hostname_length = data.get_u8() as usize;
+        let service_hostname = if hostname_length > 0 && data.remaining() >= hostname_length {
+            let name = String::from_utf8_lossy(&data[..hostname_length]);
+            Some(name.trim_end_matches('\0').to_string())
+        } else {
+            None
+        };
+
+        Some(RedfishOverIp {
+            service_uuid: uuid,
+            host_ip_assignment_ty,
+            host_ip_address,
+            host_ip_mask,
+            service_ip_discovery_ty,
+            service_ip_address,
+            service_ip_mask,
+            service_ip_port,
+            service_vlan_id,
+            service_hostname,
+        })
+    }
+
+    pub fn service_uuid_string(&self) -> String {
+        let mut u = self.service_uuid;
+        u[0..4].reverse();
+        u[4..6].reverse();
+        u[6..8].reverse();
+        get_uuid_str(&u)
+    }
+
+    pub fn host_ip_assignment_ty_str(&self) -> String {
+        get_ip_assignment_ty_str(self.host_ip_assignment_ty)
+    }
+
+    pub fn service_ip_discovery_ty_str(&self) -> String {
+        get_ip_assignment_ty_str(self.service_ip_discovery_ty)
+    }
 }
 
 #[derive(SMBIOS)]
@@ -2643,7 +4798,7 @@ pub struct TpmDevice {
     minor_spec_version: Option<u8>,
     firmware_version1: Option<u32>,
     firmawre_version2: Option<u32>,
-    description: Option<String>,
+    description: Option<SmbiosString>,
     characteristics: Option<u64>,
     oem_defined: Option<u32>,
 }
@@ -2706,7 +4861,7 @@ pub struct ProcessorAdditional {
     length: u8,
     handle: u16,
     referenced_handle: Option<u16>,
-    #[smbios(length = "Some(length - 6)")]
+    #[smbios(remaining)]
     processor_specific_block: Option<Vec<u8>>,
 }
 
@@ -2715,14 +4870,14 @@ pub struct FirmwareInventory {
     table_ty: u8,
     length: u8,
     handle: u16,
-    firmware_component_name: Option<String>,
-    firmware_version: Option<String>,
+    firmware_component_name: Option<SmbiosString>,
+    firmware_version: Option<SmbiosString>,
     version_format: Option<u8>,
     firmware_id: Option<u8>,
     firmware_id_format: Option<u8>,
-    release_date: Option<String>,
-    manufacturer: Option<String>,
-    lowerest_supported_firmware_version: Option<String>,
+    release_date: Option<SmbiosString>,
+    manufacturer: Option<SmbiosString>,
+    lowerest_supported_firmware_version: Option<SmbiosString>,
     image_size: Option<u64>,
     characteristics: Option<u16>,
     state: Option<u8>,
@@ -2737,7 +4892,7 @@ pub struct StringProperty {
     length: u8,
     handle: u16,
     string_property_id: Option<u16>,
-    string_property_value: Option<String>,
+    string_property_value: Option<SmbiosString>,
     parent_handle: Option<u16>,
 }
 
@@ -2755,8 +4910,15 @@ pub struct EnfOfTable {
     handle: u16,
 }
 
-pub fn get_board_ty_str(ty: u8) -> &'static str {
-    match ty {
+pub fn get_board_ty_str(ty: u8) -> String {
+    match try_get_board_ty_str(ty) {
+        Some(s) => s.to_string(),
+        None => format!("Unknown (0x{:02X})", ty),
+    }
+}
+
+pub fn try_get_board_ty_str(ty: u8) -> Option<&'static str> {
+    let s = match ty {
         1 => "Unknown",
         2 => "Other",
         3 => "Server Blade",
@@ -2770,7 +4932,199 @@ pub fn get_board_ty_str(ty: u8) -> &'static str {
         11 => "Processor+Memory Module",
         12 => "Processor+I/O Module",
         13 => "Interconnect Board",
-        _ => unreachable!(),
+        _ => return None,
+    };
+    Some(s)
+}
+
+pub fn get_shell_safe_str(value: &str, quote: bool) -> Option<String> {
+    let value: String = value
+        .chars()
+        .filter(|c| *c != '\r' && *c != '\n')
+        .map(|c| if c.is_control() { '.' } else { c })
+        .collect();
+
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+
+    if quote {
+        Some(format!("'{}'", value.replace('\'', "'\\''")))
+    } else {
+        Some(value.to_string())
+    }
+}
+
+// UTC, to the second; times before the epoch are clamped to it.
+fn get_rfc3339_str(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let (days, rem) = (secs / 86400, secs % 86400);
+
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm).
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
+
+fn get_uuid_str(u: &[u8; 16]) -> String {
+    let hex = |bytes: &[u8]| -> String { bytes.iter().map(|b| format!("{:02x}", b)).collect() };
+    format!(
+        "{}-{}-{}-{}-{}",
+        hex(&u[0..4]),
+        hex(&u[4..6]),
+        hex(&u[6..8]),
+        hex(&u[8..10]),
+        hex(&u[10..16])
+    )
+}
+
+fn get_ip_address(format: u8, data: &mut &[u8]) -> Option<IpAddr> {
+    let mut address = [0; 16];
+    data.copy_to_slice(&mut address);
+    match format {
+        0x01 => Some(IpAddr::V4(Ipv4Addr::new(
+            address[0], address[1], address[2], address[3],
+        ))),
+        0x02 => Some(IpAddr::V6(Ipv6Addr::from(address))),
+        _ => None,
+    }
+}
+
+fn get_ip_assignment_ty_str(ty: u8) -> String {
+    let s = match ty {
+        0x00 => "Unknown",
+        0x01 => "Static",
+        0x02 => "DHCP",
+        0x03 => "AutoConf",
+        0x04 => "Host Selected",
+        v => return format!("Unknown (0x{:02X})", v),
+    };
+    s.to_string()
+}
+
+fn get_probe_value_str(
+    value: Option<u16>,
+    divisor: u16,
+    precision: usize,
+    unit: &str,
+) -> Option<String> {
+    value.map(|v| match v {
+        0x8000 => "Unknown".to_string(),
+        _ => format!("{:.*}{}", precision, v as f32 / divisor as f32, unit),
+    })
+}
+
+fn get_signed_probe_value_str(
+    value: Option<i16>,
+    divisor: u16,
+    precision: usize,
+    unit: &str,
+) -> Option<String> {
+    value.map(|v| match v {
+        i16::MIN => "Unknown".to_string(),
+        _ => format!("{:.*}{}", precision, v as f32 / divisor as f32, unit),
+    })
+}
+
+// Shared by type 10 and type 41; the later values are reused by some type 10 firmware.
+fn get_onboard_device_ty_str(ty: u8) -> String {
+    match ty {
+        0x01 => "Other",
+        0x02 => "Unknown",
+        0x03 => "Video",
+        0x04 => "SCSI Controller",
+        0x05 => "Ethernet",
+        0x06 => "Token Ring",
+        0x07 => "Sound",
+        0x08 => "PATA Controller",
+        0x09 => "SATA Controller",
+        0x0A => "SAS Controller",
+        0x0B => "Wireless LAN",
+        0x0C => "Bluetooth",
+        0x0D => "WWAN",
+        0x0E => "eMMC",
+        0x0F => "NVMe Controller",
+        0x10 => "UFS Controller",
+        v => return format!("Unknown (0x{:02X})", v),
+    }
+    .to_string()
+}
+
+// Locations 0x0C-0x0F are only defined for temperature probes.
+fn get_probe_location_str(location: u8, temperature: bool) -> String {
+    match location {
+        0x01 => "Other",
+        0x02 => "Unknown",
+        0x03 => "Processor",
+        0x04 => "Disk",
+        0x05 => "Peripheral Bay",
+        0x06 => "System Management Module",
+        0x07 => "Motherboard",
+        0x08 => "Memory Module",
+        0x09 => "Processor Module",
+        0x0A => "Power Unit",
+        0x0B => "Add-in Card",
+        0x0C if temperature => "Front Panel Board",
+        0x0D if temperature => "Back Panel Board",
+        0x0E if temperature => "Power System Board",
+        0x0F if temperature => "Drive Back Plane",
+        v => return format!("Unknown (0x{:02X})", v),
+    }
+    .to_string()
+}
+
+fn get_probe_status_str(status: u8) -> String {
+    match status {
+        0x01 => "Other",
+        0x02 => "Unknown",
+        0x03 => "OK",
+        0x04 => "Non-critical",
+        0x05 => "Critical",
+        0x06 => "Non-recoverable",
+        v => return format!("Unknown (0x{:02X})", v),
+    }
+    .to_string()
+}
+
+fn get_cache_size_kb(value: u16) -> u32 {
+    // Bit 15 selects a granularity of 64K instead of 1K.
+    let size = (value & 0x7FFF) as u32;
+    if (value & 0x8000) != 0 {
+        size * 64
+    } else {
+        size
+    }
+}
+
+fn get_memory_module_size_str(value: u8) -> String {
+    let conn = if (value & 0x80) != 0 {
+        "(Double-bank Connection)"
+    } else {
+        "(Single-bank Connection)"
+    };
+
+    match value & 0x7F {
+        0x7D => format!("Not Determinable {}", conn),
+        0x7E => format!("Disabled {}", conn),
+        0x7F => "Not Installed".to_string(),
+        v => format!("{} MB {}", v, conn),
     }
 }
 
@@ -2792,6 +5146,48 @@ fn get_memory_ty_str(value: u16) -> Vec<String> {
     get_flag_strings(value as u64, &types)
 }
 
+// "MM/DD/YYYY" or "MM/DD/YY" as (year, month, day).
+fn parse_date_str(date: &str) -> Option<(u16, u8, u8)> {
+    let mut parts = date.trim().split('/');
+    let month = parts.next()?.trim().parse::<u8>().ok()?;
+    let day = parts.next()?.trim().parse::<u8>().ok()?;
+    let year = parts.next()?.trim();
+    if parts.next().is_some() || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let year = match (year.len(), year.parse::<u16>().ok()?) {
+        // Two-digit years are only seen on pre-2000 and early 2000s firmware.
+        (2, y) if y < 80 => 2000 + y,
+        (2, y) => 1900 + y,
+        (4, y) => y,
+        _ => return None,
+    };
+
+    Some((year, month, day))
+}
+
+fn get_error_handle_str(handle: u16) -> String {
+    match handle {
+        0xFFFE => "Not Provided".to_string(),
+        0xFFFF => "No Error".to_string(),
+        h => format!("0x{:04X}", h),
+    }
+}
+
+fn get_memory_speed_str(speed: u16, extended: Option<u32>) -> String {
+    // 0xFFFF defers to the 32-bit field, whose bit 31 is reserved.
+    let speed = match (speed, extended) {
+        (0xFFFF, Some(e)) => e & 0x7FFF_FFFF,
+        (s, _) => s as u32,
+    };
+
+    match speed {
+        0 | 0xFFFF => "Unknown".to_string(),
+        s => format!("{} MT/s", s),
+    }
+}
+
 fn get_flag_strings(value: u64, flags: &[&'static str]) -> Vec<String> {
     let mut v = vec![];
     for (i, name) in flags.iter().enumerate() {
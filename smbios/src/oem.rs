@@ -0,0 +1,195 @@
+#[cfg(feature = "encode")]
+use crate::error::EncodeError;
+use crate::error::ParseError;
+#[cfg(feature = "encode")]
+use crate::StringPool;
+use crate::{Chassis, FieldValue, RawSmbiosData, RawSmbiosTable, SmbiosTable, SmbiosTables};
+use bytes::{Buf, Bytes};
+#[cfg(feature = "encode")]
+use bytes::{BufMut, BytesMut};
+use smbios_derive::SMBIOS;
+use std::sync::RwLock;
+
+type ChassisOemDecoder = Box<dyn Fn(u32) -> Option<String> + Send + Sync>;
+
+static CHASSIS_OEM_DECODERS: RwLock<Vec<(String, ChassisOemDecoder)>> = RwLock::new(Vec::new());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Vendor {
+    Dell,
+    Hp,
+}
+
+impl Vendor {
+    pub fn from_manufacturer(manufacturer: &str) -> Option<Self> {
+        let manufacturer = manufacturer.trim();
+        if manufacturer.starts_with("Dell") {
+            Some(Vendor::Dell)
+        } else if manufacturer == "HP"
+            || manufacturer == "HPE"
+            || manufacturer.starts_with("Hewlett-Packard")
+            || manufacturer.starts_with("Hewlett Packard")
+        {
+            Some(Vendor::Hp)
+        } else {
+            None
+        }
+    }
+}
+
+// Decoders are matched against the System manufacturer by prefix; the most
+// recently registered match wins.
+pub fn register_chassis_oem_decoder<F>(manufacturer: &str, decoder: F)
+where
+    F: Fn(u32) -> Option<String> + Send + Sync + 'static,
+{
+    let mut decoders = CHASSIS_OEM_DECODERS.write().unwrap();
+    decoders.push((manufacturer.trim().to_string(), Box::new(decoder)));
+}
+
+pub fn decode_chassis_oem(manufacturer: &str, value: u32) -> Option<String> {
+    let manufacturer = manufacturer.trim();
+    let decoders = CHASSIS_OEM_DECODERS.read().unwrap();
+    decoders
+        .iter()
+        .rev()
+        .find(|(prefix, _)| manufacturer.starts_with(prefix.as_str()))
+        .and_then(|(_, decoder)| decoder(value))
+}
+
+pub enum OemTable {
+    DellCallingInterface(DellCallingInterface),
+    HpNicMacAssignment(HpNicMacAssignment),
+}
+
+impl OemTable {
+    pub fn from_raw_table(raw: &RawSmbiosTable, vendor: Vendor) -> Option<Self> {
+        match (vendor, raw.table_ty) {
+            (Vendor::Dell, 0xDA) => Some(OemTable::DellCallingInterface(
+                DellCallingInterface::from_raw_table(raw),
+            )),
+            (Vendor::Hp, 0xD1) => Some(OemTable::HpNicMacAssignment(
+                HpNicMacAssignment::from_raw_table(raw),
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl SmbiosTables {
+    pub fn vendor(&self) -> Option<Vendor> {
+        self.system_manufacturer()
+            .and_then(Vendor::from_manufacturer)
+    }
+
+    pub fn system_manufacturer(&self) -> Option<&str> {
+        self.iter().find_map(|t| match t {
+            SmbiosTable::System(system) => system.manufacturer(),
+            _ => None,
+        })
+    }
+
+    pub fn chassis_oem_str(&self, chassis: &Chassis) -> Option<String> {
+        let value = chassis.oem_defined()?;
+        self.system_manufacturer()
+            .and_then(|m| decode_chassis_oem(m, value))
+            .or_else(|| Some(format!("0x{:08X}", value)))
+    }
+
+    pub fn oem_tables(&self) -> Vec<(&RawSmbiosTable, OemTable)> {
+        let vendor = match self.vendor() {
+            Some(vendor) => vendor,
+            None => return vec![],
+        };
+
+        self.iter()
+            .filter_map(|t| match t {
+                SmbiosTable::Other(raw) => {
+                    OemTable::from_raw_table(raw, vendor).map(|oem| (raw, oem))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[derive(SMBIOS)]
+pub struct DellCallingInterfaceToken {
+    token_id: Option<u16>,
+    location: Option<u16>,
+    value: Option<u16>,
+}
+
+// Dell type 0xDA: SMI calling interface and the token table.
+#[derive(SMBIOS)]
+pub struct DellCallingInterface {
+    table_ty: u8,
+    length: u8,
+    handle: u16,
+    #[smbios(label = "Command I/O Address", fmt = "hex")]
+    cmd_io_address: Option<u16>,
+    #[smbios(label = "Command I/O Code", fmt = "hex")]
+    cmd_io_code: Option<u8>,
+    #[smbios(label = "Supported Commands", fmt = "hex")]
+    supported_cmds: Option<u32>,
+    #[smbios(length = "Some(length.saturating_sub(11) / 6)")]
+    raw_tokens: Option<Vec<DellCallingInterfaceToken>>,
+}
+
+impl DellCallingInterface {
+    pub fn tokens(&self) -> impl Iterator<Item = &DellCallingInterfaceToken> {
+        self.raw_tokens()
+            .unwrap_or_default()
+            .iter()
+            .take_while(|t| t.token_id().is_some_and(|id| id != 0xFFFF))
+    }
+
+    pub fn token(&self, token_id: u16) -> Option<&DellCallingInterfaceToken> {
+        self.tokens().find(|t| t.token_id() == Some(token_id))
+    }
+}
+
+#[derive(SMBIOS)]
+pub struct HpNicMacAddress {
+    device_function_number: Option<u8>,
+    bus_number: Option<u8>,
+    mac_address: Option<[u8; 6]>,
+}
+
+impl HpNicMacAddress {
+    pub fn device_number(&self) -> Option<u8> {
+        self.device_function_number().map(|n| n >> 3)
+    }
+
+    pub fn function_number(&self) -> Option<u8> {
+        self.device_function_number().map(|n| n & 0x07)
+    }
+
+    pub fn mac_address_str(&self) -> Option<String> {
+        self.mac_address().map(|mac| {
+            mac.iter()
+                .map(|b| format!("{:02X}", b))
+                .collect::<Vec<_>>()
+                .join(":")
+        })
+    }
+}
+
+// HP type 0xD1: MAC addresses assigned to the embedded NICs.
+#[derive(SMBIOS)]
+pub struct HpNicMacAssignment {
+    table_ty: u8,
+    length: u8,
+    handle: u16,
+    #[smbios(length = "Some(length.saturating_sub(4) / 8)")]
+    raw_nics: Option<Vec<HpNicMacAddress>>,
+}
+
+impl HpNicMacAssignment {
+    pub fn nics(&self) -> impl Iterator<Item = &HpNicMacAddress> {
+        self.raw_nics()
+            .unwrap_or_default()
+            .iter()
+            .take_while(|n| n.device_function_number() != Some(0) || n.bus_number() != Some(0))
+    }
+}
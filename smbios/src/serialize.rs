@@ -0,0 +1,116 @@
+use super::{RawSmbiosData, SmbiosMeta, SmbiosSource, SmbiosTable, SmbiosTables, StringEncoding};
+use bytes::Bytes;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize)]
+struct SmbiosVersion {
+    major: u8,
+    minor: u8,
+    dmi_revision: u8,
+}
+
+#[derive(Serialize)]
+struct SmbiosSnapshotRef<'a> {
+    meta: &'a SmbiosMeta,
+    version: SmbiosVersion,
+    structures: &'a [SmbiosTable],
+}
+
+#[derive(Deserialize)]
+struct SmbiosSnapshot {
+    #[serde(default)]
+    meta: SmbiosMeta,
+    version: SmbiosVersion,
+    structures: Vec<SmbiosTable>,
+}
+
+impl Serialize for SmbiosTables {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SmbiosSnapshotRef {
+            meta: &self.meta,
+            version: SmbiosVersion {
+                major: self.data.smbios_major_version,
+                minor: self.data.smbios_minior_version,
+                dmi_revision: self.data.dmi_revision,
+            },
+            structures: &self.tables,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SmbiosTables {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let snapshot = SmbiosSnapshot::deserialize(deserializer)?;
+        let data = RawSmbiosData {
+            used_20_calling_method: 0,
+            smbios_major_version: snapshot.version.major,
+            smbios_minior_version: snapshot.version.minor,
+            dmi_revision: snapshot.version.dmi_revision,
+            length: 0,
+            num_structures: None,
+            smbios_table_data: Bytes::new(),
+            source: SmbiosSource::Buffer,
+            entry_point: None,
+            string_encoding: StringEncoding::default(),
+            resync: false,
+        };
+
+        Ok(SmbiosTables {
+            data,
+            tables: snapshot.structures,
+            meta: snapshot.meta,
+            #[cfg(feature = "arena")]
+            arena: None,
+        })
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect()
+}
+
+fn from_hex<E: Error>(value: &str) -> Result<Vec<u8>, E> {
+    if value.len() % 2 != 0 {
+        return Err(E::custom("odd number of hex digits"));
+    }
+
+    (0..value.len())
+        .step_by(2)
+        .map(|i| {
+            value
+                .get(i..i + 2)
+                .and_then(|b| u8::from_str_radix(b, 16).ok())
+                .ok_or_else(|| E::custom(format!("invalid hex digits at {}", i)))
+        })
+        .collect()
+}
+
+pub(crate) mod hex {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(bytes: &Bytes, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&to_hex(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Bytes, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        from_hex(&value).map(Bytes::from)
+    }
+}
+
+pub(crate) mod hex_list {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(list: &[Vec<u8>], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(list.iter().map(|b| to_hex(b)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Vec<u8>>, D::Error> {
+        let values = Vec::<String>::deserialize(deserializer)?;
+        values.iter().map(|v| from_hex(v)).collect()
+    }
+}
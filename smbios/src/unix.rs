@@ -1,99 +1,147 @@
-use super::RawSmbiosData;
-use bytes::{Buf, Bytes};
-use std::fs;
-use std::io::Error;
+use super::acpi::{get_acpi_carrier, ACPI_CARRIERS};
+use super::{EntryPoint, RawSmbiosData, SmbiosSource};
+use bytes::Bytes;
+use std::fs::{self, File};
+use std::io::{Error, ErrorKind, Read, Seek, SeekFrom};
 
 const DMI_PATH: &str = "/sys/firmware/dmi/tables/DMI";
 const SMBIOS_ENTRY_POINT_PATH: &str = "/sys/firmware/dmi/tables/smbios_entry_point";
+const EFI_SYSTAB_PATH: &str = "/sys/firmware/efi/systab";
+const DEV_MEM_PATH: &str = "/dev/mem";
+const ACPI_TABLES_PATH: &str = "/sys/firmware/acpi/tables";
+
+// Large enough for both the 2.x (0x1F) and 3.x (0x18) entry points.
+const ENTRY_POINT_READ_SIZE: usize = 0x20;
 
 pub fn get_smbios() -> Result<RawSmbiosData, Error> {
-    let bytes = fs::read(SMBIOS_ENTRY_POINT_PATH)?;
-    let bytes = Bytes::from(bytes);
-
-    if String::from_utf8_lossy(&bytes.slice(0..4)) == "_SM_" {
-        Ok(get_smbios2(bytes)?)
-    } else if String::from_utf8_lossy(&bytes.slice(0..5)) == "_SM3_" {
-        Ok(get_smbios3(bytes)?)
-    } else {
-        panic!();
+    get_smbios_with_entry().map(|(_, smbios)| smbios)
+}
+
+pub fn get_smbios_with_entry() -> Result<(EntryPoint, RawSmbiosData), Error> {
+    match get_smbios_from_sysfs() {
+        // Minimal images may lack the DMI sysfs interface but still expose
+        // the UEFI configuration table.
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            get_smbios_from_efi_systab().map_err(|efi| match efi.kind() {
+                ErrorKind::NotFound => e,
+                _ => efi,
+            })
+        }
+        result => result,
     }
 }
 
-pub fn get_smbios2(entry: Bytes) -> Result<RawSmbiosData, Error> {
-    let mut entry = entry;
-    let _anchor = [
-        entry.get_u8(),
-        entry.get_u8(),
-        entry.get_u8(),
-        entry.get_u8(),
-    ];
-    let _entry_checksum = entry.get_u8();
-    let _entry_length = entry.get_u8();
-    let smbios_major_version = entry.get_u8();
-    let smbios_minior_version = entry.get_u8();
-    let _max_structure_size = entry.get_u16();
-    let dmi_revision = entry.get_u8();
-    let _formatted_ares = [
-        entry.get_u8(),
-        entry.get_u8(),
-        entry.get_u8(),
-        entry.get_u8(),
-        entry.get_u8(),
-    ];
-    let _inter_anchor = [
-        entry.get_u8(),
-        entry.get_u8(),
-        entry.get_u8(),
-        entry.get_u8(),
-        entry.get_u8(),
-    ];
-    let _inter_checksum = entry.get_u8();
-    let length = entry.get_u16() as u32;
-    let _structure_table_address = entry.get_u32();
-    let _num_smbios = entry.get_u16();
-    let _smbios_bcd_revision = entry.get_u8();
+// Only tried when the standard paths are missing, since the carrier tables
+// are vendor-defined.
+pub fn get_smbios_with_acpi_fallback() -> Result<RawSmbiosData, Error> {
+    match get_smbios() {
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            get_smbios_from_acpi().map_err(|acpi| match acpi.kind() {
+                ErrorKind::NotFound => e,
+                _ => acpi,
+            })
+        }
+        result => result,
+    }
+}
+
+fn get_smbios_from_sysfs() -> Result<(EntryPoint, RawSmbiosData), Error> {
+    let bytes = fs::read(SMBIOS_ENTRY_POINT_PATH)?;
+    let mut bytes = Bytes::from(bytes);
+
+    let entry = EntryPoint::try_from_bytes(&mut bytes)
+        .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
 
     let smbios_table_data = fs::read(DMI_PATH)?;
     let smbios_table_data = Bytes::from(smbios_table_data);
 
-    Ok(RawSmbiosData {
-        used_20_calling_method: 1,
-        smbios_major_version,
-        smbios_minior_version,
-        dmi_revision,
-        length,
-        smbios_table_data,
-    })
+    let smbios =
+        RawSmbiosData::from_entry_point(&entry, smbios_table_data, SmbiosSource::LinuxSysfs);
+
+    Ok((entry, smbios))
 }
 
-pub fn get_smbios3(entry: Bytes) -> Result<RawSmbiosData, Error> {
-    let mut entry = entry;
-    let _anchor = [
-        entry.get_u8(),
-        entry.get_u8(),
-        entry.get_u8(),
-        entry.get_u8(),
-        entry.get_u8(),
-    ];
-    let _entry_checksum = entry.get_u8();
-    let _entry_length = entry.get_u8();
-    let smbios_major_version = entry.get_u8();
-    let smbios_minior_version = entry.get_u8();
-    let dmi_revision = entry.get_u8();
-    let _entry_revision = entry.get_u8();
-    let _reserved = entry.get_u8();
-    let _structure_table_max_size = entry.get_u32_le();
-    let _structure_table_address = entry.get_u64_le();
+fn get_smbios_from_efi_systab() -> Result<(EntryPoint, RawSmbiosData), Error> {
+    let systab = fs::read_to_string(EFI_SYSTAB_PATH)?;
+    let address = parse_efi_systab(&systab).ok_or_else(|| {
+        Error::new(
+            ErrorKind::NotFound,
+            "no SMBIOS entry in the EFI system table",
+        )
+    })?;
 
-    let smbios_table_data = fs::read(DMI_PATH)?;
+    let mut bytes = Bytes::from(read_dev_mem(address, ENTRY_POINT_READ_SIZE)?);
+    let entry = EntryPoint::try_from_bytes(&mut bytes)
+        .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+
+    let smbios_table_data = read_dev_mem(entry.table_address, entry.table_length as usize)?;
     let smbios_table_data = Bytes::from(smbios_table_data);
 
-    Ok(RawSmbiosData {
-        used_20_calling_method: 0,
-        smbios_major_version,
-        smbios_minior_version,
-        dmi_revision,
-        length: 0,
-        smbios_table_data,
-    })
+    let smbios =
+        RawSmbiosData::from_entry_point(&entry, smbios_table_data, SmbiosSource::LinuxDevMem);
+
+    Ok((entry, smbios))
+}
+
+fn get_smbios_from_acpi() -> Result<RawSmbiosData, Error> {
+    // Repeated signatures get a numeric suffix, like `SSDT2`.
+    let mut paths = vec![];
+    for entry in fs::read_dir(ACPI_TABLES_PATH)? {
+        let path = entry?.path();
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default();
+        if ACPI_CARRIERS
+            .iter()
+            .any(|carrier| name.as_bytes().starts_with(&carrier.signature))
+        {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    for path in paths {
+        let table = fs::read(&path)?;
+        if let Some(carrier) = get_acpi_carrier(&table) {
+            let (_, smbios) = carrier
+                .extract(&table)
+                .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+            return Ok(smbios);
+        }
+    }
+
+    Err(Error::new(
+        ErrorKind::NotFound,
+        "no SMBIOS carrier among the ACPI tables",
+    ))
+}
+
+// Lines look like `SMBIOS3=0x7f6e3000`; the 3.x entry point is preferred.
+fn parse_efi_systab(systab: &str) -> Option<u64> {
+    let address = |key: &str| {
+        systab.lines().find_map(|line| {
+            let (k, v) = line.split_once('=')?;
+            if k.trim() != key {
+                return None;
+            }
+            let v = v.trim();
+            let v = v
+                .strip_prefix("0x")
+                .or_else(|| v.strip_prefix("0X"))
+                .unwrap_or(v);
+            u64::from_str_radix(v, 16).ok()
+        })
+    };
+
+    address("SMBIOS3").or_else(|| address("SMBIOS"))
+}
+
+fn read_dev_mem(address: u64, length: usize) -> Result<Vec<u8>, Error> {
+    let mut file = File::open(DEV_MEM_PATH)?;
+    file.seek(SeekFrom::Start(address))?;
+
+    let mut buf = vec![0; length];
+    file.read_exact(&mut buf)?;
+    Ok(buf)
 }
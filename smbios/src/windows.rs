@@ -1,5 +1,10 @@
-use super::RawSmbiosData;
+use super::acpi::ACPI_CARRIERS;
+use super::{RawSmbiosData, SmbiosSource};
+use crate::error;
 use bytes::Bytes;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
 use windows::core::Error;
 use windows::Win32::System::SystemInformation::{
     EnumSystemFirmwareTables, GetSystemFirmwareTable, FIRMWARE_TABLE_PROVIDER,
@@ -9,13 +14,63 @@ pub const FIRMWARE_TABLE_ACPI: u32 = 0x41435049; // 'ACPI'
 pub const FIRMWARE_TABLE_FIRM: u32 = 0x4649524D; // 'FIRM'
 pub const FIRMWARE_TABLE_RSMB: u32 = 0x52534D42; // 'RSMB'
 
-pub fn get_smbios() -> Result<RawSmbiosData, Error> {
-    let tables = enum_system_firmware_table(FIRMWARE_TABLE_RSMB)?;
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub fn get_smbios() -> Result<RawSmbiosData, error::Error> {
+    get_smbios_timeout(DEFAULT_TIMEOUT)
+}
 
-    let smbios_bytes = get_system_firmware_table(FIRMWARE_TABLE_RSMB, tables[0])?;
+pub fn get_smbios_timeout(timeout: Duration) -> Result<RawSmbiosData, error::Error> {
+    // Some firmware never returns from GetSystemFirmwareTable, so the call
+    // runs on a worker thread that is abandoned on timeout.
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let result = read_rsmb().map_err(|e| e.code());
+        let _ = tx.send(result);
+    });
+
+    let smbios_bytes = match rx.recv_timeout(timeout) {
+        Ok(result) => result.map_err(Error::from)?,
+        Err(_) => return Err(error::Error::Timeout),
+    };
     let mut smbios_bytes = Bytes::from(smbios_bytes);
 
-    Ok(RawSmbiosData::from(&mut smbios_bytes))
+    // Hypervisors may hand back short or inconsistent RSMB buffers.
+    let mut smbios = RawSmbiosData::try_from_bytes(&mut smbios_bytes)?;
+    smbios.source = SmbiosSource::WindowsRsmb;
+    Ok(smbios)
+}
+
+// Only tried when RSMB fails, since the carrier tables are vendor-defined.
+pub fn get_smbios_with_acpi_fallback() -> Result<RawSmbiosData, error::Error> {
+    match get_smbios() {
+        Err(error::Error::Timeout) => Err(error::Error::Timeout),
+        Err(e) => read_acpi_carrier().map_err(|_| e),
+        result => result,
+    }
+}
+
+fn read_acpi_carrier() -> Result<RawSmbiosData, error::Error> {
+    // ACPI table ids are the signatures read as little-endian integers.
+    let ids = enum_system_firmware_table(FIRMWARE_TABLE_ACPI)?;
+    for carrier in ACPI_CARRIERS {
+        let id = u32::from_le_bytes(carrier.signature);
+        if ids.contains(&id) {
+            let table = get_system_firmware_table(FIRMWARE_TABLE_ACPI, id)?;
+            let (_, smbios) = carrier.extract(&table)?;
+            return Ok(smbios);
+        }
+    }
+
+    Err(error::Error::Io(std::io::Error::new(
+        std::io::ErrorKind::NotFound,
+        "no SMBIOS carrier among the ACPI tables",
+    )))
+}
+
+fn read_rsmb() -> Result<Vec<u8>, Error> {
+    let tables = enum_system_firmware_table(FIRMWARE_TABLE_RSMB)?;
+    get_system_firmware_table(FIRMWARE_TABLE_RSMB, tables[0])
 }
 
 fn enum_system_firmware_table(signature: u32) -> Result<Vec<u32>, Error> {
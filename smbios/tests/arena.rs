@@ -0,0 +1,101 @@
+use smbios::*;
+
+mod common;
+
+use common::*;
+
+fn fixture() -> Vec<u8> {
+    let mut structures = raw_bytes(0, &[1, 2, 0, 0, 3, 0], &["Vendor", "1.0.0", "01/01/2024"]);
+    structures.extend(raw_bytes(
+        1,
+        &[
+            1, 2, 3, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        &["Acme", "Server", "1.0"],
+    ));
+    structures.extend(raw_bytes(2, &[1, 0, 0, 2], &["Board Inc", "BSN"]));
+    structures.extend(raw_bytes(
+        3,
+        &[1, 0x17, 0, 2, 0, 3, 3, 3, 3],
+        &["Chassis Co", "CSN"],
+    ));
+    structures.extend(raw_bytes(11, &[2], &["oem one", "oem two"]));
+    structures.extend(raw_bytes(127, &[], &[]));
+    structures
+}
+
+fn both(structures: &[u8]) -> (SmbiosTables, SmbiosTables) {
+    (
+        SmbiosTables::from(smbios_data(structures)),
+        SmbiosTables::from_in_arena(smbios_data(structures)),
+    )
+}
+
+#[test]
+fn arena_matches_default_parse() {
+    let (owned, arena) = both(&fixture());
+
+    assert_eq!(owned.tables().len(), arena.tables().len());
+    for (a, b) in owned.iter().zip(arena.iter()) {
+        assert_eq!(a.table_ty(), b.table_ty());
+        assert_eq!(a.handle(), b.handle());
+        let (a, b) = match (a, b) {
+            (SmbiosTable::Bios(a), SmbiosTable::Bios(b)) => (a.fields(), b.fields()),
+            (SmbiosTable::System(a), SmbiosTable::System(b)) => (a.fields(), b.fields()),
+            (SmbiosTable::BaseBoard(a), SmbiosTable::BaseBoard(b)) => (a.fields(), b.fields()),
+            (SmbiosTable::Chassis(a), SmbiosTable::Chassis(b)) => (a.fields(), b.fields()),
+            (SmbiosTable::OemStrings(a), SmbiosTable::OemStrings(b)) => {
+                assert_eq!(a.strings(), b.strings());
+                continue;
+            }
+            (SmbiosTable::EnfOfTable(_), SmbiosTable::EnfOfTable(_)) => continue,
+            _ => panic!("unexpected table type {}", a.table_ty()),
+        };
+        assert!(!a.is_empty());
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn arena_keeps_string_indices_and_dangling_strings() {
+    let (owned, arena) = both(&fixture());
+    let system = |tables: &SmbiosTables| match &tables.tables()[1] {
+        SmbiosTable::System(s) => (
+            s.manufacturer().map(str::to_string),
+            s.serial_number().map(str::to_string),
+            s.serial_number_index(),
+        ),
+        _ => unreachable!(),
+    };
+
+    assert_eq!(system(&owned), system(&arena));
+    assert_eq!(system(&arena), (Some("Acme".to_string()), None, Some(9)));
+}
+
+#[test]
+fn arena_holds_every_string_once() {
+    let (owned, arena) = both(&fixture());
+
+    assert!(owned.arena().is_none());
+    let strings = arena.arena().unwrap();
+    assert_eq!(strings.len(), 12);
+    assert_eq!(strings.get(0), Some("Vendor"));
+    assert_eq!(strings.get(11), Some("oem two"));
+    assert_eq!(strings.get(12), None);
+    assert!(arena.approx_heap_bytes() >= strings.approx_heap_bytes());
+}
+
+#[test]
+fn arena_honors_string_encoding() {
+    let mut structures = vec![1, 8, 0x00, 0x01, 1, 0, 0, 0];
+    structures.extend_from_slice(b"Caf\xE9\0\0");
+    structures.extend(raw_bytes(127, &[], &[]));
+
+    let mut data = smbios_data(&structures);
+    data.string_encoding = StringEncoding::Latin1;
+    let tables = SmbiosTables::from_in_arena(data);
+    match &tables.tables()[0] {
+        SmbiosTable::System(s) => assert_eq!(s.manufacturer(), Some("Café")),
+        _ => unreachable!(),
+    }
+}
@@ -0,0 +1,50 @@
+#![allow(dead_code)]
+
+use bytes::Bytes;
+use smbios::*;
+
+pub fn raw_bytes(table_ty: u8, body: &[u8], strings: &[&str]) -> Vec<u8> {
+    let mut bytes = vec![table_ty, (body.len() + 4) as u8, 0x00, 0x01];
+    bytes.extend_from_slice(body);
+    for s in strings {
+        bytes.extend_from_slice(s.as_bytes());
+        bytes.push(0);
+    }
+    if strings.is_empty() {
+        bytes.push(0);
+    }
+    bytes.push(0);
+    bytes
+}
+
+pub fn raw_table(table_ty: u8, body: &[u8], strings: &[&str]) -> RawSmbiosTable {
+    RawSmbiosTable::from(&mut Bytes::from(raw_bytes(table_ty, body, strings)))
+}
+
+pub fn smbios_data(structures: &[u8]) -> RawSmbiosData {
+    let mut data = vec![0x00, 0x03, 0x06, 0x00];
+    data.extend_from_slice(&(structures.len() as u32).to_le_bytes());
+    data.extend_from_slice(structures);
+    RawSmbiosData::from(&mut Bytes::from(data))
+}
+
+pub fn smbios_tables(structures: &[u8]) -> SmbiosTables {
+    SmbiosTables::from(smbios_data(structures))
+}
+
+pub fn patterned_body(len: usize, string_offsets: &[usize]) -> Vec<u8> {
+    let mut body: Vec<u8> = (0..len).map(|i| 0x20 + i as u8).collect();
+    for (i, &offset) in string_offsets.iter().enumerate() {
+        body[offset] = i as u8 + 1;
+    }
+    body
+}
+
+pub fn flag_strings(value: u64, flags: &[&str]) -> Vec<String> {
+    flags
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| (value & (1 << i)) != 0)
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
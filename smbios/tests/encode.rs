@@ -0,0 +1,183 @@
+use bytes::Bytes;
+use smbios::error::EncodeError;
+use smbios::*;
+
+mod common;
+
+use common::*;
+
+macro_rules! assert_round_trip {
+    ($table:ident, $table_ty:expr, $body:expr, $strings:expr) => {{
+        let bytes = raw_bytes($table_ty, &$body, &$strings);
+        let table = $table::from_raw_table(&RawSmbiosTable::from(&mut Bytes::from(bytes.clone())));
+
+        let mut pool = StringPool::new();
+        let mut encoded = table.encode(&mut pool).unwrap().to_vec();
+        encoded.extend_from_slice(&pool.to_bytes());
+        assert_eq!(encoded, bytes);
+    }};
+}
+
+#[test]
+fn encode_round_trip() {
+    assert_round_trip!(
+        Bios,
+        0,
+        patterned_body(22, &[0x00, 0x01, 0x04]),
+        ["Vendor", "1.0", "03/14/2023"]
+    );
+    assert_round_trip!(
+        System,
+        1,
+        patterned_body(23, &[0x00, 0x01, 0x02, 0x03, 0x15, 0x16]),
+        ["Acme", "Box", "1.0", "SN", "SKU", "Family"]
+    );
+
+    let mut body = patterned_body(15, &[0x00, 0x01, 0x02, 0x03, 0x04, 0x06]);
+    body[0x0A] = 2;
+    assert_round_trip!(
+        BaseBoard,
+        2,
+        body,
+        ["Acme", "Board", "1.0", "SN", "Tag", "Slot"]
+    );
+
+    assert_round_trip!(
+        MemoryDevice,
+        17,
+        patterned_body(0x58, &[0x0C, 0x0D, 0x13, 0x14, 0x15, 0x16, 0x27]),
+        ["DIMM0", "BANK0", "Acme", "SN", "Tag", "Part", "FW"]
+    );
+}
+
+#[test]
+fn encode_stops_at_absent_field() {
+    let bios = Bios::from_raw_table(&raw_table(0, &[1, 0, 0x00, 0xE0], &["Vendor"]));
+
+    let mut pool = StringPool::new();
+    let encoded = bios.encode(&mut pool).unwrap();
+    assert_eq!(&encoded[..], &[0, 8, 0x00, 0x01, 1, 0, 0x00, 0xE0]);
+    assert_eq!(pool.to_bytes(), b"Vendor\0\0");
+}
+
+#[test]
+fn build_system_from_scratch() {
+    let empty = System::default();
+    assert_eq!(empty.table_ty(), 0);
+    assert_eq!(empty.handle(), 0);
+    assert_eq!(empty.manufacturer(), None);
+    assert_eq!(empty.uuid_bytes(), None);
+
+    let system = System::default()
+        .with_table_ty(1)
+        .with_handle(0x0100)
+        .with_manufacturer("Acme")
+        .with_product_name("Box")
+        .with_version("1.0")
+        .with_serial_number("SN")
+        .with_uuid([0x11; 16])
+        .with_wakeup_ty(0x06)
+        .with_sku_number("SKU")
+        .with_family("Acme");
+    assert_eq!(system.table_ty(), 1);
+    assert_eq!(system.handle(), 0x0100);
+    assert_eq!(system.manufacturer(), Some("Acme"));
+    assert_eq!(system.product_name(), Some("Box"));
+    assert_eq!(system.uuid_bytes(), Some([0x11; 16]));
+    assert_eq!(system.wakeup_ty(), Some(0x06));
+    assert_eq!(system.family(), Some("Acme"));
+
+    let mut pool = StringPool::new();
+    let mut bytes = system.encode(&mut pool).unwrap().to_vec();
+    bytes.extend_from_slice(&pool.to_bytes());
+    let decoded = System::from_raw_table(&RawSmbiosTable::from(&mut Bytes::from(bytes)));
+    assert_eq!(decoded.length(), 0x1B);
+    assert_eq!(decoded.manufacturer_index(), Some(1));
+    assert_eq!(decoded.family_index(), Some(1));
+    assert_eq!(decoded.serial_number(), Some("SN"));
+    assert_eq!(decoded.sku_number(), Some("SKU"));
+    assert_eq!(decoded.uuid_bytes(), Some([0x11; 16]));
+}
+
+#[test]
+fn encode_longest_structure() {
+    let processor = ProcessorAdditional::default()
+        .with_table_ty(44)
+        .with_referenced_handle(0x0400)
+        .with_processor_specific_block(vec![0xA5; 249]);
+
+    let mut pool = StringPool::new();
+    let encoded = processor.encode(&mut pool).unwrap();
+    assert_eq!(encoded.len(), 255);
+    assert_eq!(encoded[1], 255);
+}
+
+#[test]
+fn encode_too_long_structure() {
+    let processor = ProcessorAdditional::default()
+        .with_table_ty(44)
+        .with_referenced_handle(0x0400)
+        .with_processor_specific_block(vec![0xA5; 250]);
+    assert_eq!(
+        processor.encode(&mut StringPool::new()),
+        Err(EncodeError::TooLong {
+            structure: "ProcessorAdditional",
+            length: 256
+        })
+    );
+
+    let interface = |data_length: usize| {
+        ManagementControllerHostInterface::default()
+            .with_table_ty(42)
+            .with_interface_ty(0x40)
+            .with_interface_ty_specific_data(vec![0; data_length])
+            .encode(&mut StringPool::new())
+    };
+    assert_eq!(
+        interface(255),
+        Err(EncodeError::TooLong {
+            structure: "ManagementControllerHostInterface",
+            length: 261
+        })
+    );
+    assert_eq!(
+        interface(256),
+        Err(EncodeError::CountOverflow {
+            structure: "ManagementControllerHostInterface",
+            field: "interface_ty_specific_data_length",
+            count: 256
+        })
+    );
+}
+
+#[test]
+fn encode_too_many_strings() {
+    let strings = |count: usize| {
+        OemStrings::default()
+            .with_table_ty(11)
+            .with_strings((0..count).map(|i| format!("OEM {}", i)).collect())
+            .encode(&mut StringPool::new())
+    };
+    assert!(strings(255).is_ok());
+    assert_eq!(
+        strings(256),
+        Err(EncodeError::CountOverflow {
+            structure: "OemStrings",
+            field: "count",
+            count: 256
+        })
+    );
+
+    // The pool is shared by a structure's strings and may already be full.
+    let mut pool = StringPool::new();
+    for i in 0..255 {
+        pool.push(&format!("Taken {}", i));
+    }
+    let system = System::default().with_table_ty(1).with_manufacturer("Acme");
+    assert_eq!(
+        system.encode(&mut pool),
+        Err(EncodeError::TooManyStrings {
+            structure: "System"
+        })
+    );
+}
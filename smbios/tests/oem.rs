@@ -0,0 +1,93 @@
+use smbios::*;
+
+mod common;
+
+use common::*;
+
+#[test]
+fn oem_tables_by_vendor() {
+    let tables = |manufacturer: &str, oem: Vec<u8>| {
+        let mut structures = raw_bytes(1, &[1, 0, 0, 0], &[manufacturer]);
+        structures.extend_from_slice(&oem);
+        structures.extend_from_slice(&raw_bytes(127, &[], &[]));
+
+        smbios_tables(&structures)
+    };
+
+    let mut body = vec![0xB2, 0x00, 0xB2, 0x03, 0x00, 0x00, 0x00];
+    body.extend_from_slice(&[0x5C, 0x00, 0x10, 0x00, 0x01, 0x00]);
+    body.extend_from_slice(&[0x5D, 0x00, 0x10, 0x00, 0x00, 0x00]);
+    body.extend_from_slice(&[0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00]);
+    let dell = raw_bytes(0xDA, &body, &[]);
+
+    let smbios = tables("Dell Inc.", dell.clone());
+    assert_eq!(smbios.vendor(), Some(oem::Vendor::Dell));
+    let oem_tables = smbios.oem_tables();
+    assert_eq!(oem_tables.len(), 1);
+    match &oem_tables[0].1 {
+        oem::OemTable::DellCallingInterface(dell) => {
+            assert_eq!(dell.cmd_io_address(), Some(0xB2));
+            assert_eq!(dell.tokens().count(), 2);
+            assert_eq!(dell.token(0x5C).and_then(|t| t.value()), Some(1));
+        }
+        _ => panic!("expected a Dell calling interface"),
+    }
+
+    assert!(tables("Acme", dell).oem_tables().is_empty());
+
+    let mut body = vec![0x08, 0x03, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55];
+    body.extend_from_slice(&[0x00; 8]);
+    let smbios = tables("HPE", raw_bytes(0xD1, &body, &[]));
+    match &smbios.oem_tables()[0].1 {
+        oem::OemTable::HpNicMacAssignment(hp) => {
+            let nics = hp.nics().collect::<Vec<_>>();
+            assert_eq!(nics.len(), 1);
+            assert_eq!(
+                (nics[0].bus_number(), nics[0].device_number()),
+                (Some(3), Some(1))
+            );
+            assert_eq!(
+                nics[0].mac_address_str().as_deref(),
+                Some("00:11:22:33:44:55")
+            );
+        }
+        _ => panic!("expected an HP NIC MAC assignment"),
+    }
+}
+
+#[test]
+fn chassis_oem_decoder_registry() {
+    let tables = |manufacturer: &str| {
+        let mut structures = raw_bytes(1, &[1, 0, 0, 0], &[manufacturer]);
+        let body = [0, 0x03, 0, 0, 0, 3, 3, 3, 3, 0x01, 0x00, 0x00, 0x80];
+        structures.extend_from_slice(&raw_bytes(3, &body, &[]));
+        structures.extend_from_slice(&raw_bytes(127, &[], &[]));
+        smbios_tables(&structures)
+    };
+    fn chassis(tables: &SmbiosTables) -> &Chassis {
+        tables
+            .iter()
+            .find_map(|t| match t {
+                SmbiosTable::Chassis(c) => Some(c),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    oem::register_chassis_oem_decoder("Contoso", |v| {
+        (v & 0x8000_0000 != 0).then(|| format!("Lid sensor fitted (0x{:08X})", v))
+    });
+
+    let smbios = tables("Contoso Ltd.");
+    assert_eq!(smbios.system_manufacturer(), Some("Contoso Ltd."));
+    assert_eq!(
+        smbios.chassis_oem_str(chassis(&smbios)).as_deref(),
+        Some("Lid sensor fitted (0x80000001)")
+    );
+
+    let smbios = tables("Fabrikam");
+    assert_eq!(
+        smbios.chassis_oem_str(chassis(&smbios)).as_deref(),
+        Some("0x80000001")
+    );
+}
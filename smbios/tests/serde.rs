@@ -0,0 +1,166 @@
+use smbios::*;
+use std::time::{Duration, UNIX_EPOCH};
+
+mod common;
+
+use common::*;
+
+#[test]
+fn json_meta_block() {
+    let clock = || UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let tables = SmbiosTables::from_with_clock(smbios_data(&raw_bytes(127, &[], &[])), clock);
+    let json = serde_json::to_value(&tables).unwrap();
+
+    assert_eq!(
+        json["meta"],
+        serde_json::json!({
+            "source": "buffer",
+            "captured_at": "2023-11-14T22:13:20Z",
+            "entry_point": null,
+            "declared_version": "3.6",
+            "effective_version": "3.6",
+            "tool_version": env!("CARGO_PKG_VERSION"),
+            "content_hash": "fnv1a64:58f5a9db7c55d029",
+        })
+    );
+
+    let restored: SmbiosTables = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.meta(), tables.meta());
+}
+
+type PackedCompanions = &'static [(&'static str, &'static [&'static str])];
+
+fn packed_structures() -> Vec<u8> {
+    let mut chassis = patterned_body(0x11, &[0, 2, 3, 4]);
+    chassis[0x0F] = 0;
+    let mut power_supply = patterned_body(0x12, &[1, 2, 3, 4, 5, 6, 7]);
+    power_supply[0x0A] = 0xA5;
+    power_supply[0x0B] = 0x11;
+
+    let mut structures = vec![];
+    structures.extend(raw_bytes(3, &chassis, &["A", "B", "C", "D"]));
+    structures.extend(raw_bytes(
+        4,
+        &patterned_body(0x2C, &[0, 3, 0x0C, 0x1C, 0x1D, 0x1E]),
+        &["A", "B", "C", "D", "E", "F"],
+    ));
+    structures.extend(raw_bytes(6, &patterned_body(0x08, &[0]), &["A"]));
+    structures.extend(raw_bytes(7, &patterned_body(0x17, &[0]), &["A"]));
+    structures.extend(raw_bytes(10, &[0x83, 1], &["Video"]));
+    structures.extend(raw_bytes(26, &patterned_body(0x12, &[0]), &["A"]));
+    structures.extend(raw_bytes(27, &patterned_body(0x0B, &[0x0A]), &["A"]));
+    structures.extend(raw_bytes(28, &patterned_body(0x12, &[0]), &["A"]));
+    structures.extend(raw_bytes(29, &patterned_body(0x12, &[0]), &["A"]));
+    structures.extend(raw_bytes(
+        39,
+        &power_supply,
+        &["A", "B", "C", "D", "E", "F", "G"],
+    ));
+    structures.extend(raw_bytes(41, &patterned_body(0x07, &[0]), &["A"]));
+    structures
+}
+
+fn assert_packed_keys(json: &serde_json::Value, companions: PackedCompanions) {
+    assert!(!companions.is_empty());
+    for (field, keys) in companions {
+        assert!(!json[format!("{}_raw", field)].is_null(), "{}", field);
+        assert!(json.get(field).is_none(), "{}", field);
+        assert!(!keys.is_empty(), "{}", field);
+        for key in *keys {
+            assert!(!json[key].is_null(), "{}.{}", field, key);
+        }
+    }
+}
+
+#[test]
+fn json_packed_companions() {
+    let mut structures = packed_structures();
+    structures.extend(raw_bytes(127, &[], &[]));
+    let tables = smbios_tables(&structures);
+    let json = serde_json::to_value(&tables).unwrap();
+    let structures = json["structures"].as_array().unwrap();
+
+    let expected: &[(&str, PackedCompanions)] = &[
+        ("Chassis", Chassis::PACKED_COMPANIONS),
+        ("Processor", Processor::PACKED_COMPANIONS),
+        ("MemoryModule", MemoryModule::PACKED_COMPANIONS),
+        ("Cache", Cache::PACKED_COMPANIONS),
+        ("VoltageProbe", VoltageProbe::PACKED_COMPANIONS),
+        ("CoolingDevice", CoolingDevice::PACKED_COMPANIONS),
+        ("TemperatureProbe", TemperatureProbe::PACKED_COMPANIONS),
+        (
+            "ElectricalCurrentProbe",
+            ElectricalCurrentProbe::PACKED_COMPANIONS,
+        ),
+        ("SystemPowerSupply", SystemPowerSupply::PACKED_COMPANIONS),
+        (
+            "OnboardDevicesExtended",
+            OnboardDevicesExtended::PACKED_COMPANIONS,
+        ),
+    ];
+    for (name, companions) in expected {
+        let table = structures.iter().find(|s| s["type"] == *name).unwrap();
+        assert_packed_keys(table, companions);
+    }
+
+    let devices = structures
+        .iter()
+        .find(|s| s["type"] == "OnBoardDevices")
+        .unwrap();
+    assert_packed_keys(
+        &devices["devices"][0],
+        OnBoardDevicesDevice::PACKED_COMPANIONS,
+    );
+    assert_eq!(devices["devices"][0]["device_ty_raw"], 0x83);
+    assert_eq!(devices["devices"][0]["device_status"], true);
+    assert_eq!(devices["devices"][0]["device_ty_str"], "Video");
+
+    let supply = structures
+        .iter()
+        .find(|s| s["type"] == "SystemPowerSupply")
+        .unwrap();
+    assert_eq!(supply["power_supply_characteristics_raw"], 0x11A5);
+    assert_eq!(supply["hot_replaceable"], true);
+    assert_eq!(supply["present"], false);
+    assert_eq!(supply["unplugged"], true);
+
+    // The raw values alone restore the structures.
+    let restored: SmbiosTables = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&restored).unwrap(), json);
+}
+
+#[test]
+fn json_round_trip() {
+    let mut structures = raw_bytes(0, &patterned_body(0x14, &[0, 1, 4]), &["A", "B", "C"]);
+    structures.extend(raw_bytes(
+        1,
+        &patterned_body(0x17, &[0, 1, 2, 3]),
+        &["A", "B", "C", "D"],
+    ));
+    structures.extend(packed_structures());
+    // OEM structures stay raw, with bodies and strings that are not UTF-8.
+    structures.extend(raw_bytes(0x80, &[0xDE, 0xAD, 0x00, 0xFF], &["Vendor"]));
+    structures.extend([0xC0, 0x06, 0x02, 0x01, 0x01, 0x00, 0xE9, 0xFF, 0x00, 0x00]);
+    structures.extend(raw_bytes(127, &[], &[]));
+    let tables = smbios_tables(&structures);
+
+    let json = serde_json::to_string(&tables).unwrap();
+    let restored: SmbiosTables = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+    assert_eq!(restored.tables().len(), tables.tables().len());
+
+    let value = serde_json::to_value(&tables).unwrap();
+    let oem = &value["structures"].as_array().unwrap()[13];
+    assert_eq!(oem["type"], "Other");
+    assert_eq!(oem["body"], "DEAD00FF");
+    assert_eq!(oem["tailer"], serde_json::json!(["56656E646F72"]));
+
+    match &restored.tables()[14] {
+        SmbiosTable::Other(raw) => {
+            assert_eq!((raw.table_ty, raw.handle), (0xC0, 0x0102));
+            assert_eq!(raw.body(), &[0x01, 0x00]);
+            assert_eq!(raw.tailer, vec![vec![0xE9, 0xFF]]);
+        }
+        _ => panic!("expected a raw OEM structure"),
+    }
+}
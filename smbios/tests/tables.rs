@@ -0,0 +1,2048 @@
+use bytes::Bytes;
+use smbios::error::ParseError;
+use smbios::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Barrier;
+use std::time::{Duration, UNIX_EPOCH};
+
+mod common;
+
+use common::*;
+
+#[test]
+fn truncated_bios() {
+    let raw = raw_table(0, &[1, 2, 0x00], &[]);
+
+    assert_eq!(
+        Bios::try_from_raw_table(&raw).err(),
+        Some(ParseError::FieldTruncated {
+            structure: "Bios",
+            field: "bios_starting_address",
+            offset: 6,
+            length: 7,
+        })
+    );
+}
+
+#[test]
+fn probe_sentinels() {
+    let mut body = vec![0, 0x42];
+    body.extend_from_slice(&0x8000u16.to_le_bytes());
+    body.extend_from_slice(&1500u16.to_le_bytes());
+    let probe = VoltageProbe::from_raw_table(&raw_table(26, &body, &[]));
+    assert_eq!(probe.maximum_value_str().as_deref(), Some("Unknown"));
+    assert_eq!(probe.minimum_value_str().as_deref(), Some("1.500 V"));
+
+    let mut body = vec![0xFF, 0xFF, 0x63, 0, 0, 0, 0, 0];
+    body.extend_from_slice(&0x8000u16.to_le_bytes());
+    let cooling = CoolingDevice::from_raw_table(&raw_table(27, &body, &[]));
+    assert_eq!(cooling.nominal_speed_str().as_deref(), Some("Unknown"));
+
+    let probe_body = |maximum: i16, minimum: i16, resolution: u16, nominal: i16| {
+        let mut body = vec![0, 0x42];
+        body.extend_from_slice(&maximum.to_le_bytes());
+        body.extend_from_slice(&minimum.to_le_bytes());
+        body.extend_from_slice(&resolution.to_le_bytes());
+        body.extend_from_slice(&0x8000u16.to_le_bytes());
+        body.extend_from_slice(&0x8000u16.to_le_bytes());
+        body.extend_from_slice(&0u32.to_le_bytes());
+        body.extend_from_slice(&nominal.to_le_bytes());
+        body
+    };
+
+    let body = probe_body(i16::MIN, -125, 0x8000, 450);
+    let probe = TemperatureProbe::from_raw_table(&raw_table(28, &body, &[]));
+    assert_eq!(probe.maximum_value_str().as_deref(), Some("Unknown"));
+    assert_eq!(probe.minimum_value_str().as_deref(), Some("-12.5 deg C"));
+    assert_eq!(probe.resolution_str().as_deref(), Some("Unknown"));
+    assert_eq!(probe.tolerance_str().as_deref(), Some("Unknown"));
+    assert_eq!(probe.accuracy_str().as_deref(), Some("Unknown"));
+    assert_eq!(probe.nominal_value_str().as_deref(), Some("45.0 deg C"));
+
+    let body = probe_body(2500, i16::MIN, 5, i16::MIN);
+    let probe = ElectricalCurrentProbe::from_raw_table(&raw_table(29, &body, &[]));
+    assert_eq!(probe.maximum_value_str().as_deref(), Some("2.500 A"));
+    assert_eq!(probe.minimum_value_str().as_deref(), Some("Unknown"));
+    assert_eq!(probe.resolution_str().as_deref(), Some("0.5 mA"));
+    assert_eq!(probe.tolerance_str().as_deref(), Some("Unknown"));
+    assert_eq!(probe.accuracy_str().as_deref(), Some("Unknown"));
+    assert_eq!(probe.nominal_value_str().as_deref(), Some("Unknown"));
+}
+
+#[test]
+fn system_wakeup_unknown() {
+    let wakeup = |value: u8| {
+        let mut body = vec![0; 20];
+        body.push(value);
+        System::from_raw_table(&raw_table(1, &body, &[])).wakeup_ty_str()
+    };
+
+    assert_eq!(wakeup(0x00).as_deref(), Some("Reserved"));
+    assert_eq!(wakeup(0x08).as_deref(), Some("AC Power Restored"));
+    assert_eq!(wakeup(0x09).as_deref(), Some("Unknown (0x09)"));
+    assert_eq!(wakeup(0x0A).as_deref(), Some("Unknown (0x0A)"));
+    assert_eq!(wakeup(0xFF).as_deref(), Some("Unknown (0xFF)"));
+
+    // A 2.0 structure ends before the UUID and wake-up type.
+    let system = System::from_raw_table(&raw_table(1, &[0; 4], &[]));
+    assert_eq!(system.length(), 8);
+    assert_eq!(system.wakeup_ty(), None);
+    assert_eq!(system.wakeup_ty_str(), None);
+    assert!(system
+        .fields()
+        .iter()
+        .all(|(key, _)| *key != "Wake-up Type"));
+}
+
+#[test]
+fn memory_type_detail_flags() {
+    let details = [
+        "Reserved",
+        "Other",
+        "Unknown",
+        "Fast-paged",
+        "Static column",
+        "Pseudo-static",
+        "RAMBUS",
+        "Synchronous",
+        "CMOS",
+        "EDO",
+        "Window DRAM",
+        "Cache DRAM",
+        "Non-volatile",
+        "Registered",
+        "Unbuffered",
+        "LRDIMM",
+    ];
+
+    for value in [0u16, 0x0080, 0x2080, 0x4080, 0xFFFF] {
+        let mut body = vec![0; 15];
+        body.extend_from_slice(&value.to_le_bytes());
+        let device = MemoryDevice::from_raw_table(&raw_table(17, &body, &[]));
+
+        assert_eq!(
+            device.ty_detail_str(),
+            Some(flag_strings(value as u64, &details))
+        );
+    }
+}
+
+#[test]
+fn raw_smbios_data_bounds() {
+    let mut short = Bytes::from_static(&[0x00, 0x03, 0x06]);
+    assert!(RawSmbiosData::try_from_bytes(&mut short).is_err());
+
+    let mut overlong = Bytes::from_static(&[0x00, 0x03, 0x06, 0x00, 0x10, 0x00, 0x00, 0x00, 0x7F]);
+    assert!(RawSmbiosData::try_from_bytes(&mut overlong).is_err());
+}
+
+#[test]
+fn physical_memory_array_error_handle() {
+    let array = |handle: u16| {
+        let mut body = vec![0x03, 0x03, 0x03, 0x00, 0x00, 0x00, 0x01];
+        body.extend_from_slice(&handle.to_le_bytes());
+        body.extend_from_slice(&[0x04, 0x00]);
+        PhysicalMemoryArray::from_raw_table(&raw_table(16, &body, &[]))
+    };
+
+    assert_eq!(
+        array(0xFFFE).error_information_handle_str().as_deref(),
+        Some("Not Provided")
+    );
+    assert_eq!(
+        array(0xFFFF).error_information_handle_str().as_deref(),
+        Some("No Error")
+    );
+    assert_eq!(
+        array(0x0042).error_information_handle_str().as_deref(),
+        Some("0x0042")
+    );
+}
+
+#[test]
+fn bios_characteristics_flags() {
+    let chars = [
+        "",
+        "",
+        "",
+        "BIOS characteristics not supported",
+        "ISA is supported",
+        "MCA is supported",
+        "EISA is supported",
+        "PCI is supported",
+        "PC Card (PCMCIA) is supported",
+        "PNP is supported",
+        "APM is supported",
+        "BIOS is upgradeable",
+        "BIOS shadowing is allowed",
+        "VLB is supported",
+        "ESCD support is available",
+        "Boot from CD is supported",
+        "Selectable boot is supported",
+        "BIOS ROM is socketed",
+        "Boot from PC Card (PCMCIA) is supported",
+        "EDD is supported",
+        "Japanese floppy for NEC 9800 1.2 MB is supported (int 13h)",
+        "Japanese floppy for Toshiba 1.2 MB is supported (int 13h)",
+        "5.25\"/360 kB floppy services are supported (int 13h)",
+        "5.25\"/1.2 MB floppy services are supported (int 13h)",
+        "3.5\"/720 kB floppy services are supported (int 13h)",
+        "3.5\"/2.88 MB floppy services are supported (int 13h)",
+        "Print screen service is supported (int 5h)",
+        "8042 keyboard services are supported (int 9h)",
+        "Serial services are supported (int 14h)",
+        "Printer services are supported (int 17h)",
+        "CGA/mono video services are supported (int 10h)",
+        "NEC PC-98",
+    ];
+
+    for value in [0u64, 0x08, 0x0B80_9890, 0xFFFF_FFFF, 0xFFFF_FFFF_0000_0080] {
+        let mut body = vec![0, 0, 0, 0, 0, 0];
+        body.extend_from_slice(&value.to_le_bytes());
+        let bios = Bios::from_raw_table(&raw_table(0, &body, &[]));
+
+        assert_eq!(
+            bios.bios_characteristics_str(),
+            Some(flag_strings(value, &chars))
+        );
+    }
+}
+
+#[test]
+fn bios_characteristics_accessors() {
+    let mut body = vec![0, 0, 0, 0, 0, 0];
+    body.extend_from_slice(&0x0000_8880u64.to_le_bytes());
+    body.extend_from_slice(&[0x01, 0x18]);
+    let bios = Bios::from_raw_table(&raw_table(0, &body, &[]));
+    let chars = bios.characteristics().unwrap();
+
+    assert!(chars.is_supported());
+    assert!(chars.supports_pci());
+    assert!(chars.is_upgradeable());
+    assert!(chars.supports_boot_from_cd());
+    assert!(!chars.supports_edd());
+    assert!(chars.supports_acpi());
+    assert!(chars.supports_uefi());
+    assert!(chars.is_virtual_machine());
+    assert!(!chars.supports_network_boot());
+}
+
+#[test]
+fn memory_channel_devices() {
+    let body = [0x03, 10, 2, 5, 0x10, 0x00, 6, 0x11, 0x00];
+    let channel = MemoryChannel::from_raw_table(&raw_table(37, &body, &[]));
+
+    let devices = channel.memory_devices().unwrap();
+    assert_eq!(devices.len(), 2);
+    assert_eq!(
+        (devices[0].load(), devices[0].handle()),
+        (Some(5), Some(0x10))
+    );
+    assert_eq!(
+        (devices[1].load(), devices[1].handle()),
+        (Some(6), Some(0x11))
+    );
+}
+
+#[test]
+fn baseboard_handles_do_not_over_read() {
+    let body = [
+        1, 0, 0, 0, 0, 0x01, 0, 0x03, 0x00, 0x0A, 0x02, 0x10, 0x00, 0x20,
+    ];
+    let (board, warnings) =
+        BaseBoard::from_raw_table_with_warnings(&raw_table(2, &body, &["Acme"]));
+
+    assert_eq!(board.manufacturer(), Some("Acme"));
+    assert_eq!(board.manufacturer_index(), Some(1));
+    assert_eq!(board.num_contained_object(), Some(2));
+    assert_eq!(board.contained_object_handle(), Some(&[0x0010][..]));
+    assert_eq!(
+        warnings,
+        vec!["BaseBoard: contained_object_handle declares 2 entries but only 1 fit"]
+    );
+}
+
+#[test]
+fn processor_family2_fallback() {
+    let processor = |family2: Option<u16>| {
+        let mut body = vec![0; 0x24];
+        body[0x02] = 0xFE;
+        if let Some(family2) = family2 {
+            body.extend_from_slice(&family2.to_le_bytes());
+        }
+        Processor::from_raw_table(&raw_table(4, &body, &[]))
+    };
+
+    assert_eq!(
+        processor(Some(0x0101)).processor_family_str().as_deref(),
+        Some("ARMv8")
+    );
+    assert_eq!(
+        processor(Some(0x1234)).processor_family_str().as_deref(),
+        Some("Unknown (0x1234)")
+    );
+    assert_eq!(
+        processor(None).processor_family_str().as_deref(),
+        Some("Unknown")
+    );
+}
+
+#[test]
+fn chassis_contained_elements() {
+    let mut body = vec![0, 0x03, 0, 0, 0, 3, 3, 3, 3, 0, 0, 0, 0, 0, 1, 20, 3];
+    for i in 0..20 {
+        body.extend_from_slice(&[0x84, i, i + 1]);
+    }
+    body.push(1);
+    let chassis = Chassis::from_raw_table(&raw_table(3, &body, &["SKU"]));
+
+    assert_eq!(chassis.contained_elements().map(|e| e.len()), Some(60));
+    assert_eq!(chassis.contained_elements_str().map(|e| e.len()), Some(20));
+    assert_eq!(chassis.sku_number(), Some("SKU"));
+}
+
+#[test]
+fn chassis_unspecified_sentinels() {
+    let chassis = |height: u8, cords: u8| {
+        let mut body = vec![0; 0x11];
+        body[0x0D] = height;
+        body[0x0E] = cords;
+        Chassis::from_raw_table(&raw_table(3, &body, &[]))
+    };
+
+    let unspecified = chassis(0, 0);
+    assert_eq!(unspecified.height_str().as_deref(), Some("Unspecified"));
+    assert_eq!(
+        unspecified.num_power_cords_str().as_deref(),
+        Some("Unspecified")
+    );
+
+    let rack = chassis(2, 1);
+    assert_eq!(rack.height_str().as_deref(), Some("2 U"));
+    assert_eq!(rack.num_power_cords_str().as_deref(), Some("1"));
+}
+
+#[test]
+fn event_log_descriptors() {
+    let log = |count: u8| {
+        let mut body = vec![0; 16];
+        body.extend_from_slice(&[0x01, count, 2]);
+        body.extend_from_slice(&[0x01, 0x00, 0x02, 0x00, 0x03, 0x00]);
+        SystemEventLog::from_raw_table(&raw_table(15, &body, &[]))
+    };
+
+    assert_eq!(
+        log(3).list_supported_event_log_ty_desc().map(|l| l.len()),
+        Some(6)
+    );
+    assert_eq!(
+        log(4).list_supported_event_log_ty_desc().map(|l| l.len()),
+        Some(6)
+    );
+}
+
+#[test]
+fn entry_point() {
+    let mut bytes = Bytes::from_static(&[
+        b'_', b'S', b'M', b'3', b'_', 0x00, 0x18, 0x03, 0x06, 0x00, 0x01, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x00, 0x0F, 0x7F, 0x00, 0x00, 0x00, 0x00,
+    ]);
+    let entry = EntryPoint::try_from_bytes(&mut bytes).unwrap();
+    assert_eq!(entry.anchor, EntryPointAnchor::Smbios3);
+    assert_eq!((entry.major_version, entry.minor_version), (3, 6));
+    assert_eq!(entry.table_length, 0x1000);
+    assert_eq!(entry.table_address, 0x7F0F_0000);
+
+    let mut bytes = Bytes::from_static(b"_DMI_");
+    assert_eq!(
+        EntryPoint::try_from_bytes(&mut bytes),
+        Err(ParseError::InvalidAnchor)
+    );
+}
+
+#[test]
+fn bios_release_date() {
+    let bios = |date: &str| {
+        let body = [0, 0, 0, 0, 1, 0];
+        Bios::from_raw_table(&raw_table(0, &body, &[date]))
+    };
+
+    assert_eq!(
+        bios("03/14/2023").bios_release_date_parsed(),
+        Some((2023, 3, 14))
+    );
+    assert_eq!(
+        bios("12/01/99").bios_release_date_parsed(),
+        Some((1999, 12, 1))
+    );
+    assert_eq!(
+        bios("07/04/05").bios_release_date_parsed(),
+        Some((2005, 7, 4))
+    );
+    assert_eq!(bios("2023-03-14").bios_release_date_parsed(), None);
+    assert_eq!(bios("13/01/2023").bios_release_date_parsed(), None);
+    assert_eq!(bios("01/01/023").bios_release_date_parsed(), None);
+}
+
+#[test]
+fn field_notes() {
+    assert_eq!(
+        get_field_note(0, "Address"),
+        Some(Bios::bios_starting_address_note())
+    );
+    assert_eq!(
+        get_field_note(4, "Current Speed"),
+        Some(Processor::current_speed_note())
+    );
+    assert_eq!(
+        get_field_note(17, "Speed"),
+        Some(MemoryDevice::speed_note())
+    );
+    assert_eq!(FIELD_NOTES.len(), 3);
+
+    // Labels are per table type.
+    assert_eq!(get_field_note(7, "Speed"), None);
+    assert_eq!(get_field_note(4, "Max Speed"), None);
+}
+
+#[test]
+fn system_slots_after_peer_groups() {
+    let slots = |count: u8, groups: &[u8]| {
+        let mut body = vec![
+            0, 0xA5, 0x0D, 0x04, 0x04, 0x01, 0x00, 0, 0, 0, 0, 0x01, 0x00, 0x0D,
+        ];
+        body.push(count);
+        body.extend_from_slice(groups);
+        body.extend_from_slice(&[0x03, 0x0D, 0x2C, 0x01, 0x04]);
+        SystemSlots::from_raw_table(&raw_table(9, &body, &[]))
+    };
+
+    let slot = slots(0, &[]);
+    assert_eq!(slot.peer_groups().map(|g| g.len()), Some(0));
+    assert_eq!(slot.slot_information(), Some(0x03));
+    assert_eq!(slot.slot_physical_width(), Some(0x0D));
+
+    let slot = slots(1, &[0x00, 0x00, 0x02, 0x08, 0x08]);
+    assert_eq!(slot.peer_groups().map(|g| g.len()), Some(1));
+    assert_eq!(slot.slot_information(), Some(0x03));
+
+    let slot = slots(3, &[0x00, 0x00, 0x02, 0x08, 0x08]);
+    assert_eq!(slot.peer_groups().map(|g| g.len()), Some(1));
+    assert_eq!(slot.slot_information(), Some(0x03));
+    assert_eq!(slot.slot_physical_width(), Some(0x0D));
+    assert_eq!(slot.slot_pitch_str().as_deref(), Some("3.00 mm"));
+    assert_eq!(slot.slot_height_str().as_deref(), Some("Low-profile"));
+}
+
+#[test]
+fn system_slots_information() {
+    let slot = |slot_ty: u8, info: u8| {
+        let mut body = vec![0; 0x0F];
+        body[0x01] = slot_ty;
+        body.push(info);
+        SystemSlots::from_raw_table(&raw_table(9, &body, &[]))
+    };
+
+    assert_eq!(
+        slot(0xA5, 4).slot_information_str().as_deref(),
+        Some("PCIe Gen 4")
+    );
+    assert_eq!(
+        slot(0xBD, 5).slot_information_str().as_deref(),
+        Some("PCIe Gen 5")
+    );
+    assert_eq!(
+        slot(0xA5, 0).slot_information_str().as_deref(),
+        Some("Unknown")
+    );
+    assert_eq!(
+        slot(0x06, 0).slot_information_str().as_deref(),
+        Some("Unknown")
+    );
+    assert_eq!(slot(0x06, 4).slot_information_str().as_deref(), Some("4"));
+    assert_eq!(slot(0xA5, 4).slot_information(), Some(4));
+
+    let truncated = SystemSlots::from_raw_table(&raw_table(9, &[0, 0xA5], &[]));
+    assert_eq!(truncated.slot_information_str(), None);
+}
+
+#[test]
+fn bios_rom_size_without_extended() {
+    let body = [0, 0, 0, 0, 0, 0xFF];
+    let bios = Bios::from_raw_table(&raw_table(0, &body, &[]));
+    assert_eq!(bios.bios_rom_size(), Some(0xFF));
+    assert_eq!(bios.bios_rom_size_ex(), None);
+    assert!(bios.fields().iter().all(|(label, _)| *label != "ROM Size"));
+
+    let body = [0, 0, 0, 0, 0, 0x0F];
+    let bios = Bios::from_raw_table(&raw_table(0, &body, &[]));
+    assert_eq!(bios.bios_rom_size_ex(), Some(1024));
+}
+
+#[test]
+fn spec_min_length() {
+    assert_eq!(MemoryDevice::spec_min_length(2, 0), None);
+    assert_eq!(MemoryDevice::spec_min_length(2, 3), Some(0x1B));
+    assert_eq!(MemoryDevice::spec_min_length(2, 7), Some(0x22));
+    assert_eq!(MemoryDevice::spec_min_length(3, 7), Some(0x5C));
+    assert_eq!(Bios::spec_min_length(2, 0), Some(0x12));
+    assert_eq!(MemoryChannel::spec_min_length(3, 0), None);
+
+    let data =
+        |major, minor| RawSmbiosData::from(&mut Bytes::from(vec![0, major, minor, 0, 0, 0, 0, 0]));
+    let bios = Bios::from_raw_table(&raw_table(0, &[0; 0x14], &[]));
+    assert!(bios.length_is_valid(&data(2, 3)));
+    assert!(bios.length_is_valid(&data(2, 4)));
+    assert!(!bios.length_is_valid(&data(3, 1)));
+    assert!(!SmbiosTable::Bios(bios).length_is_valid(&data(3, 1)));
+}
+
+#[test]
+fn handle_resolvers() {
+    let with_handle = |mut bytes: Vec<u8>, handle: u16| {
+        bytes[2..4].copy_from_slice(&handle.to_le_bytes());
+        bytes
+    };
+
+    let mut processor = vec![0; 0x1C];
+    processor[0x16..0x18].copy_from_slice(&0x0701u16.to_le_bytes());
+    processor[0x18..0x1A].copy_from_slice(&0xFFFFu16.to_le_bytes());
+    processor[0x1A..0x1C].copy_from_slice(&0x1B00u16.to_le_bytes());
+    let mut cooling = vec![0; 8];
+    cooling[0..2].copy_from_slice(&0x1C00u16.to_le_bytes());
+
+    let mut structures = with_handle(raw_bytes(4, &processor, &[]), 0x0400);
+    structures.extend(with_handle(raw_bytes(7, &[1], &["L1"]), 0x0701));
+    structures.extend(with_handle(raw_bytes(27, &cooling, &[]), 0x1B00));
+    structures.extend(with_handle(raw_bytes(28, &[1], &["CPU"]), 0x1C00));
+    structures.extend(raw_bytes(127, &[], &[]));
+
+    let tables = smbios_tables(&structures);
+
+    let processor = match tables.get_by_handle(0x0400) {
+        Some(SmbiosTable::Processor(processor)) => processor,
+        _ => panic!("expected a processor"),
+    };
+    assert_eq!(
+        processor
+            .l1_cache_handle_resolved(&tables)
+            .and_then(|c| c.socket_designation()),
+        Some("L1")
+    );
+    assert!(processor.l2_cache_handle_resolved(&tables).is_none());
+    // Handle 0x1B00 exists but is not a cache.
+    assert!(processor.l3_cache_handle_resolved(&tables).is_none());
+
+    let cooling = match tables.get_by_handle(0x1B00) {
+        Some(SmbiosTable::CoolingDevice(cooling)) => cooling,
+        _ => panic!("expected a cooling device"),
+    };
+    assert_eq!(
+        cooling
+            .temperature_probe_handle_resolved(&tables)
+            .and_then(|p| p.description()),
+        Some("CPU")
+    );
+}
+
+#[test]
+fn total_enabled_cores_and_threads() {
+    let processor = |status: u8, cores: u8, threads: u8, cores2: u16, threads2: u16| {
+        let mut body = vec![0; 0x2C];
+        body[0x14] = status;
+        body[0x20] = cores;
+        body[0x21] = threads;
+        body[0x28..0x2A].copy_from_slice(&cores2.to_le_bytes());
+        body[0x2A..0x2C].copy_from_slice(&threads2.to_le_bytes());
+        raw_bytes(4, &body, &[])
+    };
+
+    let mut structures = processor(0x41, 8, 16, 8, 16);
+    structures.extend(processor(0x41, 0xFF, 0xFF, 300, 600));
+    structures.extend(processor(0x00, 4, 8, 4, 8));
+    structures.extend(raw_bytes(127, &[], &[]));
+
+    let tables = smbios_tables(&structures);
+
+    assert_eq!(tables.total_enabled_cores(), 308);
+    assert_eq!(tables.total_enabled_threads(), 616);
+}
+
+#[test]
+fn latin1_strings() {
+    let mut structures = vec![1, 8, 0x00, 0x01, 1, 0, 0, 0];
+    structures.extend_from_slice(b"Caf\xE9\0\0");
+    structures.extend(raw_bytes(127, &[], &[]));
+
+    let mut smbios = smbios_data(&structures);
+
+    let manufacturer = |smbios: &RawSmbiosData| {
+        let raw = smbios.structures().next().unwrap();
+        System::from_raw_table(&raw)
+            .manufacturer()
+            .map(|m| m.to_string())
+    };
+    assert_eq!(manufacturer(&smbios).as_deref(), Some("Caf\u{FFFD}"));
+
+    smbios.string_encoding = StringEncoding::Latin1;
+    assert_eq!(manufacturer(&smbios).as_deref(), Some("Café"));
+}
+
+#[test]
+fn field_presence() {
+    let processor = |len: usize| Processor::from_raw_table(&raw_table(4, &vec![0; len], &[]));
+
+    let short = processor(0x24);
+    assert!(short.has_field("l3_cache_handle"));
+    assert!(!short.has_field("core_count2"));
+    assert!(!short.has_field("no_such_field"));
+    assert!(short.missing_fields().is_empty());
+
+    let long = processor(0x28);
+    assert!(long.has_field("core_count2"));
+    assert!(!long.has_field("core_enabled2"));
+
+    let mut raw = raw_table(4, &[0; 0x24], &[]);
+    raw.length = 0x2C;
+    let mismatched = Processor::from_raw_table(&raw);
+    assert_eq!(
+        mismatched.missing_fields(),
+        vec!["processor_family2", "core_count2"]
+    );
+}
+
+#[test]
+fn field_presence_after_vectors() {
+    let mut body = vec![0; 15];
+    body[14] = 2; // peer grouping count
+    body.extend_from_slice(&[0; 10]);
+    let fixed = body.len();
+    body.extend_from_slice(&[0x01, 0x0D, 0x00, 0x01, 0x02]);
+
+    let slots = SystemSlots::from_raw_table(&raw_table(9, &body, &[]));
+    assert_eq!(slots.slot_information(), Some(0x01));
+    assert!(slots.has_field("peer_groups"));
+    assert!(slots.has_field("slot_information"));
+    assert!(slots.has_field("slot_height"));
+    assert!(slots.missing_fields().is_empty());
+
+    let short = SystemSlots::from_raw_table(&raw_table(9, &body[..body.len() - 1], &[]));
+    assert!(short.has_field("slot_pitch"));
+    assert!(!short.has_field("slot_height"));
+
+    let mut raw = raw_table(9, &body[..fixed], &[]);
+    raw.length = 4 + body.len() as u8;
+    let mismatched = SystemSlots::from_raw_table(&raw);
+    assert_eq!(
+        mismatched.missing_fields(),
+        vec![
+            "slot_information",
+            "slot_physical_width",
+            "slot_pitch",
+            "slot_height"
+        ]
+    );
+
+    let body = [0x03, 10, 2, 5, 0x10, 0x00, 5, 0x11, 0x00];
+    let channel = MemoryChannel::from_raw_table(&raw_table(37, &body, &[]));
+    assert!(channel.has_field("memory_device_count"));
+    assert!(channel.has_field("memory_devices"));
+    assert!(channel.missing_fields().is_empty());
+
+    let channel = MemoryChannel::from_raw_table(&raw_table(37, &body[..2], &[]));
+    assert!(!channel.has_field("memory_device_count"));
+    assert!(!channel.has_field("memory_devices"));
+}
+
+#[test]
+fn raw_smbios_data_from_reader() {
+    let mut structures = raw_bytes(1, &[1, 0, 0, 0], &["Acme"]);
+    structures.extend(raw_bytes(127, &[], &[]));
+
+    let mut stream = vec![
+        b'_', b'S', b'M', b'3', b'_', 0x00, 0x18, 0x03, 0x06, 0x00, 0x01, 0x00,
+    ];
+    stream.extend_from_slice(&0x1000u32.to_le_bytes());
+    stream.extend_from_slice(&0u64.to_le_bytes());
+    stream.extend_from_slice(&structures);
+
+    let smbios = RawSmbiosData::from_reader(std::io::Cursor::new(stream)).unwrap();
+    assert_eq!(
+        (smbios.smbios_major_version, smbios.smbios_minior_version),
+        (3, 6)
+    );
+    assert_eq!(smbios.structures().count(), 2);
+
+    let mut stream = b"_SM_".to_vec();
+    stream.extend_from_slice(&[0x00, 0x1F, 0x02, 0x08, 0x00, 0x00, 0x00, 0, 0, 0, 0, 0]);
+    stream.extend_from_slice(b"_DMI_");
+    stream.push(0x00);
+    stream.extend_from_slice(&0x0100u16.to_le_bytes());
+    stream.extend_from_slice(&[0x00; 6]);
+    stream.push(0x28);
+    stream.extend_from_slice(&structures);
+
+    assert!(matches!(
+        RawSmbiosData::from_reader(std::io::Cursor::new(stream)),
+        Err(error::Error::Parse(ParseError::Truncated { .. }))
+    ));
+}
+
+fn acpi_table(signature: &[u8; 4], payload: &[u8], padding: usize) -> Vec<u8> {
+    let mut table = signature.to_vec();
+    table.extend_from_slice(&((0x24 + payload.len()) as u32).to_le_bytes());
+    table.extend_from_slice(&[0x01, 0x00]);
+    table.extend_from_slice(b"VENDOR");
+    table.extend_from_slice(b"CARRIER ");
+    table.extend_from_slice(&[0x00; 12]);
+    table.extend_from_slice(payload);
+    table.resize(table.len() + padding, 0xFF);
+    table
+}
+
+#[test]
+fn acpi_carrier() {
+    let mut structures = raw_bytes(1, &[1, 0, 0, 0], &["Acme"]);
+    structures.extend(raw_bytes(127, &[], &[]));
+
+    let mut payload = vec![
+        b'_', b'S', b'M', b'3', b'_', 0x00, 0x18, 0x03, 0x06, 0x00, 0x01, 0x00,
+    ];
+    payload.extend_from_slice(&0x1000u32.to_le_bytes());
+    payload.extend_from_slice(&0xDEAD_0000u64.to_le_bytes());
+    payload.extend_from_slice(&structures);
+
+    let table = acpi_table(b"SMB3", &payload, 16);
+    let carrier = acpi::get_acpi_carrier(&table).unwrap();
+    assert_eq!(&carrier.signature, b"SMB3");
+    let (entry, smbios) = carrier.extract(&table).unwrap();
+    assert_eq!(entry.anchor, EntryPointAnchor::Smbios3);
+    assert_eq!(smbios.smbios_table_data.as_ref(), structures.as_slice());
+    assert_eq!(smbios.source.to_string(), "acpi:SMB3");
+    match SmbiosTables::from(smbios).tables() {
+        [SmbiosTable::System(system), _] => assert_eq!(system.manufacturer(), Some("Acme")),
+        _ => panic!("expected a system structure"),
+    }
+
+    let mut payload = b"_SM_".to_vec();
+    payload.extend_from_slice(&[0x00, 0x1F, 0x02, 0x08, 0x00, 0x00, 0x00, 0, 0, 0, 0, 0]);
+    payload.extend_from_slice(b"_DMI_");
+    payload.push(0x00);
+    payload.extend_from_slice(&(structures.len() as u16).to_le_bytes());
+    payload.extend_from_slice(&[0x00; 6]);
+    payload.push(0x28);
+    payload.push(0x00);
+    payload.extend_from_slice(&structures);
+
+    let table = acpi_table(b"DMIV", &payload, 0);
+    let (entry, smbios) = acpi::get_acpi_carrier(&table)
+        .unwrap()
+        .extract(&table)
+        .unwrap();
+    assert_eq!(entry.anchor, EntryPointAnchor::Smbios2);
+    assert_eq!(smbios.structures().count(), 2);
+
+    let table = acpi_table(b"DMIV", &payload[..payload.len() - 1], 0);
+    assert!(matches!(
+        acpi::get_acpi_carrier(&table).unwrap().extract(&table),
+        Err(ParseError::Truncated {
+            field: "smbios_table_data",
+            ..
+        })
+    ));
+
+    assert_eq!(
+        acpi::get_acpi_carrier(&acpi_table(b"SSDT", &payload, 0)),
+        None
+    );
+}
+
+#[test]
+fn snapshot_meta_in_memory() {
+    let clock = || UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let tables = SmbiosTables::from_with_clock(smbios_data(&raw_bytes(127, &[], &[])), clock);
+
+    assert_eq!(
+        tables.meta(),
+        &SmbiosMeta {
+            source: "buffer".to_string(),
+            captured_at: "2023-11-14T22:13:20Z".to_string(),
+            entry_point: None,
+            declared_version: "3.6".to_string(),
+            effective_version: "3.6".to_string(),
+            tool_version: env!("CARGO_PKG_VERSION").to_string(),
+            content_hash: "fnv1a64:58f5a9db7c55d029".to_string(),
+        }
+    );
+
+    let leap_day = UNIX_EPOCH + Duration::from_secs(951_868_799);
+    let meta = SmbiosMeta::new(tables.data(), leap_day);
+    assert_eq!(meta.captured_at, "2000-02-29T23:59:59Z");
+}
+
+#[test]
+fn snapshot_meta_from_file() {
+    let mut stream = b"_SM_".to_vec();
+    stream.extend_from_slice(&[0x00, 0x1F, 0x02, 0x33, 0x00, 0x00, 0x00, 0, 0, 0, 0, 0]);
+    stream.extend_from_slice(b"_DMI_");
+    stream.push(0x00);
+    stream.extend_from_slice(&6u16.to_le_bytes());
+    stream.extend_from_slice(&[0x00; 6]);
+    stream.push(0x23);
+    stream.extend(raw_bytes(127, &[], &[]));
+
+    let path = std::env::temp_dir().join(format!("smbios-meta-{}.bin", std::process::id()));
+    std::fs::write(&path, &stream).unwrap();
+    let smbios = RawSmbiosData::from_file(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    let smbios = smbios.unwrap();
+    assert_eq!(smbios.source, SmbiosSource::File(path.clone()));
+    assert_eq!(smbios.entry_point, Some(EntryPointAnchor::Smbios2));
+
+    let clock = || UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let tables = SmbiosTables::from_with_clock(smbios, clock);
+    let meta = tables.meta();
+    assert_eq!(meta.source, format!("file:{}", path.display()));
+    assert_eq!(meta.captured_at, "2023-11-14T22:13:20Z");
+    assert_eq!(meta.entry_point.as_deref(), Some("_SM_"));
+    assert_eq!(meta.declared_version, "2.51");
+    assert_eq!(meta.effective_version, "2.6");
+    assert_eq!(meta.content_hash, "fnv1a64:58f5a9db7c55d029");
+
+    assert!(matches!(
+        RawSmbiosData::from_file(&path),
+        Err(error::Error::Io(_))
+    ));
+}
+
+#[test]
+fn clamped_vector_counts() {
+    let body = [0x03, 10, 0xFF, 5, 0x10, 0x00];
+    let (channel, warnings) =
+        MemoryChannel::from_raw_table_with_warnings(&raw_table(37, &body, &[]));
+
+    let devices = channel.memory_devices().unwrap();
+    assert_eq!(devices.len(), 1);
+    assert_eq!(devices[0].handle(), Some(0x10));
+    assert_eq!(
+        warnings,
+        vec!["MemoryChannel: memory_devices declares 255 entries but only 1 fit"]
+    );
+}
+
+#[test]
+fn clamped_variable_size_records() {
+    let body = [
+        0x40, // network
+        0x00, // no interface data
+        0xFF, // protocol records
+        0x04, 0x02, 0xAA, 0xBB, // Redfish over IP, 2 bytes
+        0x02, 0x00, // IPMI, no data
+    ];
+    let (interface, warnings) =
+        ManagementControllerHostInterface::from_raw_table_with_warnings(&raw_table(42, &body, &[]));
+
+    let records = interface.protocol_records().unwrap();
+    assert_eq!(records.len(), 2);
+    assert_eq!(
+        records[0].protocol_ty_specific_data(),
+        Some(&[0xAA, 0xBB][..])
+    );
+    assert_eq!(records[1].protocol_ty(), Some(0x02));
+    assert_eq!(
+        warnings,
+        vec!["ManagementControllerHostInterface: protocol_records declares 255 entries but only 2 fit"]
+    );
+
+    let body = [
+        0xFF, // count
+        0x06, 0x00, 0x01, 0x05, 0x01, 0x01, // product name -> string 1
+    ];
+    let (additional, warnings) =
+        Additional::from_raw_table_with_warnings(&raw_table(40, &body, &["Box 2"]));
+
+    let entries = additional.additional_information_entities().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].value_as_string(&additional), Some("Box 2"));
+    assert_eq!(
+        warnings,
+        vec!["Additional: additional_information_entities declares 255 entries but only 1 fit"]
+    );
+
+    // Byte-counted vectors are reported in bytes.
+    let body = [0x40, 0x10, 0x01, 0x02];
+    let (_, warnings) =
+        ManagementControllerHostInterface::from_raw_table_with_warnings(&raw_table(42, &body, &[]));
+    assert_eq!(
+        warnings,
+        vec!["ManagementControllerHostInterface: interface_ty_specific_data declares 16 bytes but only 2 fit"]
+    );
+}
+
+#[test]
+fn memory_device_speed() {
+    let device = |speed: u16, extended: u32| {
+        let mut body = vec![0; 0x58];
+        body[0x11..0x13].copy_from_slice(&speed.to_le_bytes());
+        body[0x1C..0x1E].copy_from_slice(&speed.to_le_bytes());
+        body[0x50..0x54].copy_from_slice(&extended.to_le_bytes());
+        body[0x54..0x58].copy_from_slice(&extended.to_le_bytes());
+        MemoryDevice::from_raw_table(&raw_table(17, &body, &[]))
+    };
+
+    let unknown = device(0, 0);
+    assert_eq!(unknown.speed_str().as_deref(), Some("Unknown"));
+    assert_eq!(
+        unknown.configured_memory_speed_str().as_deref(),
+        Some("Unknown")
+    );
+
+    let promoted = device(0xFFFF, 0x8000_2134);
+    assert_eq!(promoted.speed_str().as_deref(), Some("8500 MT/s"));
+    assert_eq!(
+        promoted.configured_memory_speed_str().as_deref(),
+        Some("8500 MT/s")
+    );
+
+    let normal = device(3200, 0x2134);
+    assert_eq!(normal.speed_str().as_deref(), Some("3200 MT/s"));
+    assert_eq!(
+        normal.configured_memory_speed_str().as_deref(),
+        Some("3200 MT/s")
+    );
+
+    let truncated = MemoryDevice::from_raw_table(&raw_table(17, &[0xFF; 0x13], &[]));
+    assert_eq!(truncated.speed_str().as_deref(), Some("Unknown"));
+}
+
+#[test]
+fn event_log_access_method_address() {
+    let log = |method: u8| {
+        let mut body = vec![0; 16];
+        body[6] = method;
+        body[12..16].copy_from_slice(&0x00CD_0C72u32.to_le_bytes());
+        SystemEventLog::from_raw_table(&raw_table(15, &body, &[]))
+    };
+
+    for method in 0x00..=0x02 {
+        assert_eq!(
+            log(method).access_method_address_str().as_deref(),
+            Some("I/O port index 0x0C72, data 0x00CD")
+        );
+    }
+    assert_eq!(
+        log(0x03).access_method_address_str().as_deref(),
+        Some("Memory-mapped 0x00CD0C72")
+    );
+    assert_eq!(
+        log(0x04).access_method_address_str().as_deref(),
+        Some("GPNV handle 0x0C72")
+    );
+    assert_eq!(
+        log(0x80).access_method_address_str().as_deref(),
+        Some("0x00CD0C72")
+    );
+    assert_eq!(
+        log(0x05).access_method_str().as_deref(),
+        Some("Unknown (0x05)")
+    );
+
+    let truncated = SystemEventLog::from_raw_table(&raw_table(15, &[0; 12], &[]));
+    assert_eq!(truncated.access_method_address_str(), None);
+}
+
+#[test]
+fn chassis_unknown_values() {
+    for value in 0x00..=0xFF {
+        let body = [0x00, value, 0x00, 0x00, 0x00, value, value, value, value];
+        let chassis = Chassis::from_raw_table(&raw_table(3, &body, &[]));
+
+        assert!(chassis.ty_str().is_some());
+        assert!(chassis.boot_up_state_str().is_some());
+        assert!(chassis.power_supply_state_str().is_some());
+        assert!(chassis.thermal_state_str().is_some());
+        assert!(chassis.security_status_str().is_some());
+        assert_eq!(chassis.fields().len(), 6);
+    }
+
+    let body = [0x00, 0x25, 0x00, 0x00, 0x00, 0x07, 0x03, 0x00, 0x06];
+    let chassis = Chassis::from_raw_table(&raw_table(3, &body, &[]));
+    assert_eq!(chassis.ty_str().as_deref(), Some("Unknown (0x25)"));
+    assert_eq!(
+        chassis.boot_up_state_str().as_deref(),
+        Some("Unknown (0x07)")
+    );
+    assert_eq!(chassis.power_supply_state_str().as_deref(), Some("Safe"));
+    assert_eq!(
+        chassis.thermal_state_str().as_deref(),
+        Some("Unknown (0x00)")
+    );
+    assert_eq!(
+        chassis.security_status_str().as_deref(),
+        Some("Unknown (0x06)")
+    );
+}
+
+#[test]
+fn virtual_machine_detection() {
+    let system = |manufacturer: &str, product: &str| {
+        let mut structures = raw_bytes(1, &[1, 2, 0, 0], &[manufacturer, product]);
+        structures.extend(raw_bytes(127, &[], &[]));
+        smbios_tables(&structures).hypervisor()
+    };
+
+    assert_eq!(
+        system("VMware, Inc.", "VMware Virtual Platform"),
+        Some(Hypervisor::VMware)
+    );
+    assert_eq!(
+        system("innotek GmbH", "VirtualBox"),
+        Some(Hypervisor::VirtualBox)
+    );
+    assert_eq!(
+        system("QEMU", "Standard PC (Q35 + ICH9, 2009)"),
+        Some(Hypervisor::Qemu)
+    );
+    assert_eq!(system("Red Hat", "KVM"), Some(Hypervisor::Kvm));
+    assert_eq!(system("Xen", "HVM domU"), Some(Hypervisor::Xen));
+    assert_eq!(
+        system("Microsoft Corporation", "Virtual Machine"),
+        Some(Hypervisor::HyperV)
+    );
+    assert_eq!(
+        system("Amazon EC2", "m5.large"),
+        Some(Hypervisor::AmazonEc2)
+    );
+    assert_eq!(system("Amazon EC2", "m5.metal"), None);
+    assert_eq!(system("Microsoft Corporation", "Surface Pro"), None);
+    assert_eq!(system("Acme", "Box"), None);
+
+    let mut structures = raw_bytes(3, &[1, 0x01, 0, 0, 0], &["QEMU"]);
+    structures.extend(raw_bytes(127, &[], &[]));
+    assert_eq!(
+        smbios_tables(&structures).hypervisor(),
+        Some(Hypervisor::Qemu)
+    );
+
+    // A chassis of type Other alone is not a virtual machine.
+    let mut structures = raw_bytes(3, &[1, 0x01, 0, 0, 0], &["Acme"]);
+    structures.extend(raw_bytes(127, &[], &[]));
+    assert_eq!(smbios_tables(&structures).hypervisor(), None);
+
+    let mut bios = vec![0; 0x14];
+    bios[0x0F] = 0x10;
+    let mut structures = raw_bytes(0, &bios, &[]);
+    structures.extend(raw_bytes(1, &[1, 2, 0, 0], &["Acme", "Box"]));
+    structures.extend(raw_bytes(127, &[], &[]));
+    let tables = smbios_tables(&structures);
+    assert_eq!(tables.hypervisor(), Some(Hypervisor::Other));
+    assert!(tables.is_virtual_machine());
+}
+
+#[test]
+fn processor_family_unknown_values() {
+    let processor = |family: u8, family2: Option<u16>| {
+        let mut body = vec![0; 0x24];
+        body[0x02] = family;
+        if let Some(family2) = family2 {
+            body.extend_from_slice(&family2.to_le_bytes());
+        }
+        Processor::from_raw_table(&raw_table(4, &body, &[]))
+    };
+
+    for family in 0x00..=0xFF {
+        assert!(processor(family, None).processor_family_str().is_some());
+    }
+    for family2 in 0x0000..=0xFFFF {
+        let processor = processor(0xFE, Some(family2));
+        assert!(processor.processor_family2_str().is_some());
+        assert!(processor.processor_family_str().is_some());
+    }
+
+    let family =
+        |family: u8, family2: Option<u16>| processor(family, family2).processor_family_str();
+    assert_eq!(family(0x16, None).as_deref(), Some("Intel Processor"));
+    assert_eq!(family(0x59, None).as_deref(), Some("Unknown (0x59)"));
+    assert_eq!(family(0xFE, None).as_deref(), Some("Unknown"));
+    assert_eq!(family(0xFE, Some(0x0102)).as_deref(), Some("ARMv9"));
+    assert_eq!(
+        family(0xFE, Some(0x0103)).as_deref(),
+        Some("Unknown (0x0103)")
+    );
+}
+
+#[test]
+fn probe_negative_values() {
+    let mut body = vec![0, 0x42];
+    for value in [0x8000u16, (-250i16) as u16, 100, (-5i16) as u16, 50] {
+        body.extend_from_slice(&value.to_le_bytes());
+    }
+    body.extend_from_slice(&[0; 4]);
+    body.extend_from_slice(&(-1200i16).to_le_bytes());
+
+    let voltage = VoltageProbe::from_raw_table(&raw_table(26, &body, &[]));
+    assert_eq!(voltage.maximum_value(), Some(i16::MIN));
+    assert_eq!(voltage.maximum_value_str().as_deref(), Some("Unknown"));
+    assert_eq!(voltage.minimum_value_str().as_deref(), Some("-0.250 V"));
+    assert_eq!(voltage.resolution_str().as_deref(), Some("10.0 mV"));
+    assert_eq!(voltage.tolerance(), Some(-5));
+    assert_eq!(voltage.tolerance_str().as_deref(), Some("-0.005 V"));
+    assert_eq!(voltage.accuracy_str().as_deref(), Some("0.50%"));
+    assert_eq!(voltage.nominal_value_str().as_deref(), Some("-1.200 V"));
+
+    let temperature = TemperatureProbe::from_raw_table(&raw_table(28, &body, &[]));
+    assert_eq!(
+        temperature.minimum_value_str().as_deref(),
+        Some("-25.0 deg C")
+    );
+    assert_eq!(temperature.tolerance_str().as_deref(), Some("-0.5 deg C"));
+    assert_eq!(
+        temperature.nominal_value_str().as_deref(),
+        Some("-120.0 deg C")
+    );
+
+    let current = ElectricalCurrentProbe::from_raw_table(&raw_table(29, &body, &[]));
+    assert_eq!(current.minimum_value_str().as_deref(), Some("-0.250 A"));
+    assert_eq!(current.tolerance_str().as_deref(), Some("-0.005 A"));
+    assert_eq!(current.nominal_value_str().as_deref(), Some("-1.200 A"));
+}
+
+#[test]
+fn processor_upgrade_and_status() {
+    let processor = |status: u8, upgrade: u8| {
+        let mut body = vec![0; 0x16];
+        body[0x14] = status;
+        body[0x15] = upgrade;
+        Processor::from_raw_table(&raw_table(4, &body, &[]))
+    };
+
+    for value in 0x00..=0xFF {
+        assert!(processor(value, value).status_str().is_some());
+        assert!(processor(value, value).processor_upgrade_str().is_some());
+    }
+
+    assert_eq!(
+        processor(0x41, 0x55).processor_upgrade_str().as_deref(),
+        Some("Socket LGA1851")
+    );
+    assert_eq!(
+        processor(0x41, 0xA0).processor_upgrade_str().as_deref(),
+        Some("Unknown (0xA0)")
+    );
+    assert_eq!(processor(0x41, 0).status_str().as_deref(), Some("Enabled"));
+    assert_eq!(
+        processor(0x45, 0).status_str().as_deref(),
+        Some("Unknown (0x05)")
+    );
+    assert_eq!(
+        processor(0x01, 0).status_str().as_deref(),
+        Some("Unpopulated")
+    );
+}
+
+#[test]
+fn resync_after_bad_length() {
+    let mut structures = raw_bytes(1, &[1, 0, 0, 0], &["Acme"]);
+    // Declares no body, so the walk stops in the middle of the structure.
+    structures.extend([2, 4, 0x00, 0x02, 0x00, 0x00, 0x50, 0x51]);
+    structures.extend(b"Board\0\0");
+    structures.extend(raw_bytes(3, &[0, 0x03, 0, 0, 0], &[]));
+    structures.extend(raw_bytes(127, &[], &[]));
+
+    let mut smbios = smbios_data(&structures);
+    smbios.resync = true;
+
+    let mut iter = smbios.structures();
+    let tables = iter.by_ref().map(|t| t.table_ty).collect::<Vec<_>>();
+    assert_eq!(tables, vec![1, 2, 3, 127]);
+    assert_eq!(
+        iter.resyncs(),
+        &[Resync {
+            offset: 20,
+            skipped: 9
+        }]
+    );
+
+    let tables = SmbiosTables::from(smbios);
+    assert_eq!(tables.tables().len(), 4);
+}
+
+#[test]
+fn max_spec_table_ty_is_the_last_named() {
+    assert!(get_table_name_by_id(MAX_SPEC_TABLE_TY).is_some());
+    for ty in MAX_SPEC_TABLE_TY + 1..126 {
+        assert_eq!(get_table_name_by_id(ty), None);
+    }
+}
+
+#[test]
+fn system_slots_unknown_values() {
+    for value in 0x00..=0xFF {
+        let mut body = vec![0, value, value, value, value, 0, 0, 0, 0];
+        body.extend_from_slice(&[0, 0, 0, 0, value, 0, 0, value]);
+        body.extend_from_slice(&[0, 0, value]);
+        let slot = SystemSlots::from_raw_table(&raw_table(9, &body, &[]));
+
+        assert!(slot.slot_ty_str().is_some());
+        assert!(slot.slot_data_bus_width_str().is_some());
+        assert!(slot.current_usage_str().is_some());
+        assert!(slot.slot_length_str().is_some());
+        assert!(slot.slot_physical_width_str().is_some());
+        assert!(slot.slot_height_str().is_some());
+    }
+
+    let body = [0, 0xB7, 0x0F, 0xFF, 0x06];
+    let slot = SystemSlots::from_raw_table(&raw_table(9, &body, &[]));
+    assert_eq!(slot.slot_ty_str().as_deref(), Some("Unknown (0xB7)"));
+    assert_eq!(
+        slot.slot_data_bus_width_str().as_deref(),
+        Some("Unknown (0x0F)")
+    );
+    assert_eq!(slot.current_usage_str().as_deref(), Some("Unknown (0xFF)"));
+    assert_eq!(
+        slot.slot_length_str().as_deref(),
+        Some("3.5\" drive form factor")
+    );
+}
+
+#[test]
+fn additional_information_entries() {
+    let mut structures = raw_bytes(1, &[1, 2, 0, 0], &["Acme", "Box"]);
+    let entries = [
+        0x02, // count
+        0x06, 0x00, 0x01, 0x05, 0x01, 0x02, // product name -> string 2
+        0x09, 0x00, 0x01, 0x08, 0x00, 0x78, 0x56, 0x34, 0x12, // dword
+    ];
+    structures.extend(raw_bytes(40, &entries, &["Product", "Box 2"]));
+    structures.extend(raw_bytes(127, &[], &[]));
+
+    let tables = smbios_tables(&structures);
+    let additional = match &tables.tables()[1] {
+        SmbiosTable::Additional(additional) => additional,
+        _ => panic!("expected type 40"),
+    };
+    assert_eq!(additional.strings(), &["Product", "Box 2"]);
+
+    let entries = additional.additional_information_entities().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].string(), Some("Product"));
+    assert_eq!(entries[1].value_as_u64(), Some(0x12345678));
+    assert_eq!(entries[1].value_as_string(additional), None);
+
+    // Offset 0x05 of type 1 is the product name string.
+    let entry = &entries[0];
+    let system = match tables.get_by_handle(entry.referenced_handle().unwrap()) {
+        Some(SmbiosTable::System(system)) => system,
+        _ => panic!("expected type 1"),
+    };
+    assert_eq!(entry.referenced_offset(), Some(0x05));
+    assert_eq!(system.product_name(), Some("Box"));
+    assert_eq!(entry.value_as_string(additional), Some("Box 2"));
+}
+
+#[test]
+fn port_connector_unknown_values() {
+    for value in 0x00..=0xFF {
+        let body = [0, value, 0, value, value];
+        let port = PortConnector::from_raw_table(&raw_table(8, &body, &[]));
+
+        assert!(port.internal_connector_ty_str().is_some());
+        assert!(port.external_connector_ty_str().is_some());
+        assert!(port.port_ty_str().is_some());
+    }
+
+    let body = [0, 0x23, 0, 0x40, 0x23];
+    let port = PortConnector::from_raw_table(&raw_table(8, &body, &[]));
+    assert_eq!(
+        port.internal_connector_ty_str().as_deref(),
+        Some("USB Type-C Receptacle")
+    );
+    assert_eq!(
+        port.external_connector_ty_str().as_deref(),
+        Some("Unknown (0x40)")
+    );
+    assert_eq!(port.port_ty_str().as_deref(), Some("Thunderbolt"));
+}
+
+#[test]
+fn memory_device_ecc() {
+    let device = |total: u16, data: u16| {
+        let mut body = vec![0; 4];
+        body.extend_from_slice(&total.to_le_bytes());
+        body.extend_from_slice(&data.to_le_bytes());
+        MemoryDevice::from_raw_table(&raw_table(17, &body, &[]))
+    };
+
+    assert_eq!(device(72, 64).has_ecc(), Some(true));
+    assert_eq!(device(64, 64).has_ecc(), Some(false));
+    assert_eq!(device(0xFFFF, 64).has_ecc(), None);
+    assert_eq!(device(72, 0xFFFF).has_ecc(), None);
+
+    let truncated = MemoryDevice::from_raw_table(&raw_table(17, &[0; 6], &[]));
+    assert_eq!(truncated.has_ecc(), None);
+}
+
+#[test]
+fn memory_device_unknown_values() {
+    let device = |value: u8| {
+        let mut body = vec![0; 0x25];
+        body[0x0A] = value;
+        body[0x0E] = value;
+        body[0x24] = value;
+        MemoryDevice::from_raw_table(&raw_table(17, &body, &[]))
+    };
+
+    for value in 0x00..=0xFF {
+        let device = device(value);
+        assert!(device.form_factor_str().is_some());
+        assert!(device.memory_ty_str().is_some());
+        assert!(device.memory_technology_str().is_some());
+    }
+
+    let camm = device(0x11);
+    assert_eq!(camm.form_factor(), Some(MemoryFormFactor::Camm));
+    assert_eq!(camm.form_factor_str().as_deref(), Some("CAMM"));
+
+    let unknown = device(0x40);
+    assert_eq!(unknown.form_factor_str().as_deref(), Some("Unknown (0x40)"));
+    assert_eq!(unknown.memory_ty_str().as_deref(), Some("Unknown (0x40)"));
+    assert_eq!(
+        unknown.memory_technology_str().as_deref(),
+        Some("Unknown (0x40)")
+    );
+}
+
+#[test]
+fn dangling_string_index_warns() {
+    let body = [7, 0, 2, 0];
+    let (system, warnings) =
+        System::from_raw_table_with_warnings(&raw_table(1, &body, &["A", "B", "C"]));
+
+    assert_eq!(system.manufacturer(), None);
+    assert_eq!(system.product_name(), None);
+    assert_eq!(system.version(), Some("B"));
+    assert_eq!(
+        warnings,
+        vec!["System: manufacturer references string 7 but only 3 present"]
+    );
+}
+
+#[test]
+fn board_type_unknown_values() {
+    for value in 0x00..=0xFF {
+        let board =
+            BaseBoard::from_raw_table(&raw_table(2, &[0, 0, 0, 0, 0, 0, 0, 0, 0, value], &[]));
+        assert!(board.board_ty_str().is_some());
+        assert_eq!(
+            try_get_board_ty_str(value).is_some(),
+            (1..=13).contains(&value)
+        );
+    }
+
+    assert_eq!(get_board_ty_str(10), "Motherboard");
+    assert_eq!(get_board_ty_str(0x0E), "Unknown (0x0E)");
+
+    let body = [
+        0, 0x03, 0, 0, 0, 3, 3, 3, 3, 0, 0, 0, 0, 0, 1, 2, 3, 0x0E, 1, 1, 0x0A, 1, 1,
+    ];
+    let chassis = Chassis::from_raw_table(&raw_table(3, &body, &[]));
+    assert_eq!(
+        chassis.contained_elements_str(),
+        Some(vec![
+            "Unknown (0x0E) (1-1)".to_string(),
+            "Motherboard (1-1)".to_string()
+        ])
+    );
+}
+
+#[test]
+fn probe_unknown_locations_and_status() {
+    for value in 0x00..=0xFF {
+        let voltage = VoltageProbe::from_raw_table(&raw_table(26, &[0, value], &[]));
+        assert!(voltage.location_str().is_some());
+        assert!(voltage.status_str().is_some());
+
+        let temperature = TemperatureProbe::from_raw_table(&raw_table(28, &[0, value], &[]));
+        assert!(temperature.location_str().is_some());
+        assert!(temperature.status_str().is_some());
+
+        let current = ElectricalCurrentProbe::from_raw_table(&raw_table(29, &[0, value], &[]));
+        assert!(current.location_str().is_some());
+        assert!(current.status_str().is_some());
+
+        let cooling = CoolingDevice::from_raw_table(&raw_table(27, &[0, 0, value], &[]));
+        assert!(cooling.device_ty_str().is_some());
+        assert!(cooling.status_str().is_some());
+    }
+
+    let zeroed = VoltageProbe::from_raw_table(&raw_table(26, &[0, 0x00], &[]));
+    assert_eq!(zeroed.location_str().as_deref(), Some("Unknown (0x00)"));
+    assert_eq!(zeroed.status_str().as_deref(), Some("Unknown (0x00)"));
+
+    let body = [0, 0x6C];
+    let temperature = TemperatureProbe::from_raw_table(&raw_table(28, &body, &[]));
+    assert_eq!(
+        temperature.location_str().as_deref(),
+        Some("Front Panel Board")
+    );
+    assert_eq!(temperature.status_str().as_deref(), Some("OK"));
+    let current = ElectricalCurrentProbe::from_raw_table(&raw_table(29, &body, &[]));
+    assert_eq!(current.location_str().as_deref(), Some("Unknown (0x0C)"));
+
+    let cooling = CoolingDevice::from_raw_table(&raw_table(27, &[0, 0, 0xFF], &[]));
+    assert_eq!(cooling.device_ty_str().as_deref(), Some("Unknown (0x1F)"));
+    assert_eq!(cooling.status_str().as_deref(), Some("Unknown (0x07)"));
+}
+
+#[test]
+fn system_uuid_string() {
+    let smbios = smbios_data(&[]);
+    let system = |uuid: [u8; 16]| {
+        let mut body = vec![0, 0, 0, 0];
+        body.extend_from_slice(&uuid);
+        System::from_raw_table(&raw_table(1, &body, &[]))
+    };
+
+    let uuid = [
+        0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE,
+        0xFF,
+    ];
+    assert_eq!(
+        system(uuid).uuid_string(&smbios).as_deref(),
+        Some("00112233-4455-6677-8899-aabbccddeeff")
+    );
+    assert_eq!(
+        system([0xFF; 16]).uuid_string(&smbios).as_deref(),
+        Some("Not Present")
+    );
+    assert_eq!(
+        system([0x00; 16]).uuid_string(&smbios).as_deref(),
+        Some("Not Settable")
+    );
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn system_uuid_string_matches_uuid() {
+    let mut legacy = vec![0x00, 0x02, 0x04, 0x00];
+    legacy.extend_from_slice(&0u32.to_le_bytes());
+    let legacy = RawSmbiosData::from(&mut Bytes::from(legacy));
+
+    let mut body = vec![0, 0, 0, 0];
+    body.extend_from_slice(&[
+        0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE,
+        0xFF,
+    ]);
+    let system = System::from_raw_table(&raw_table(1, &body, &[]));
+
+    for smbios in [smbios_data(&[]), legacy] {
+        assert_eq!(
+            system.uuid_string(&smbios),
+            system.get_uuid(&smbios).map(|u| u.to_string())
+        );
+    }
+}
+
+#[test]
+fn group_associations_without_items() {
+    for body in [&[][..], &[1]] {
+        let (group, _) =
+            GroupAssociations::from_raw_table_with_warnings(&raw_table(14, body, &["Group"]));
+        assert_eq!(group.items().map(|items| items.len()), Some(0));
+    }
+}
+
+#[test]
+fn shell_safe_strings() {
+    let cases: &[(&str, bool, Option<&str>)] = &[
+        ("ABC123", false, Some("ABC123")),
+        ("ABC123", true, Some("'ABC123'")),
+        ("ABC\n123", false, Some("ABC123")),
+        ("ABC\r\n", false, Some("ABC")),
+        ("SN\nrm -rf /", true, Some("'SNrm -rf /'")),
+        ("A\tB", false, Some("A.B")),
+        ("\x1b[31mRED", false, Some(".[31mRED")),
+        ("it's", false, Some("it's")),
+        ("it's", true, Some("'it'\\''s'")),
+        ("'", true, Some("''\\'''")),
+        ("`reboot`", false, Some("`reboot`")),
+        ("`reboot`", true, Some("'`reboot`'")),
+        ("$(id)", true, Some("'$(id)'")),
+        ("  padded  ", false, Some("padded")),
+        ("", false, None),
+        ("\r\n", false, None),
+        ("\n \n", true, None),
+    ];
+
+    for (value, quote, expected) in cases {
+        assert_eq!(
+            get_shell_safe_str(value, *quote).as_deref(),
+            *expected,
+            "{:?} quote={}",
+            value,
+            quote
+        );
+    }
+}
+
+#[test]
+fn management_device_address_type() {
+    let body = [1, 0x04, 0x95, 0x02, 0x00, 0x00, 0x03];
+    let device = ManagementDevice::from_raw_table(&raw_table(34, &body, &["LM78-1"]));
+
+    assert_eq!(
+        device.ty_str().as_deref(),
+        Some("National Semiconductor LM78")
+    );
+    assert_eq!(device.address(), Some(0x0295));
+    assert_eq!(device.address_ty_str().as_deref(), Some("I/O Port"));
+
+    for value in 0x00..=0xFF {
+        let body = [1, value, 0x00, 0x00, 0x00, 0x00, value];
+        let device = ManagementDevice::from_raw_table(&raw_table(34, &body, &[]));
+        assert!(device.ty_str().is_some());
+        assert!(device.address_ty_str().is_some());
+    }
+    let body = [1, 0x0E, 0x00, 0x00, 0x00, 0x00, 0x06];
+    let device = ManagementDevice::from_raw_table(&raw_table(34, &body, &[]));
+    assert_eq!(device.ty_str().as_deref(), Some("Unknown (0x0E)"));
+    assert_eq!(device.address_ty_str().as_deref(), Some("Unknown (0x06)"));
+}
+
+#[test]
+fn system_power_supply_matches_dmidecode() {
+    // A PSU record as reported by dmidecode:
+    //   Max Power Capacity: 750 W
+    //   Status: Present, OK
+    //   Type: Switching
+    //   Input Voltage Range Switching: Auto-switch
+    //   Plugged: Yes
+    //   Hot Replaceable: Yes
+    let body = [
+        0x01, 1, 2, 3, 4, 5, 6, 7, 0xEE, 0x02, 0xA3, 0x11, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    ];
+    let strings = [
+        "PSU 1",
+        "PWR SPLY,750W",
+        "DELL",
+        "CNxxx",
+        "N/A",
+        "0Y9VFC",
+        "A00",
+    ];
+    let psu = SystemPowerSupply::from_raw_table(&raw_table(39, &body, &strings));
+
+    assert_eq!(psu.max_power_capacity_watts(), Some(750));
+    assert_eq!(psu.max_power_capacity_str().as_deref(), Some("750 W"));
+    assert_eq!(psu.present(), Some(true));
+    assert_eq!(psu.status_str().as_deref(), Some("OK"));
+    assert_eq!(psu.ty_str().as_deref(), Some("Switching"));
+    assert_eq!(psu.range_switching_str().as_deref(), Some("Auto-switch"));
+    assert_eq!(psu.unplugged(), Some(false));
+    assert_eq!(psu.hot_replaceable(), Some(true));
+
+    let mut body = body;
+    body[8..12].copy_from_slice(&[0x00, 0x80, 0xFF, 0xFF]);
+    let psu = SystemPowerSupply::from_raw_table(&raw_table(39, &body, &strings));
+    assert_eq!(psu.max_power_capacity_watts(), None);
+    assert_eq!(psu.max_power_capacity_str().as_deref(), Some("Unknown"));
+    assert_eq!(psu.status_str().as_deref(), Some("Unknown (0x07)"));
+    assert_eq!(psu.ty_str().as_deref(), Some("Unknown (0x0F)"));
+    assert_eq!(psu.range_switching_str().as_deref(), Some("Unknown (0x0F)"));
+}
+
+#[test]
+fn processor_type_and_population() {
+    let processor = |ty: u8, status: u8| {
+        let mut body = vec![0; 0x15];
+        body[0x01] = ty;
+        body[0x14] = status;
+        Processor::from_raw_table(&raw_table(4, &body, &[]))
+    };
+
+    let types = [
+        "Other",
+        "Unknown",
+        "Central Processor",
+        "Math Processor",
+        "DSP Processor",
+        "Video Processor",
+    ];
+    for (ty, expected) in (0x01..=0x06).zip(types) {
+        assert_eq!(
+            processor(ty, 0).processor_ty_str().as_deref(),
+            Some(expected)
+        );
+    }
+    assert_eq!(
+        processor(0xFF, 0).processor_ty_str().as_deref(),
+        Some("Unknown (0xFF)")
+    );
+
+    assert_eq!(processor(3, 0x41).is_populated(), Some(true));
+    assert_eq!(processor(3, 0x01).is_populated(), Some(false));
+    let short = Processor::from_raw_table(&raw_table(4, &[0; 0x14], &[]));
+    assert_eq!(short.is_populated(), None);
+}
+
+#[test]
+fn memory_device_size_bytes() {
+    let device = |size: u16, extended: u32| {
+        let mut body = vec![0; 0x1C];
+        body[0x08..0x0A].copy_from_slice(&size.to_le_bytes());
+        body[0x18..0x1C].copy_from_slice(&extended.to_le_bytes());
+        raw_bytes(17, &body, &[])
+    };
+    let size = |raw: Vec<u8>| {
+        MemoryDevice::from_raw_table(&RawSmbiosTable::from(&mut Bytes::from(raw))).size_bytes()
+    };
+
+    assert_eq!(size(device(0, 0)), Some(0));
+    assert_eq!(size(device(0xFFFF, 0)), None);
+    assert_eq!(size(device(8192, 0)), Some(8 << 30));
+    assert_eq!(size(device(0x8200, 0)), Some(512 << 10));
+    assert_eq!(size(device(0x7FFF, 0x8002_0000)), Some(128 << 30));
+
+    let mut structures = device(16384, 0);
+    structures.extend_from_slice(&device(0, 0));
+    structures.extend_from_slice(&device(0xFFFF, 0));
+    structures.extend_from_slice(&device(0x7FFF, 0x0002_0000));
+    structures.extend_from_slice(&raw_bytes(127, &[], &[]));
+    assert_eq!(smbios_tables(&structures).total_memory_bytes(), 144 << 30);
+}
+
+#[test]
+fn onboard_device_types() {
+    let body = [0x86, 1, 0x0B, 2, 0xBF, 3];
+    let onboard = OnBoardDevices::from_raw_table(&raw_table(10, &body, &["A", "B", "C"]));
+    assert_eq!(
+        onboard.get_device(),
+        Some(vec![
+            (true, "Token Ring".to_string(), "A"),
+            (false, "Wireless LAN".to_string(), "B"),
+            (true, "Unknown (0x3F)".to_string(), "C"),
+        ])
+    );
+
+    for value in 0x00..=0xFF {
+        let onboard = OnBoardDevices::from_raw_table(&raw_table(10, &[value, 1], &["A"]));
+        assert_eq!(onboard.get_device().map(|d| d.len()), Some(1));
+    }
+}
+
+#[test]
+fn structure_length_below_header() {
+    for length in [0, 3] {
+        let bad = [0x01, length, 0x34, 0x12, 0x00, 0x00];
+        assert_eq!(
+            RawSmbiosTable::try_from_bytes(&mut Bytes::copy_from_slice(&bad)).err(),
+            Some(ParseError::InvalidLength {
+                handle: 0x1234,
+                length
+            })
+        );
+
+        let mut at_start = bad.to_vec();
+        at_start.extend_from_slice(&raw_bytes(0, &[1], &["Vendor"]));
+        assert_eq!(smbios_data(&at_start).structures().count(), 0);
+
+        let mut in_middle = raw_bytes(0, &[1], &["Vendor"]);
+        in_middle.extend_from_slice(&bad);
+        in_middle.extend_from_slice(&raw_bytes(127, &[], &[]));
+        let tables = smbios_tables(&in_middle);
+        assert_eq!(tables.tables().len(), 1);
+        assert_eq!(tables.tables()[0].table_ty(), 0);
+
+        // The lenient conversion still hands such a header to the parsers.
+        let bad = [10, length, 0x00, 0x01, 0x00, 0x00];
+        let raw = RawSmbiosTable::from(&mut Bytes::copy_from_slice(&bad));
+        let devices = OnBoardDevices::from_raw_table(&raw);
+        assert_eq!(devices.devices().unwrap_or_default().len(), 0);
+        assert_eq!(SmbiosTable::from(raw).table_ty(), 10);
+    }
+}
+
+#[test]
+fn memory_module_sizes_and_connections() {
+    let module = |connections: u8, installed: u8, enabled: u8| {
+        let body = [0, connections, 0, 0, 0, installed, enabled, 0];
+        MemoryModule::from_raw_table(&raw_table(6, &body, &[]))
+    };
+
+    let sizes = |m: MemoryModule| (m.installed_size_str(), m.enabled_size_str());
+    assert_eq!(
+        sizes(module(0xFF, 0x7D, 0xFE)),
+        (
+            Some("Not Determinable (Single-bank Connection)".to_string()),
+            Some("Disabled (Double-bank Connection)".to_string())
+        )
+    );
+    assert_eq!(
+        sizes(module(0xFF, 0x7F, 0xFF)),
+        (
+            Some("Not Installed".to_string()),
+            Some("Not Installed".to_string())
+        )
+    );
+    assert_eq!(
+        module(0xFF, 0x8A, 0x0A).installed_size_str().as_deref(),
+        Some("10 MB (Double-bank Connection)")
+    );
+
+    assert_eq!(
+        module(0xFF, 0, 0).bank_connections_str().as_deref(),
+        Some("None")
+    );
+    assert_eq!(
+        module(0x01, 0, 0).bank_connections_str().as_deref(),
+        Some("0 1")
+    );
+    assert_eq!(
+        module(0x2F, 0, 0).bank_connections_str().as_deref(),
+        Some("2")
+    );
+}
+
+#[test]
+fn truncated_structures() {
+    let first = raw_bytes(0, &[1, 0], &["Vendor"]);
+    let second = raw_bytes(1, &[1, 2, 0, 0], &["Acme", "Box"]);
+    let mut image = first.clone();
+    image.extend_from_slice(&second);
+
+    for cut in first.len() + 1..image.len() {
+        let smbios = smbios_data(&image[..cut]);
+        let mut structures = smbios.structures();
+        assert!(structures.by_ref().count() <= 2);
+        assert_eq!(
+            structures.error().map(|(offset, _)| offset),
+            Some(first.len())
+        );
+    }
+
+    let parse = |cut: usize| {
+        let smbios = smbios_data(&image[..first.len() + cut]);
+        let mut structures = smbios.structures();
+        let tables = structures.by_ref().collect::<Vec<_>>();
+        let error = structures.error().map(|(_, e)| e.clone());
+        (tables, error)
+    };
+
+    // Mid-header: nothing to salvage.
+    let (tables, error) = parse(2);
+    assert_eq!(tables.len(), 1);
+    assert!(matches!(
+        error,
+        Some(ParseError::Truncated {
+            field: "handle",
+            ..
+        })
+    ));
+
+    // Mid-body: the partial body is kept.
+    let (tables, error) = parse(6);
+    assert_eq!(tables.len(), 2);
+    assert_eq!(tables[1].body(), &[1, 2]);
+    assert!(matches!(
+        error,
+        Some(ParseError::Truncated { field: "body", .. })
+    ));
+
+    // Mid-strings: completed strings are kept.
+    let (tables, error) = parse(8 + 5 + 2);
+    assert_eq!(tables[1].tailer, vec![b"Acme".to_vec()]);
+    assert!(matches!(
+        error,
+        Some(ParseError::Truncated {
+            field: "strings",
+            ..
+        })
+    ));
+
+    // Missing final NUL.
+    let (tables, error) = parse(second.len() - 1);
+    assert_eq!(tables[1].get_string_by_index(2).as_deref(), Some("Box"));
+    assert!(matches!(
+        error,
+        Some(ParseError::Truncated {
+            field: "strings",
+            ..
+        })
+    ));
+
+    let (tables, error) = parse(second.len());
+    assert_eq!(tables.len(), 2);
+    assert_eq!(error, None);
+}
+
+#[test]
+fn baseboard_keeps_partial_contained_objects() {
+    let body = [
+        1, 0, 0, 0, 0, 0x01, 0, 0x03, 0x00, 0x0A, 0x03, 0x10, 0x00, 0x20, 0x00,
+    ];
+    let (board, warnings) =
+        BaseBoard::from_raw_table_with_warnings(&raw_table(2, &body, &["Acme"]));
+
+    assert_eq!(board.num_contained_object(), Some(3));
+    assert_eq!(board.contained_object_handle(), Some(&[0x0010, 0x0020][..]));
+    assert_eq!(
+        warnings,
+        vec!["BaseBoard: contained_object_handle declares 3 entries but only 2 fit"]
+    );
+}
+
+#[test]
+fn string_set_termination() {
+    let parse = |bytes: &[u8]| {
+        let mut strict = Bytes::copy_from_slice(bytes);
+        let strict = RawSmbiosTable::try_from_bytes(&mut strict).map(|t| (t.tailer, strict.len()));
+        let lenient = RawSmbiosTable::from(&mut Bytes::copy_from_slice(bytes)).tailer;
+        (strict.ok(), lenient)
+    };
+    let header = [0x01, 0x04, 0x00, 0x01];
+    let with = |tail: &[u8]| [&header[..], tail].concat();
+
+    // Zero strings: the double NUL, with the next structure's type 0 after it.
+    assert_eq!(parse(&with(b"\0\0\0")), (Some((vec![], 1)), vec![]));
+    // One string.
+    assert_eq!(
+        parse(&with(b"A\0\0")),
+        (Some((vec![b"A".to_vec()], 0)), vec![b"A".to_vec()])
+    );
+    // The NUL after the last string is not an extra empty string.
+    assert_eq!(
+        parse(&with(b"A\0B\0\0\0")),
+        (
+            Some((vec![b"A".to_vec(), b"B".to_vec()], 1)),
+            vec![b"A".to_vec(), b"B".to_vec()]
+        )
+    );
+    // End of buffer right after the first NUL keeps the completed string.
+    assert_eq!(parse(&with(b"A\0")), (None, vec![b"A".to_vec()]));
+    assert_eq!(parse(&with(b"\0")), (None, vec![]));
+}
+
+#[test]
+fn cache_unknown_values() {
+    let cache = |value: u8| {
+        let mut body = vec![0; 0x0F];
+        body[0x01..0x03].copy_from_slice(&(u16::from(value) << 8).to_le_bytes());
+        body[0x0C] = value;
+        body[0x0D] = value;
+        body[0x0E] = value;
+        Cache::from_raw_table(&raw_table(7, &body, &[]))
+    };
+
+    for value in 0x00..=0xFF {
+        let cache = cache(value);
+        assert!(cache.operational_mode().is_some());
+        assert!(cache.error_correction_ty_str().is_some());
+        assert!(cache.system_cache_ty_str().is_some());
+        assert!(cache.associativity_str().is_some());
+    }
+
+    let unknown = cache(0x0F);
+    assert_eq!(unknown.operational_mode(), Some("Unknown"));
+    assert_eq!(
+        unknown.error_correction_ty_str().as_deref(),
+        Some("Unknown (0x0F)")
+    );
+    assert_eq!(
+        unknown.system_cache_ty_str().as_deref(),
+        Some("Unknown (0x0F)")
+    );
+    assert_eq!(
+        unknown.associativity_str().as_deref(),
+        Some("Unknown (0x0F)")
+    );
+    assert_eq!(
+        cache(0x0E).associativity_str().as_deref(),
+        Some("20-way Set-Associative")
+    );
+}
+
+#[test]
+fn non_utf8_strings() {
+    let mut bytes = vec![1, 8, 0x00, 0x01, 1, 0, 0, 0];
+    bytes.extend_from_slice(b"Acme\xAE\0\0");
+    let mut raw = RawSmbiosTable::from(&mut Bytes::from(bytes));
+
+    assert_eq!(raw.string_bytes_by_index(1), Some(&b"Acme\xAE"[..]));
+    assert_eq!(raw.string_bytes_by_index(0), None);
+    assert_eq!(raw.string_bytes_by_index(2), None);
+    assert_eq!(
+        System::from_raw_table(&raw).manufacturer(),
+        Some("Acme\u{FFFD}")
+    );
+
+    raw.encoding = StringEncoding::Latin1;
+    assert_eq!(
+        System::from_raw_table(&raw).manufacturer(),
+        Some("Acme\u{AE}")
+    );
+}
+
+#[test]
+fn memory_slots_populated() {
+    let device = |size: u16| {
+        let mut body = vec![0; 0x0C];
+        body[0x08..0x0A].copy_from_slice(&size.to_le_bytes());
+        raw_bytes(17, &body, &[])
+    };
+
+    let mut structures = vec![];
+    for size in [8192, 0, 0xFFFF, 0, 0x8200, 0] {
+        structures.extend_from_slice(&device(size));
+    }
+    structures.extend_from_slice(&raw_bytes(127, &[], &[]));
+    assert_eq!(smbios_tables(&structures).memory_slots(), (3, 6));
+
+    let empty = smbios_tables(&raw_bytes(127, &[], &[]));
+    assert_eq!(empty.memory_slots(), (0, 0));
+}
+
+#[test]
+fn iteration_stops_at_end_of_table_and_length() {
+    let mut structures = raw_bytes(0, &[1, 0], &["Vendor"]);
+    structures.extend_from_slice(&raw_bytes(127, &[], &[]));
+    let table_length = structures.len() as u32;
+    structures.extend_from_slice(&raw_bytes(1, &[1, 0, 0, 0], &["Acme"]));
+    let padded = [&structures[..], &[0; 64]].concat();
+
+    let image = |length: u32| {
+        let mut data = vec![0x00, 0x03, 0x06, 0x00];
+        data.extend_from_slice(&length.to_le_bytes());
+        data.extend_from_slice(&padded);
+        RawSmbiosData::from(&mut Bytes::from(data))
+    };
+
+    for length in [0, table_length, padded.len() as u32] {
+        let smbios = image(length);
+        let mut iter = smbios.structures();
+        let types = iter.by_ref().map(|t| t.table_ty).collect::<Vec<_>>();
+        assert_eq!(types, vec![0, 127]);
+        assert!(iter.error().is_none());
+    }
+
+    // A declared length that ends before End-of-Table stops there.
+    let smbios = image(table_length - 6);
+    let types = smbios.structures().map(|t| t.table_ty).collect::<Vec<_>>();
+    assert_eq!(types, vec![0]);
+}
+
+#[test]
+fn ipmi_base_address_modifier() {
+    let ipmi = |modifier: u8| {
+        let mut body = vec![0x01, 0x20, 0x20, 0xFF];
+        body.extend_from_slice(&0x0CA2u64.to_le_bytes());
+        body.extend_from_slice(&[modifier, 0x00]);
+        IpmiDevice::from_raw_table(&raw_table(38, &body, &[]))
+    };
+
+    let device = ipmi(0b0101_1011);
+    assert_eq!(
+        device.register_spacing_str().as_deref(),
+        Some("32-bit Boundaries")
+    );
+    assert_eq!(device.base_address_ls_bit(), Some(1));
+    assert_eq!(device.interrupt_info_specified(), Some(true));
+    assert_eq!(device.interrupt_polarity_str(), Some("Active High"));
+    assert_eq!(device.interrupt_trigger_str(), Some("Level"));
+
+    let device = ipmi(0b1000_1000);
+    assert_eq!(
+        device.register_spacing_str().as_deref(),
+        Some("16-byte Boundaries")
+    );
+    assert_eq!(device.base_address_ls_bit(), Some(0));
+    assert_eq!(device.interrupt_polarity_str(), Some("Active Low"));
+    assert_eq!(device.interrupt_trigger_str(), Some("Edge"));
+
+    let device = ipmi(0b1100_0011);
+    assert_eq!(
+        device.register_spacing_str().as_deref(),
+        Some("Unknown (0x03)")
+    );
+    assert_eq!(device.interrupt_info_specified(), Some(false));
+    assert_eq!(device.interrupt_polarity_str(), None);
+    assert_eq!(device.interrupt_trigger_str(), None);
+
+    assert_eq!(
+        ipmi(0x00).register_spacing_str().as_deref(),
+        Some("Successive Byte Boundaries")
+    );
+}
+
+#[test]
+fn approx_heap_bytes() {
+    let raw = raw_table(1, &[1, 2, 0, 0], &["Acme", "Server"]);
+    assert!(raw.approx_heap_bytes() >= 2 * std::mem::size_of::<Vec<u8>>() + 10);
+
+    let system = System::from_raw_table(&raw);
+    assert_eq!(system.approx_heap_bytes(), "Acme".len() + "Server".len());
+    assert_eq!(System::default().approx_heap_bytes(), 0);
+
+    let oem = OemStrings::from_raw_table(&raw_table(11, &[2], &["one", "two"]));
+    assert!(oem.approx_heap_bytes() >= 2 * std::mem::size_of::<String>() + 6);
+
+    let mut structures = raw_bytes(1, &[1, 2, 0, 0], &["Acme", "Server"]);
+    structures.extend(raw_bytes(127, &[], &[]));
+    let tables = smbios_tables(&structures);
+    assert!(
+        tables.approx_heap_bytes()
+            >= tables.data().smbios_table_data.len()
+                + 2 * std::mem::size_of::<SmbiosTable>()
+                + system.approx_heap_bytes()
+    );
+}
+
+#[test]
+fn cache_shares_first_snapshot() {
+    let cache = SmbiosCache::new();
+    let loads = AtomicUsize::new(0);
+    let barrier = Barrier::new(8);
+    let load = || {
+        loads.fetch_add(1, Ordering::SeqCst);
+        Ok(smbios_tables(&raw_bytes(127, &[], &[])))
+    };
+
+    let snapshots: Vec<usize> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                scope.spawn(|| {
+                    barrier.wait();
+                    cache.get_or_load(load).unwrap() as *const SmbiosTables as usize
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+
+    assert!(snapshots.iter().all(|&s| s == snapshots[0]));
+    assert_eq!(
+        cache.get().map(|t| t as *const _ as usize),
+        Some(snapshots[0])
+    );
+    let loads = loads.load(Ordering::SeqCst);
+    assert!((1..=8).contains(&loads));
+
+    // Once filled, the loader is never called again.
+    let again = cache.get_or_load(|| panic!("loaded twice")).unwrap();
+    assert_eq!(again as *const SmbiosTables as usize, snapshots[0]);
+}
+
+#[test]
+fn cache_retries_after_failed_load() {
+    let cache = SmbiosCache::new();
+
+    let failed = cache.get_or_load(|| Err(error::Error::Timeout));
+    assert!(matches!(failed, Err(error::Error::Timeout)));
+    assert!(cache.get().is_none());
+
+    let tables = cache
+        .get_or_load(|| Ok(smbios_tables(&raw_bytes(127, &[], &[]))))
+        .unwrap();
+    assert_eq!(tables.tables().len(), 1);
+    assert!(cache.get().is_some());
+}